@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_audio`, `decode_ogg_opus`, `decode_with_symphonia`, `extract_audio`, `group_segments`, `is_ogg_opus`, `new`, `push`, `transcribe_window`, `transcribe`, `window_end`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Resampler`, `SpeechModel`, `Word`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Loads a CTC speech recognition model exported to ONNX, such as an MMS or wav2vec 2.0
+/// model fine-tuned for one language, with its `vocab.json` mapping tokens to ids. The model
+/// takes 16 kHz mono samples and returns logits per frame. `language` is the ISO 639 code
+/// stored with the transcripts, e.g. "yo" or "ha".
+Future<void>  initTranscription({required String modelPath , required String vocabularyPath , String? language }) => RustLib.instance.api.crateApiAudioInitTranscription(modelPath: modelPath, vocabularyPath: vocabularyPath, language: language);
+
+/// Unloads the speech model.
+void  closeTranscription() => RustLib.instance.api.crateApiAudioCloseTranscription();
+
+bool  isTranscriptionEnabled() => RustLib.instance.api.crateApiAudioIsTranscriptionEnabled();
+
+/// Transcribes the voice note or recording at `path`, in segments with their time in the
+/// recording, so that playback can start where something was said.
+Future<List<TranscriptSegment>>  transcribeAudio({required String path }) => RustLib.instance.api.crateApiAudioTranscribeAudio(path: path);
+
+            /// A stretch of a recording and what was said in it.
+class TranscriptSegment  {
+                final BigInt startMs;
+final BigInt endMs;
+final String text;
+
+                const TranscriptSegment({required this.startMs ,required this.endMs ,required this.text ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startMs.hashCode^endMs.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TranscriptSegment &&
+                runtimeType == other.runtimeType
+                && startMs == other.startMs&& endMs == other.endMs&& text == other.text;
+        
+            }
+            
\ No newline at end of file
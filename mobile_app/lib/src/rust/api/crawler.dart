@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'crawler.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `crawl_dir`, `crawl_entry`, `crawl_with`, `error`, `is_allowed_extension`, `send`, `skip`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Crawler`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Crawls the folders described by `config`, streaming every file found, every path
+/// skipped and every error. Closing the stream on the Dart side stops the crawl.
+Stream<CrawlEvent>  crawlFiles({required CrawlConfig config }) => RustLib.instance.api.crateApiCrawlerCrawlFiles(config: config);
+
+            class CrawlConfig  {
+                final List<String> roots;
+/// File extensions to report, without the leading dot and case insensitive. Every file
+/// is reported when empty. ZIP archives are reported too: whether they hold such files is
+/// only known once they are extracted, opening each one would slow the crawl down.
+final List<String> extensions;
+/// Glob patterns matched against full paths, e.g. "**/Android/data". Matching folders
+/// are not entered.
+final List<String> excludeGlobs;
+/// Number of folder levels entered below a root, unlimited when `None`. Files directly
+/// in a root are at depth 0.
+final int? maxDepth;
+final SymlinkPolicy symlinks;
+
+                const CrawlConfig({required this.roots ,required this.extensions ,required this.excludeGlobs ,this.maxDepth ,required this.symlinks ,});
+
+                /// Same rules as the Dart PDF scanner: hidden entries and the restricted Android
+/// folders are skipped.
+static Future<CrawlConfig>  default_()=>RustLib.instance.api.crateApiCrawlerCrawlConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => roots.hashCode^extensions.hashCode^excludeGlobs.hashCode^maxDepth.hashCode^symlinks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CrawlConfig &&
+                runtimeType == other.runtimeType
+                && roots == other.roots&& extensions == other.extensions&& excludeGlobs == other.excludeGlobs&& maxDepth == other.maxDepth&& symlinks == other.symlinks;
+        
+            }
+
+@freezed
+                sealed class CrawlEvent with _$CrawlEvent  {
+                    const CrawlEvent._();
+
+                     const factory CrawlEvent.file({   required String path ,  required BigInt size ,/// Seconds since the Unix epoch.
+  PlatformInt64? modified , }) = CrawlEvent_File;
+ const factory CrawlEvent.skipped({   required String path ,  required SkipReason reason , }) = CrawlEvent_Skipped;
+ /// A file or folder could not be read, e.g. for lack of permission. Crawling goes on.
+const factory CrawlEvent.error({   required String path ,  required String message , }) = CrawlEvent_Error;
+
+                    
+
+                    
+                }
+
+class CrawlSummary  {
+                final BigInt files;
+final BigInt skipped;
+final BigInt errors;
+/// The crawl was stopped before the end because the listener went away.
+final bool cancelled;
+
+                const CrawlSummary({required this.files ,required this.skipped ,required this.errors ,required this.cancelled ,});
+
+                static Future<CrawlSummary>  default_()=>RustLib.instance.api.crateApiCrawlerCrawlSummaryDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => files.hashCode^skipped.hashCode^errors.hashCode^cancelled.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CrawlSummary &&
+                runtimeType == other.runtimeType
+                && files == other.files&& skipped == other.skipped&& errors == other.errors&& cancelled == other.cancelled;
+        
+            }
+
+enum SkipReason {
+                    excluded,
+maxDepth,
+symlink,
+/// A folder reached again through a symbolic link.
+alreadyVisited,
+                    ;
+                    
+                }
+
+/// What to do with symbolic links met while crawling.
+enum SymlinkPolicy {
+                    /// Links are reported as skipped and not followed.
+skip,
+/// Links are followed. A folder already crawled is not crawled again, which also
+/// protects against link cycles.
+follow,
+                    ;
+                    
+                }
+            
\ No newline at end of file
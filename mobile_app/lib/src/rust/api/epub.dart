@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `attribute`, `chapter_text`, `check_encryption`, `clean_text`, `extract_epub`, `package_path`, `percent_decode`, `read_package`, `read_toc`, `resolve_href`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChapterText`
+
+
+            
+
+            class ManifestItem  {
+                final String path;
+final String mediaType;
+final String properties;
+
+                const ManifestItem({required this.path ,required this.mediaType ,required this.properties ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^mediaType.hashCode^properties.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ManifestItem &&
+                runtimeType == other.runtimeType
+                && path == other.path&& mediaType == other.mediaType&& properties == other.properties;
+        
+            }
+
+class Package  {
+                final String? title;
+final List<String> authors;
+final String? language;
+final Map<String, ManifestItem> manifest;
+final List<String> spine;
+final String? ncxId;
+
+                const Package({this.title ,required this.authors ,this.language ,required this.manifest ,required this.spine ,this.ncxId ,});
+
+                static Future<Package>  default_()=>RustLib.instance.api.crateApiEpubPackageDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^authors.hashCode^language.hashCode^manifest.hashCode^spine.hashCode^ncxId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Package &&
+                runtimeType == other.runtimeType
+                && title == other.title&& authors == other.authors&& language == other.language&& manifest == other.manifest&& spine == other.spine&& ncxId == other.ncxId;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,55 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tantivy.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `chunk_id`, `extract_pdf_file`, `file_metadata`, `from_text`, `nfc`, `normalize`, `split_paragraphs`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExtractedText`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Extracts the text of the file at `path` with the extractor for its extension. XML files
+/// other than SMS backups give no documents.
+Future<ExtractedFile>  extractFile({required String path }) => RustLib.instance.api.crateApiExtractExtractFile(path: path);
+
+/// Extracts the file at `path` and replaces whatever was indexed from it with the new
+/// documents, in a single commit. Returns the ids of the documents, to record with
+/// `record_indexed_files` and to compute their embeddings.
+Future<List<String>>  indexFile({required String path }) => RustLib.instance.api.crateApiExtractIndexFile(path: path);
+
+            /// A file turned into documents ready to be indexed.
+class ExtractedFile  {
+                final String path;
+final String? title;
+final String? author;
+/// One document per PDF page or e-book chapter, per conversation window for chats, per
+/// few rows for spreadsheets, per transcript segment for recordings, or per chunk of
+/// paragraphs for other formats. Their metadata carries the file properties, but not its
+/// modification time.
+final List<DocumentWithMetadata> documents;
+
+                const ExtractedFile({required this.path ,this.title ,this.author ,required this.documents ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^title.hashCode^author.hashCode^documents.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExtractedFile &&
+                runtimeType == other.runtimeType
+                && path == other.path&& title == other.title&& author == other.author&& documents == other.documents;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `frecency`, `import_legacy_history`, `load`, `matching`, `now`, `record`, `save`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HistoryEntry`, `SearchHistory`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Loads the search history stored in `dir_path`, importing the Dart history file from
+/// the same directory the first time.
+void  initSearchHistory({required String dirPath }) => RustLib.instance.api.crateApiHistoryInitSearchHistory(dirPath: dirPath);
+
+Future<void>  recordSearch({required String query }) => RustLib.instance.api.crateApiHistoryRecordSearch(query: query);
+
+/// Most recent queries first.
+List<String>  getSearchHistory({required BigInt limit }) => RustLib.instance.api.crateApiHistoryGetSearchHistory(limit: limit);
+
+Future<void>  clearSearchHistory() => RustLib.instance.api.crateApiHistoryClearSearchHistory();
+
+/// Completes `prefix` with past queries, ranked by recency and frequency, followed by
+/// completions of its last word from the index terms, ranked by document frequency.
+/// Cheap enough to be called on every keystroke.
+Future<List<Suggestion>>  suggest({required String prefix , required BigInt limit }) => RustLib.instance.api.crateApiHistorySuggest(prefix: prefix, limit: limit);
+
+            class Suggestion  {
+                final String text;
+final SuggestionSource source;
+
+                const Suggestion({required this.text ,required this.source ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^source.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Suggestion &&
+                runtimeType == other.runtimeType
+                && text == other.text&& source == other.source;
+        
+            }
+
+enum SuggestionSource {
+                    history,
+index,
+                    ;
+                    
+                }
+            
\ No newline at end of file
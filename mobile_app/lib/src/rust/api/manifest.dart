@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'crawler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `compare_files`, `hash_file`, `load_manifest`, `manifest_path`, `save_manifest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Compares the files found by crawling `config` with the manifest. Files that were
+/// removed have their chunks deleted right away, new and changed files are returned to be
+/// indexed. A file whose size or modification time changed but not its content is only
+/// updated in the manifest.
+///
+/// After an index recovery every file is indexed again, and the chunks of the old index are
+/// reported as deleted.
+Future<ReconcileReport>  reconcileFiles({required CrawlConfig config }) => RustLib.instance.api.crateApiManifestReconcileFiles(config: config);
+
+/// Records files indexed after `reconcile_files`, once their chunks have been committed.
+/// Chunks a changed file was previously indexed as, and that are not part of `chunk_ids`
+/// anymore, are deleted. Returns the ids of those deleted chunks.
+Future<List<String>>  recordIndexedFiles({required List<IndexedFile> files }) => RustLib.instance.api.crateApiManifestRecordIndexedFiles(files: files);
+
+Future<ManifestEntry?>  getManifestEntry({required String path }) => RustLib.instance.api.crateApiManifestGetManifestEntry(path: path);
+
+            enum ChangeKind {
+                    new_,
+changed,
+                    ;
+                    
+                }
+
+/// A file to index, see `record_indexed_files`.
+class FileToIndex  {
+                final String path;
+final BigInt size;
+final PlatformInt64? modified;
+final String hash;
+final ChangeKind kind;
+
+                const FileToIndex({required this.path ,required this.size ,this.modified ,required this.hash ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^size.hashCode^modified.hashCode^hash.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileToIndex &&
+                runtimeType == other.runtimeType
+                && path == other.path&& size == other.size&& modified == other.modified&& hash == other.hash&& kind == other.kind;
+        
+            }
+
+class IndexedFile  {
+                final FileToIndex file;
+final List<String> chunkIds;
+
+                const IndexedFile({required this.file ,required this.chunkIds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => file.hashCode^chunkIds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexedFile &&
+                runtimeType == other.runtimeType
+                && file == other.file&& chunkIds == other.chunkIds;
+        
+            }
+
+/// What is known about an indexed source file.
+class ManifestEntry  {
+                final String path;
+final BigInt size;
+/// Seconds since the Unix epoch.
+final PlatformInt64? modified;
+/// SHA-1 of the file content, in hexadecimal.
+final String hash;
+/// Ids of the documents the file was indexed as.
+final List<String> chunkIds;
+/// Seconds since the Unix epoch.
+final PlatformInt64 indexedAt;
+
+                const ManifestEntry({required this.path ,required this.size ,this.modified ,required this.hash ,required this.chunkIds ,required this.indexedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^size.hashCode^modified.hashCode^hash.hashCode^chunkIds.hashCode^indexedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ManifestEntry &&
+                runtimeType == other.runtimeType
+                && path == other.path&& size == other.size&& modified == other.modified&& hash == other.hash&& chunkIds == other.chunkIds&& indexedAt == other.indexedAt;
+        
+            }
+
+class ReconcileReport  {
+                final List<FileToIndex> toIndex;
+/// Files that disappeared since they were indexed. Their chunks have been deleted.
+final List<String> removed;
+/// Ids of the chunks deleted, to delete their embeddings as well.
+final List<String> deletedChunkIds;
+final BigInt unchanged;
+/// Files and folders that could not be read. Files indexed from them are kept.
+final List<String> errors;
+
+                const ReconcileReport({required this.toIndex ,required this.removed ,required this.deletedChunkIds ,required this.unchanged ,required this.errors ,});
+
+                static Future<ReconcileReport>  default_()=>RustLib.instance.api.crateApiManifestReconcileReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => toIndex.hashCode^removed.hashCode^deletedChunkIds.hashCode^unchanged.hashCode^errors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReconcileReport &&
+                runtimeType == other.runtimeType
+                && toIndex == other.toIndex&& removed == other.removed&& deletedChunkIds == other.deletedChunkIds&& unchanged == other.unchanged&& errors == other.errors;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `copy_app_files`, `copy_documents`, `migrate`, `recover_interrupted_migration`, `replace_dir`, `set_progress`, `sibling_dir`, `stored_schema_version`, `upgrade_index`, `write_schema_version`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            IndexStatus  indexStatus({required String dirPath }) => RustLib.instance.api.crateApiMigrationIndexStatus(dirPath: dirPath);
+
+/// Migrates the index in `dir_path` to the current schema. Must be called before
+/// `init_tantivy`, which otherwise migrates without reporting progress. Poll
+/// `migration_progress` to follow it.
+Future<MigrationReport>  migrateIndex({required String dirPath }) => RustLib.instance.api.crateApiMigrationMigrateIndex(dirPath: dirPath);
+
+MigrationProgress  migrationProgress() => RustLib.instance.api.crateApiMigrationMigrationProgress();
+
+            class IndexStatus  {
+                /// Schema version of the index on disk, `None` when there is no index yet.
+final int? version;
+final int currentVersion;
+final bool needsMigration;
+
+                const IndexStatus({this.version ,required this.currentVersion ,required this.needsMigration ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => version.hashCode^currentVersion.hashCode^needsMigration.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexStatus &&
+                runtimeType == other.runtimeType
+                && version == other.version&& currentVersion == other.currentVersion&& needsMigration == other.needsMigration;
+        
+            }
+
+class MigrationProgress  {
+                final bool running;
+final BigInt processed;
+final BigInt total;
+
+                const MigrationProgress({required this.running ,required this.processed ,required this.total ,});
+
+                static Future<MigrationProgress>  default_()=>RustLib.instance.api.crateApiMigrationMigrationProgressDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => running.hashCode^processed.hashCode^total.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigrationProgress &&
+                runtimeType == other.runtimeType
+                && running == other.running&& processed == other.processed&& total == other.total;
+        
+            }
+
+class MigrationReport  {
+                final int fromVersion;
+final int toVersion;
+final BigInt documentsMigrated;
+/// The old index could not be converted. It was quarantined and replaced by an empty
+/// one, so every source file has to be indexed again.
+final bool rebuildRequired;
+/// Why the old index could not be converted.
+final String? error;
+
+                const MigrationReport({required this.fromVersion ,required this.toVersion ,required this.documentsMigrated ,required this.rebuildRequired ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fromVersion.hashCode^toVersion.hashCode^documentsMigrated.hashCode^rebuildRequired.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigrationReport &&
+                runtimeType == other.runtimeType
+                && fromVersion == other.fromVersion&& toVersion == other.toVersion&& documentsMigrated == other.documentsMigrated&& rebuildRequired == other.rebuildRequired&& error == other.error;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `ctc_decode`, `detection_input`, `extract_image`, `find_boxes`, `load_image`, `load_session`, `reading_order`, `recognition_input`, `recognize_with`, `recognize`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OcrModels`, `TextBox`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            /// Loads PaddleOCR detection and recognition models exported to ONNX, and the character
+/// dictionary of the recognition model, one character per line. Until this is called,
+/// pages and images without a text layer are not indexed.
+Future<void>  initOcr({required String detectionModelPath , required String recognitionModelPath , required String dictionaryPath }) => RustLib.instance.api.crateApiOcrInitOcr(detectionModelPath: detectionModelPath, recognitionModelPath: recognitionModelPath, dictionaryPath: dictionaryPath);
+
+/// Unloads the OCR models.
+void  closeOcr() => RustLib.instance.api.crateApiOcrCloseOcr();
+
+bool  isOcrEnabled() => RustLib.instance.api.crateApiOcrIsOcrEnabled();
+
+/// Recognises the text of the image file at `path`, e.g. a photo of a document.
+Future<String>  recognizeImage({required String path }) => RustLib.instance.api.crateApiOcrRecognizeImage(path: path);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_image`, `extract_pdf_with`, `info_dictionary`, `info_text`, `load_pdf`, `parse_pdf_date`, `read_info`, `recognize_page`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Reads the title, author, creation date and page count of the PDF at `path`.
+Future<PdfInfo>  readPdfInfo({required String path }) => RustLib.instance.api.crateApiPdfReadPdfInfo(path: path);
+
+Future<PdfText>  extractPdf({required String path }) => RustLib.instance.api.crateApiPdfExtractPdf(path: path);
+
+/// Streams the text of the PDF at `path` page by page and returns its metadata once every
+/// page has been sent. Closing the stream on the Dart side stops the extraction.
+Stream<PdfPage>  streamPdfPages({required String path }) => RustLib.instance.api.crateApiPdfStreamPdfPages(path: path);
+
+            class PdfInfo  {
+                final String? title;
+final String? author;
+/// Seconds since the Unix epoch.
+final PlatformInt64? creationDate;
+final int pageCount;
+
+                const PdfInfo({this.title ,this.author ,this.creationDate ,required this.pageCount ,});
+
+                static Future<PdfInfo>  default_()=>RustLib.instance.api.crateApiPdfPdfInfoDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^author.hashCode^creationDate.hashCode^pageCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PdfInfo &&
+                runtimeType == other.runtimeType
+                && title == other.title&& author == other.author&& creationDate == other.creationDate&& pageCount == other.pageCount;
+        
+            }
+
+class PdfPage  {
+                /// Starts at 1.
+final int pageNumber;
+/// Text recognised with OCR when the page has no text layer and OCR is enabled.
+final String text;
+/// Why the text of this page could not be extracted, e.g. an unsupported font encoding.
+/// The other pages are still extracted.
+final String? error;
+
+                const PdfPage({required this.pageNumber ,required this.text ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pageNumber.hashCode^text.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PdfPage &&
+                runtimeType == other.runtimeType
+                && pageNumber == other.pageNumber&& text == other.text&& error == other.error;
+        
+            }
+
+class PdfText  {
+                final PdfInfo info;
+final List<PdfPage> pages;
+
+                const PdfText({required this.info ,required this.pages ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => info.hashCode^pages.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PdfText &&
+                runtimeType == other.runtimeType
+                && info == other.info&& pages == other.pages;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'query.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `collapse`, `nfc`, `text`, `to_tantivy_query`, `tokenize`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            @freezed
+                sealed class Query with _$Query  {
+                    const Query._();
+
+                     /// Matches documents containing every word of `text`.
+const factory Query.term({   required String text , }) = Query_Term;
+ /// Matches the words of `text` in order, allowing `slop` other words in between.
+const factory Query.phrase({   required String text ,  required int slop , }) = Query_Phrase;
+ const factory Query.boolean({   required List<Query> must ,  required List<Query> should ,  required List<Query> mustNot , }) = Query_Boolean;
+ /// Runs `query` against `field` instead of the document text. Only tokenized text
+/// fields can be scoped to, fields matched whole like `path` cannot.
+const factory Query.field({   required String field ,  required Query query , }) = Query_Field;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
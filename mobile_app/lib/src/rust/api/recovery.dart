@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_index`, `open_index`, `quarantine`, `queue_rebuild`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Returns, once, what happened the last time the index had to be recovered, so that the
+/// UI can tell the user why their files are being indexed again.
+RecoveryReport?  takeRecoveryReport() => RustLib.instance.api.crateApiRecoveryTakeRecoveryReport();
+
+/// Whether the index in `dir_path` was reset and every file has to be indexed again.
+/// `reconcile_files` takes care of it.
+bool  rebuildPending({required String dirPath }) => RustLib.instance.api.crateApiRecoveryRebuildPending(dirPath: dirPath);
+
+/// To be called once every file has been scheduled to be indexed again after a recovery.
+Future<void>  clearRebuildPending({required String dirPath }) => RustLib.instance.api.crateApiRecoveryClearRebuildPending(dirPath: dirPath);
+
+            enum RecoveryReason {
+                    /// The index files were damaged, e.g. by the app being killed during a commit or the
+/// storage filling up.
+corrupted,
+/// The index was written by an unknown version of the app and could not be migrated.
+incompatibleSchema,
+                    ;
+                    
+                }
+
+/// What happened when the index could not be opened as it was. The app was given a fresh,
+/// empty index and has to index its files again, see `rebuild_pending`.
+class RecoveryReport  {
+                final RecoveryReason reason;
+final List<String> problems;
+/// Where the damaged index was moved, kept until the next recovery to help diagnose
+/// the problem.
+final String? quarantineDir;
+/// Seconds since the Unix epoch.
+final PlatformInt64 occurredAt;
+
+                const RecoveryReport({required this.reason ,required this.problems ,this.quarantineDir ,required this.occurredAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => reason.hashCode^problems.hashCode^quarantineDir.hashCode^occurredAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecoveryReport &&
+                runtimeType == other.runtimeType
+                && reason == other.reason&& problems == other.problems&& quarantineDir == other.quarantineDir&& occurredAt == other.occurredAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_bytes`, `add_dir`, `add_file`, `add_index`, `extract_prefix`, `file_options`, `has_embeddings`, `has_entry`, `is_not_found`, `open_archive`, `read_entry`, `remap_json_text`, `remap_json`, `remap_path`, `restore_index`, `snapshot_info`, `write_snapshot`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SnapshotEntry`, `SnapshotManifest`, `SnapshotWriter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Writes the last commit of the index, the embedding store, the file manifest and the
+/// settings to a single archive at `archive_path`. Changes not committed yet are not part
+/// of the snapshot. Indexing can go on while the snapshot is written.
+Future<SnapshotInfo>  exportSnapshot({required SnapshotPaths paths , String? settingsJson , required String storageRoot , required String archivePath }) => RustLib.instance.api.crateApiSnapshotExportSnapshot(paths: paths, settingsJson: settingsJson, storageRoot: storageRoot, archivePath: archivePath);
+
+/// Checks that `archive_path` is a complete snapshot this version of the app can import,
+/// without extracting it.
+Future<SnapshotInfo>  readSnapshotInfo({required String archivePath }) => RustLib.instance.api.crateApiSnapshotReadSnapshotInfo(archivePath: archivePath);
+
+/// Restores a snapshot made by `export_snapshot`, replacing the data at `paths`. Stored
+/// paths under the storage root of the original device are rewritten to be under
+/// `storage_root`. The index is closed first and must be opened again with `init_tantivy`.
+/// Nothing is replaced when the archive fails validation.
+Future<SnapshotInfo>  importSnapshot({required String archivePath , required SnapshotPaths paths , required String storageRoot }) => RustLib.instance.api.crateApiSnapshotImportSnapshot(archivePath: archivePath, paths: paths, storageRoot: storageRoot);
+
+            class SnapshotInfo  {
+                final int schemaVersion;
+/// Seconds since the Unix epoch.
+final PlatformInt64 createdAt;
+/// Root of the shared storage on the device the snapshot was taken on, e.g.
+/// "/storage/emulated/0".
+final String storageRoot;
+final BigInt documentCount;
+final bool hasEmbeddings;
+final bool hasFileManifest;
+/// App settings saved with the snapshot. On import, paths under the old storage root
+/// are rewritten to the new one.
+final String? settingsJson;
+
+                const SnapshotInfo({required this.schemaVersion ,required this.createdAt ,required this.storageRoot ,required this.documentCount ,required this.hasEmbeddings ,required this.hasFileManifest ,this.settingsJson ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => schemaVersion.hashCode^createdAt.hashCode^storageRoot.hashCode^documentCount.hashCode^hasEmbeddings.hashCode^hasFileManifest.hashCode^settingsJson.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnapshotInfo &&
+                runtimeType == other.runtimeType
+                && schemaVersion == other.schemaVersion&& createdAt == other.createdAt&& storageRoot == other.storageRoot&& documentCount == other.documentCount&& hasEmbeddings == other.hasEmbeddings&& hasFileManifest == other.hasFileManifest&& settingsJson == other.settingsJson;
+        
+            }
+
+/// Locations of the data a snapshot is made of. Optional parts are left out of the
+/// snapshot, or not restored, when `None`.
+class SnapshotPaths  {
+                final String indexDir;
+final String? embeddingStoreDir;
+/// JSON file listing the indexed source files, `file_manifest.json` in the index
+/// directory.
+final String? fileManifestPath;
+
+                const SnapshotPaths({required this.indexDir ,this.embeddingStoreDir ,this.fileManifestPath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => indexDir.hashCode^embeddingStoreDir.hashCode^fileManifestPath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnapshotPaths &&
+                runtimeType == other.runtimeType
+                && indexDir == other.indexDir&& embeddingStoreDir == other.embeddingStoreDir&& fileManifestPath == other.fileManifestPath;
+        
+            }
+            
\ No newline at end of file
@@ -5,82 +5,594 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'query.dart';
+import 'recovery.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TantivyApi`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
 
-void initTantivy({required String dirPath}) =>
-    RustLib.instance.api.crateApiTantivyInitTantivy(dirPath: dirPath);
+            // These functions are ignored because they are not marked as `pub`: `any_facet_query`, `archive_entries_query`, `build_document`, `build_schema`, `commit`, `count_source_files`, `delete_files`, `delete_files`, `delete_folder`, `delete_matching`, `delete_path`, `delete_text_query`, `dfa_distance`, `dir_size`, `escape_regex`, `execute_search`, `facet_collector`, `facet_counts`, `filtered_query`, `folder_facet`, `for_each_term_doc`, `generate_snippets`, `health`, `index_dir`, `index_terms_with_prefix`, `legacy_file_name`, `new`, `path_query`, `read_metadata`, `resolve`, `search_text`, `searcher`, `similar_terms`, `snippet_generator`, `source_file_count`, `spelling_suggestions`, `stats`, `strip_query_syntax`, `terms_within`, `utf16_len`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DfaWrapper`, `IndexFields`, `TantivyApi`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `accept`, `can_match`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `is_match`, `start`
 
-Future<void> addDocument({required Document doc}) =>
-    RustLib.instance.api.crateApiTantivyAddDocument(doc: doc);
 
-Future<List<SearchResult>> searchDocuments(
-        {required String query, required BigInt topK}) =>
-    RustLib.instance.api
-        .crateApiTantivySearchDocuments(query: query, topK: topK);
+            void  initTantivy({required String dirPath }) => RustLib.instance.api.crateApiTantivyInitTantivy(dirPath: dirPath);
 
-Document? getDocumentById({required String id}) =>
-    RustLib.instance.api.crateApiTantivyGetDocumentById(id: id);
+/// Closes the index, waiting for background merges to finish. Uncommitted changes are
+/// discarded. `init_tantivy` can be called again afterwards.
+Future<void>  closeTantivy() => RustLib.instance.api.crateApiTantivyCloseTantivy();
 
-Future<void> updateDocument({required Document doc}) =>
-    RustLib.instance.api.crateApiTantivyUpdateDocument(doc: doc);
+Future<void>  addDocument({required Document doc }) => RustLib.instance.api.crateApiTantivyAddDocument(doc: doc);
 
-Future<void> deleteDocument({required String id}) =>
-    RustLib.instance.api.crateApiTantivyDeleteDocument(id: id);
+/// Same as `add_document`, but also indexes where the document comes from so that it can
+/// be filtered and faceted on.
+Future<void>  addDocumentWithMetadata({required Document doc , required DocumentMetadata metadata }) => RustLib.instance.api.crateApiTantivyAddDocumentWithMetadata(doc: doc, metadata: metadata);
 
-Future<void> addDocumentsBatch({required List<Document> docs}) =>
-    RustLib.instance.api.crateApiTantivyAddDocumentsBatch(docs: docs);
+/// Replaces every document indexed from `path` with `docs`, in a single commit, so that
+/// searches never see the file half indexed.
+Future<void>  replaceFileDocuments({required String path , required List<DocumentWithMetadata> docs }) => RustLib.instance.api.crateApiTantivyReplaceFileDocuments(path: path, docs: docs);
 
-Future<void> deleteDocumentsBatch({required List<String> ids}) =>
-    RustLib.instance.api.crateApiTantivyDeleteDocumentsBatch(ids: ids);
+Future<List<SearchResult>>  searchDocuments({required String query , required BigInt topK }) => RustLib.instance.api.crateApiTantivySearchDocuments(query: query, topK: topK);
 
-void commit() => RustLib.instance.api.crateApiTantivyCommit();
+/// Searches with a query string. Syntax errors such as unbalanced quotes or parentheses
+/// do not fail the search, the offending parts are ignored and reported in
+/// `query_warnings`.
+Future<SearchResponse>  search({required String query , required SearchOptions options }) => RustLib.instance.api.crateApiTantivySearch(query: query, options: options);
 
-Future<void> addDocumentNoCommit({required Document doc}) =>
-    RustLib.instance.api.crateApiTantivyAddDocumentNoCommit(doc: doc);
+Future<SearchResponse>  searchStructured({required Query query , required SearchOptions options }) => RustLib.instance.api.crateApiTantivySearchStructured(query: query, options: options);
 
-Future<void> deleteDocumentNoCommit({required String id}) =>
-    RustLib.instance.api.crateApiTantivyDeleteDocumentNoCommit(id: id);
+DocumentWithMetadata?  getDocumentById({required String id }) => RustLib.instance.api.crateApiTantivyGetDocumentById(id: id);
 
-class Document {
-  final String id;
-  final String text;
+Future<void>  updateDocument({required Document doc }) => RustLib.instance.api.crateApiTantivyUpdateDocument(doc: doc);
 
-  const Document({
-    required this.id,
-    required this.text,
-  });
+Future<void>  deleteDocument({required String id }) => RustLib.instance.api.crateApiTantivyDeleteDocument(id: id);
 
-  @override
-  int get hashCode => id.hashCode ^ text.hashCode;
+/// Deletes every chunk of the file at `path`, and of the files inside it when it is an
+/// archive. Chunks indexed without metadata are recognised by their legacy
+/// "<fileName>-<page>" id, as long as no other indexed file has the same name. The deleted
+/// ids are returned so that the app can delete the embeddings of the same chunks.
+Future<DeleteReport>  deleteByPath({required String path }) => RustLib.instance.api.crateApiTantivyDeleteByPath(path: path);
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Document &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          text == other.text;
-}
+/// Deletes every chunk of the files in `folder` and its subfolders.
+Future<DeleteReport>  deleteByFolder({required String folder }) => RustLib.instance.api.crateApiTantivyDeleteByFolder(folder: folder);
 
-class SearchResult {
-  final double score;
-  final Document doc;
+/// Deletes every chunk matching `query`. Unlike `search`, the query must parse without
+/// errors, so that a typo cannot delete more than intended. Like the other deletes, it is
+/// left uncommitted while writes made by the `_no_commit` functions are pending, and takes
+/// effect with their `commit`.
+Future<DeleteReport>  deleteByQuery({required String query }) => RustLib.instance.api.crateApiTantivyDeleteByQuery(query: query);
 
-  const SearchResult({
-    required this.score,
-    required this.doc,
-  });
+Future<void>  addDocumentsBatch({required List<Document> docs }) => RustLib.instance.api.crateApiTantivyAddDocumentsBatch(docs: docs);
 
-  @override
-  int get hashCode => score.hashCode ^ doc.hashCode;
+Future<void>  deleteDocumentsBatch({required List<String> ids }) => RustLib.instance.api.crateApiTantivyDeleteDocumentsBatch(ids: ids);
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SearchResult &&
-          runtimeType == other.runtimeType &&
-          score == other.score &&
-          doc == other.doc;
-}
+void  commit() => RustLib.instance.api.crateApiTantivyCommit();
+
+/// Number of commits made since the index was opened. Pass it as
+/// `SearchOptions::min_generation` to read your own writes.
+BigInt  commitGeneration() => RustLib.instance.api.crateApiTantivyCommitGeneration();
+
+Future<void>  addDocumentNoCommit({required Document doc }) => RustLib.instance.api.crateApiTantivyAddDocumentNoCommit(doc: doc);
+
+Future<void>  deleteDocumentNoCommit({required String id }) => RustLib.instance.api.crateApiTantivyDeleteDocumentNoCommit(id: id);
+
+void  setMergePolicy({required MergePolicyOptions options }) => RustLib.instance.api.crateApiTantivySetMergePolicy(options: options);
+
+Future<IndexHealth>  indexHealth() => RustLib.instance.api.crateApiTantivyIndexHealth();
+
+/// Merges all committed segments into one, dropping deleted documents, then removes the
+/// files no longer used. Meant to run while the device is idle and charging, as it can
+/// take a while on large indexes. Searching and indexing go on during the merge, documents
+/// committed meanwhile stay in their own segments. Uncommitted changes are left as they are.
+Future<IndexHealth>  optimizeIndex() => RustLib.instance.api.crateApiTantivyOptimizeIndex();
+
+/// Removes index files left behind by merges and deletes. Returns the number of files
+/// removed.
+Future<BigInt>  garbageCollectIndex() => RustLib.instance.api.crateApiTantivyGarbageCollectIndex();
+
+/// Reads every file of the index to check it against its checksums, which is too slow to do
+/// on every startup. A damaged index is quarantined and replaced by an empty one, as on
+/// startup, and the returned report tells what happened. Uncommitted changes are discarded
+/// in that case.
+Future<RecoveryReport?>  verifyIndex() => RustLib.instance.api.crateApiTantivyVerifyIndex();
+
+/// `embedding_store_dir` is only used to report the size of the embedding store.
+Future<IndexStats>  indexStats({String? embeddingStoreDir }) => RustLib.instance.api.crateApiTantivyIndexStats(embeddingStoreDir: embeddingStoreDir);
+
+            class DeleteReport  {
+                final BigInt deletedCount;
+/// Ids of the deleted chunks. This crate keeps no embedding store, so nothing else is
+/// deleted here: the app deletes the embeddings it keeps for these ids.
+final List<String> deletedIds;
+/// False when the delete waits for the next `commit`, see `delete_by_query`.
+final bool committed;
+
+                const DeleteReport({required this.deletedCount ,required this.deletedIds ,required this.committed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => deletedCount.hashCode^deletedIds.hashCode^committed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeleteReport &&
+                runtimeType == other.runtimeType
+                && deletedCount == other.deletedCount&& deletedIds == other.deletedIds&& committed == other.committed;
+        
+            }
+
+class Document  {
+                final String id;
+final String text;
+
+                const Document({required this.id ,required this.text ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Document &&
+                runtimeType == other.runtimeType
+                && id == other.id&& text == other.text;
+        
+            }
+
+/// Where a document comes from. The extension and folder facets are derived from `path`.
+class DocumentMetadata  {
+                final String path;
+/// Last modification time of the source file, in seconds since the Unix epoch.
+final PlatformInt64? modified;
+/// ISO 639 code of the document language, e.g. "yo" or "ha".
+final String? language;
+/// Title of the book or file the document is part of.
+final String? title;
+final String? author;
+/// Title of the chapter the document holds, for e-books.
+final String? chapter;
+/// Position of the chapter in the book, starting at 1.
+final int? chapterIndex;
+/// Headings of the section the document starts in and of those it contains.
+final List<String> headings;
+/// Name of the conversation a chat document comes from.
+final String? chat;
+/// Authors of the messages of a chat document, or sender of an email.
+final List<String> senders;
+/// Time of the first message of a chat document or date of an email, in seconds since
+/// the Unix epoch.
+final PlatformInt64? timestamp;
+/// Name of the sheet a spreadsheet document comes from.
+final String? sheet;
+/// Number of the first row of a spreadsheet document, as shown by spreadsheet apps.
+final int? row;
+/// Start and end of a transcript segment in its recording, in milliseconds.
+final BigInt? startMs;
+final BigInt? endMs;
+/// Subject of the email a document comes from.
+final String? subject;
+/// Addresses an email was sent to, copies included.
+final List<String> recipients;
+
+                const DocumentMetadata({required this.path ,this.modified ,this.language ,this.title ,this.author ,this.chapter ,this.chapterIndex ,required this.headings ,this.chat ,required this.senders ,this.timestamp ,this.sheet ,this.row ,this.startMs ,this.endMs ,this.subject ,required this.recipients ,});
+
+                static Future<DocumentMetadata>  default_()=>RustLib.instance.api.crateApiTantivyDocumentMetadataDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^modified.hashCode^language.hashCode^title.hashCode^author.hashCode^chapter.hashCode^chapterIndex.hashCode^headings.hashCode^chat.hashCode^senders.hashCode^timestamp.hashCode^sheet.hashCode^row.hashCode^startMs.hashCode^endMs.hashCode^subject.hashCode^recipients.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DocumentMetadata &&
+                runtimeType == other.runtimeType
+                && path == other.path&& modified == other.modified&& language == other.language&& title == other.title&& author == other.author&& chapter == other.chapter&& chapterIndex == other.chapterIndex&& headings == other.headings&& chat == other.chat&& senders == other.senders&& timestamp == other.timestamp&& sheet == other.sheet&& row == other.row&& startMs == other.startMs&& endMs == other.endMs&& subject == other.subject&& recipients == other.recipients;
+        
+            }
+
+/// A document and where it comes from, see `replace_file_documents` and
+/// `get_document_by_id`.
+class DocumentWithMetadata  {
+                final Document doc;
+final DocumentMetadata metadata;
+
+                const DocumentWithMetadata({required this.doc ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => doc.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DocumentWithMetadata &&
+                runtimeType == other.runtimeType
+                && doc == other.doc&& metadata == other.metadata;
+        
+            }
+
+class FacetCount  {
+                final String value;
+final BigInt count;
+
+                const FacetCount({required this.value ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => value.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FacetCount &&
+                runtimeType == other.runtimeType
+                && value == other.value&& count == other.count;
+        
+            }
+
+/// Range of a matched term within a snippet, in UTF-16 code units so that it can be
+/// used directly with Dart `String` indices.
+class HighlightRange  {
+                final BigInt start;
+final BigInt end;
+
+                const HighlightRange({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HighlightRange &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+/// State of the index files, used to decide when maintenance is worth running.
+class IndexHealth  {
+                final BigInt segmentCount;
+final BigInt numDocs;
+final BigInt numDeletedDocs;
+/// Deleted documents over all stored documents, between 0 and 1.
+final double deletedRatio;
+final BigInt diskBytes;
+
+                const IndexHealth({required this.segmentCount ,required this.numDocs ,required this.numDeletedDocs ,required this.deletedRatio ,required this.diskBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => segmentCount.hashCode^numDocs.hashCode^numDeletedDocs.hashCode^deletedRatio.hashCode^diskBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexHealth &&
+                runtimeType == other.runtimeType
+                && segmentCount == other.segmentCount&& numDocs == other.numDocs&& numDeletedDocs == other.numDeletedDocs&& deletedRatio == other.deletedRatio&& diskBytes == other.diskBytes;
+        
+            }
+
+/// Overview of the index shown on the settings screen.
+class IndexStats  {
+                /// Indexed documents, each one a chunk of a source file such as a PDF page.
+final BigInt chunkCount;
+final BigInt fileCount;
+/// Chunks per file extension.
+final List<FacetCount> extensions;
+/// Chunks per language.
+final List<FacetCount> languages;
+final BigInt segmentCount;
+final BigInt deletedCount;
+final BigInt indexBytes;
+final BigInt embeddingStoreBytes;
+/// Time of the last commit in seconds since the Unix epoch, `None` before the first one.
+final PlatformInt64? lastCommit;
+
+                const IndexStats({required this.chunkCount ,required this.fileCount ,required this.extensions ,required this.languages ,required this.segmentCount ,required this.deletedCount ,required this.indexBytes ,required this.embeddingStoreBytes ,this.lastCommit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => chunkCount.hashCode^fileCount.hashCode^extensions.hashCode^languages.hashCode^segmentCount.hashCode^deletedCount.hashCode^indexBytes.hashCode^embeddingStoreBytes.hashCode^lastCommit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexStats &&
+                runtimeType == other.runtimeType
+                && chunkCount == other.chunkCount&& fileCount == other.fileCount&& extensions == other.extensions&& languages == other.languages&& segmentCount == other.segmentCount&& deletedCount == other.deletedCount&& indexBytes == other.indexBytes&& embeddingStoreBytes == other.embeddingStoreBytes&& lastCommit == other.lastCommit;
+        
+            }
+
+/// Settings of the merge policy used when committing. Fewer, larger segments make searches
+/// faster at the cost of more background merging.
+class MergePolicyOptions  {
+                /// Segments are not merged at all when disabled, e.g. while on battery.
+final bool enabled;
+/// Number of segments of similar size that triggers a merge.
+final BigInt minNumSegments;
+/// Segments holding more documents than this are never merged.
+final BigInt maxDocsBeforeMerge;
+/// Ratio of deleted documents above which a segment is merged on its own to reclaim
+/// space, between 0 and 1.
+final double deletedRatioBeforeMerge;
+
+                const MergePolicyOptions({required this.enabled ,required this.minNumSegments ,required this.maxDocsBeforeMerge ,required this.deletedRatioBeforeMerge ,});
+
+                static Future<MergePolicyOptions>  default_()=>RustLib.instance.api.crateApiTantivyMergePolicyOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => enabled.hashCode^minNumSegments.hashCode^maxDocsBeforeMerge.hashCode^deletedRatioBeforeMerge.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MergePolicyOptions &&
+                runtimeType == other.runtimeType
+                && enabled == other.enabled&& minNumSegments == other.minNumSegments&& maxDocsBeforeMerge == other.maxDocsBeforeMerge&& deletedRatioBeforeMerge == other.deletedRatioBeforeMerge;
+        
+            }
+
+/// Number of matching documents per filter value, used to render filter chips.
+class SearchFacets  {
+                final List<FacetCount> extensions;
+/// Full paths of the direct subfolders of the `folder_prefix` filter, or of the root
+/// when it is unset.
+final List<FacetCount> folders;
+/// Years of the modification time.
+final List<FacetCount> years;
+final List<FacetCount> languages;
+
+                const SearchFacets({required this.extensions ,required this.folders ,required this.years ,required this.languages ,});
+
+                static Future<SearchFacets>  default_()=>RustLib.instance.api.crateApiTantivySearchFacetsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => extensions.hashCode^folders.hashCode^years.hashCode^languages.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchFacets &&
+                runtimeType == other.runtimeType
+                && extensions == other.extensions&& folders == other.folders&& years == other.years&& languages == other.languages;
+        
+            }
+
+/// Restricts search results. Empty lists and `None` values do not filter anything.
+class SearchFilter  {
+                /// File extensions without the leading dot, e.g. "pdf". Matches any of them.
+final List<String> extensions;
+/// Only match documents located in this folder or one of its subfolders.
+final String? folderPrefix;
+/// Inclusive lower bound on the modification time, in seconds since the Unix epoch.
+final PlatformInt64? modifiedAfter;
+/// Exclusive upper bound on the modification time, in seconds since the Unix epoch.
+final PlatformInt64? modifiedBefore;
+/// Language codes. Matches any of them.
+final List<String> languages;
+
+                const SearchFilter({required this.extensions ,this.folderPrefix ,this.modifiedAfter ,this.modifiedBefore ,required this.languages ,});
+
+                static Future<SearchFilter>  default_()=>RustLib.instance.api.crateApiTantivySearchFilterDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => extensions.hashCode^folderPrefix.hashCode^modifiedAfter.hashCode^modifiedBefore.hashCode^languages.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchFilter &&
+                runtimeType == other.runtimeType
+                && extensions == other.extensions&& folderPrefix == other.folderPrefix&& modifiedAfter == other.modifiedAfter&& modifiedBefore == other.modifiedBefore&& languages == other.languages;
+        
+            }
+
+class SearchHit  {
+                final double score;
+final Document doc;
+final DocumentMetadata metadata;
+final List<Snippet> snippets;
+
+                const SearchHit({required this.score ,required this.doc ,required this.metadata ,required this.snippets ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => score.hashCode^doc.hashCode^metadata.hashCode^snippets.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && score == other.score&& doc == other.doc&& metadata == other.metadata&& snippets == other.snippets;
+        
+            }
+
+class SearchOptions  {
+                /// Number of hits per page. Only the total and the facets are returned when it is 0.
+final BigInt topK;
+/// Number of hits to skip, a multiple of `top_k` when paging.
+final BigInt offset;
+final SearchFilter filter;
+/// Maximum number of snippets returned per hit.
+final BigInt maxSnippets;
+/// Maximum length of a snippet, in characters.
+final BigInt snippetMaxChars;
+/// Commit generation the results must reflect, see `commit_generation`.
+final BigInt minGeneration;
+/// Maximum edit distance used to expand query terms, at most 2. Fuzzy matching is
+/// disabled when it is 0.
+final int fuzzyDistance;
+/// Spelling suggestions are returned when there are fewer hits than this.
+final BigInt suggestBelowHits;
+
+                const SearchOptions({required this.topK ,required this.offset ,required this.filter ,required this.maxSnippets ,required this.snippetMaxChars ,required this.minGeneration ,required this.fuzzyDistance ,required this.suggestBelowHits ,});
+
+                static Future<SearchOptions>  default_()=>RustLib.instance.api.crateApiTantivySearchOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => topK.hashCode^offset.hashCode^filter.hashCode^maxSnippets.hashCode^snippetMaxChars.hashCode^minGeneration.hashCode^fuzzyDistance.hashCode^suggestBelowHits.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchOptions &&
+                runtimeType == other.runtimeType
+                && topK == other.topK&& offset == other.offset&& filter == other.filter&& maxSnippets == other.maxSnippets&& snippetMaxChars == other.snippetMaxChars&& minGeneration == other.minGeneration&& fuzzyDistance == other.fuzzyDistance&& suggestBelowHits == other.suggestBelowHits;
+        
+            }
+
+class SearchResponse  {
+                final List<SearchHit> hits;
+/// Number of documents matching the query and filters, across all pages.
+final BigInt totalHits;
+final SearchFacets facets;
+/// "Did you mean" queries built from terms present in the index, best first.
+final List<String> suggestions;
+/// Parts of a query string that could not be parsed and were ignored.
+final List<String> queryWarnings;
+
+                const SearchResponse({required this.hits ,required this.totalHits ,required this.facets ,required this.suggestions ,required this.queryWarnings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^totalHits.hashCode^facets.hashCode^suggestions.hashCode^queryWarnings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchResponse &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& totalHits == other.totalHits&& facets == other.facets&& suggestions == other.suggestions&& queryWarnings == other.queryWarnings;
+        
+            }
+
+class SearchResult  {
+                final double score;
+final Document doc;
+final DocumentMetadata metadata;
+
+                const SearchResult({required this.score ,required this.doc ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => score.hashCode^doc.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchResult &&
+                runtimeType == other.runtimeType
+                && score == other.score&& doc == other.doc&& metadata == other.metadata;
+        
+            }
+
+class Snippet  {
+                final String fragment;
+/// Offset of the fragment within the document text, in UTF-16 code units.
+final BigInt offset;
+final List<HighlightRange> highlights;
+
+                const Snippet({required this.fragment ,required this.offset ,required this.highlights ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fragment.hashCode^offset.hashCode^highlights.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Snippet &&
+                runtimeType == other.runtimeType
+                && fragment == other.fragment&& offset == other.offset&& highlights == other.highlights;
+        
+            }
+            
\ No newline at end of file
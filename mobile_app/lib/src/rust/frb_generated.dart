@@ -4,1719 +4,4042 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/acho.dart';
+import 'api/audio.dart';
+import 'api/crawler.dart';
+import 'api/extract.dart';
+import 'api/history.dart';
+import 'api/manifest.dart';
+import 'api/migration.dart';
+import 'api/ocr.dart';
+import 'api/pdf.dart';
+import 'api/query.dart';
+import 'api/recovery.dart';
 import 'api/simple.dart';
+import 'api/snapshot.dart';
 import 'api/tantivy.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
 
-  @override
-  String get codegenVersion => '2.11.1';
+                  }
 
-  @override
-  int get rustContentHash => -1510959301;
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_mobile_app',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiTantivyAddDocument({required Document doc});
+                  @override
+                  int get rustContentHash => 749940810;
 
-  Future<void> crateApiTantivyAddDocumentNoCommit({required Document doc});
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_mobile_app',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<void> crateApiTantivyAddDocumentsBatch({required List<Document> docs});
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiTantivyAddDocument({required Document doc });
 
-  void crateApiTantivyCommit();
+Future<void> crateApiTantivyAddDocumentNoCommit({required Document doc });
 
-  Future<void> crateApiTantivyDeleteDocument({required String id});
+Future<void> crateApiTantivyAddDocumentWithMetadata({required Document doc , required DocumentMetadata metadata });
 
-  Future<void> crateApiTantivyDeleteDocumentNoCommit({required String id});
+Future<void> crateApiTantivyAddDocumentsBatch({required List<Document> docs });
 
-  Future<void> crateApiTantivyDeleteDocumentsBatch({required List<String> ids});
+Future<void> crateApiRecoveryClearRebuildPending({required String dirPath });
 
-  Document? crateApiTantivyGetDocumentById({required String id});
+Future<void> crateApiHistoryClearSearchHistory();
 
-  Future<Array2I64> crateApiAchoGetEncodingArray(
-      {required List<Encoding> encodings, required EncodingType encodingType});
+void crateApiOcrCloseOcr();
 
-  Future<List<SimilarityScore>> crateApiAchoGetTopK(
-      {required List<double> scores, required BigInt k});
+Future<void> crateApiTantivyCloseTantivy();
 
-  String crateApiSimpleGreet({required String name});
+void crateApiAudioCloseTranscription();
 
-  Future<void> crateApiSimpleInitApp();
+void crateApiTantivyCommit();
 
-  void crateApiTantivyInitTantivy({required String dirPath});
+BigInt crateApiTantivyCommitGeneration();
 
-  Future<(Tokenizer, Session)> crateApiAchoLoadArtifacts(
-      {required String modelPath, required String tokenizerPath});
-
-  Future<Array2F32> crateApiAchoRunInference(
-      {required List<String> text,
-      required Session model,
-      required Tokenizer tokenizer});
-
-  Future<List<SearchResult>> crateApiTantivySearchDocuments(
-      {required String query, required BigInt topK});
-
-  Future<List<SimilarityScore>> crateApiAchoSimilarity(
-      {required List<String> query,
-      required List<String> texts,
-      required String modelPath,
-      required String tokenizerPath,
-      required BigInt topK});
-
-  Future<(Array2I64, Array2I64)> crateApiAchoTokenize(
-      {required List<String> texts, required Tokenizer tokenizer});
-
-  Future<void> crateApiTantivyUpdateDocument({required Document doc});
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Array2F32;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Array2F32;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2F32Ptr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Array2I64;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Array2I64;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2I64Ptr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Encoding;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Encoding;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EncodingPtr;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Session;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Session;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionPtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Tokenizer;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Tokenizer;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TokenizerPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiTantivyAddDocument({required Document doc}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_document(doc, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyAddDocumentConstMeta,
-      argValues: [doc],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyAddDocumentConstMeta => const TaskConstMeta(
-        debugName: "add_document",
-        argNames: ["doc"],
-      );
-
-  @override
-  Future<void> crateApiTantivyAddDocumentNoCommit({required Document doc}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_document(doc, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyAddDocumentNoCommitConstMeta,
-      argValues: [doc],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyAddDocumentNoCommitConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_document_no_commit",
-        argNames: ["doc"],
-      );
-
-  @override
-  Future<void> crateApiTantivyAddDocumentsBatch(
-      {required List<Document> docs}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_document(docs, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyAddDocumentsBatchConstMeta,
-      argValues: [docs],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyAddDocumentsBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_documents_batch",
-        argNames: ["docs"],
-      );
-
-  @override
-  void crateApiTantivyCommit() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyCommitConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyCommitConstMeta => const TaskConstMeta(
-        debugName: "commit",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiTantivyDeleteDocument({required String id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyDeleteDocumentConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyDeleteDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_document",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<void> crateApiTantivyDeleteDocumentNoCommit({required String id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyDeleteDocumentNoCommitConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyDeleteDocumentNoCommitConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_document_no_commit",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<void> crateApiTantivyDeleteDocumentsBatch(
-      {required List<String> ids}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(ids, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyDeleteDocumentsBatchConstMeta,
-      argValues: [ids],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyDeleteDocumentsBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_documents_batch",
-        argNames: ["ids"],
-      );
-
-  @override
-  Document? crateApiTantivyGetDocumentById({required String id}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(id, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_document,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyGetDocumentByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyGetDocumentByIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_document_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<Array2I64> crateApiAchoGetEncodingArray(
-      {required List<Encoding> encodings, required EncodingType encodingType}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-            encodings, serializer);
-        sse_encode_encoding_type(encodingType, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAchoGetEncodingArrayConstMeta,
-      argValues: [encodings, encodingType],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAchoGetEncodingArrayConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_encoding_array",
-        argNames: ["encodings", "encodingType"],
-      );
-
-  @override
-  Future<List<SimilarityScore>> crateApiAchoGetTopK(
-      {required List<double> scores, required BigInt k}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_f_32_loose(scores, serializer);
-        sse_encode_usize(k, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_similarity_score,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAchoGetTopKConstMeta,
-      argValues: [scores, k],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAchoGetTopKConstMeta => const TaskConstMeta(
-        debugName: "get_top_k",
-        argNames: ["scores", "k"],
-      );
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(name, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSimpleGreetConstMeta,
-      argValues: [name],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
-        debugName: "greet",
-        argNames: ["name"],
-      );
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSimpleInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  @override
-  void crateApiTantivyInitTantivy({required String dirPath}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dirPath, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyInitTantivyConstMeta,
-      argValues: [dirPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyInitTantivyConstMeta => const TaskConstMeta(
-        debugName: "init_tantivy",
-        argNames: ["dirPath"],
-      );
-
-  @override
-  Future<(Tokenizer, Session)> crateApiAchoLoadArtifacts(
-      {required String modelPath, required String tokenizerPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(modelPath, serializer);
-        sse_encode_String(tokenizerPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAchoLoadArtifactsConstMeta,
-      argValues: [modelPath, tokenizerPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAchoLoadArtifactsConstMeta => const TaskConstMeta(
-        debugName: "load_artifacts",
-        argNames: ["modelPath", "tokenizerPath"],
-      );
-
-  @override
-  Future<Array2F32> crateApiAchoRunInference(
-      {required List<String> text,
-      required Session model,
-      required Tokenizer tokenizer}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(text, serializer);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-            model, serializer);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-            tokenizer, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAchoRunInferenceConstMeta,
-      argValues: [text, model, tokenizer],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAchoRunInferenceConstMeta => const TaskConstMeta(
-        debugName: "run_inference",
-        argNames: ["text", "model", "tokenizer"],
-      );
-
-  @override
-  Future<List<SearchResult>> crateApiTantivySearchDocuments(
-      {required String query, required BigInt topK}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_usize(topK, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_search_result,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivySearchDocumentsConstMeta,
-      argValues: [query, topK],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivySearchDocumentsConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_documents",
-        argNames: ["query", "topK"],
-      );
-
-  @override
-  Future<List<SimilarityScore>> crateApiAchoSimilarity(
-      {required List<String> query,
-      required List<String> texts,
-      required String modelPath,
-      required String tokenizerPath,
-      required BigInt topK}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(query, serializer);
-        sse_encode_list_String(texts, serializer);
-        sse_encode_String(modelPath, serializer);
-        sse_encode_String(tokenizerPath, serializer);
-        sse_encode_usize(topK, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 17, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_similarity_score,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAchoSimilarityConstMeta,
-      argValues: [query, texts, modelPath, tokenizerPath, topK],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAchoSimilarityConstMeta => const TaskConstMeta(
-        debugName: "similarity",
-        argNames: ["query", "texts", "modelPath", "tokenizerPath", "topK"],
-      );
-
-  @override
-  Future<(Array2I64, Array2I64)> crateApiAchoTokenize(
-      {required List<String> texts, required Tokenizer tokenizer}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(texts, serializer);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-            tokenizer, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 18, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAchoTokenizeConstMeta,
-      argValues: [texts, tokenizer],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAchoTokenizeConstMeta => const TaskConstMeta(
-        debugName: "tokenize",
-        argNames: ["texts", "tokenizer"],
-      );
-
-  @override
-  Future<void> crateApiTantivyUpdateDocument({required Document doc}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_document(doc, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 19, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTantivyUpdateDocumentConstMeta,
-      argValues: [doc],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTantivyUpdateDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_document",
-        argNames: ["doc"],
-      );
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Array2F32 => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Array2F32 => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Array2I64 => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Array2I64 => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Encoding => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Encoding => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Session => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Session => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Tokenizer => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Tokenizer => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  Array2F32
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Array2F32Impl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Array2I64
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Array2I64Impl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Encoding
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EncodingImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Session
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Tokenizer
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TokenizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Session
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Tokenizer
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TokenizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Array2F32
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Array2F32Impl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Array2I64
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Array2I64Impl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Encoding
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EncodingImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Session
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Tokenizer
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TokenizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Document dco_decode_box_autoadd_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_document(raw);
-  }
-
-  @protected
-  Document dco_decode_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return Document(
-      id: dco_decode_String(arr[0]),
-      text: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  EncodingType dco_decode_encoding_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EncodingType.values[raw as int];
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<Encoding>
-      dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding)
-        .toList();
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<Document> dco_decode_list_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_document).toList();
-  }
-
-  @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<double>;
-  }
-
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<SearchResult> dco_decode_list_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_search_result).toList();
-  }
-
-  @protected
-  List<SimilarityScore> dco_decode_list_similarity_score(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_similarity_score).toList();
-  }
-
-  @protected
-  Document? dco_decode_opt_box_autoadd_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_document(raw);
-  }
-
-  @protected
-  (
-    Array2I64,
-    Array2I64
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          arr[0]),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          arr[1]),
-    );
-  }
-
-  @protected
-  (
-    Tokenizer,
-    Session
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          arr[0]),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          arr[1]),
-    );
-  }
-
-  @protected
-  SearchResult dco_decode_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SearchResult(
-      score: dco_decode_f_32(arr[0]),
-      doc: dco_decode_document(arr[1]),
-    );
-  }
-
-  @protected
-  SimilarityScore dco_decode_similarity_score(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SimilarityScore(
-      index: dco_decode_usize(arr[0]),
-      score: dco_decode_f_32(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  Array2F32
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Array2F32Impl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Array2I64
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Array2I64Impl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Encoding
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EncodingImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Session
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SessionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Tokenizer
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return TokenizerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Session
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SessionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Tokenizer
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return TokenizerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Array2F32
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Array2F32Impl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Array2I64
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Array2I64Impl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Encoding
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EncodingImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Session
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SessionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Tokenizer
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return TokenizerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Document sse_decode_box_autoadd_document(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_document(deserializer));
-  }
-
-  @protected
-  Document sse_decode_document(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_text = sse_decode_String(deserializer);
-    return Document(id: var_id, text: var_text);
-  }
-
-  @protected
-  EncodingType sse_decode_encoding_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return EncodingType.values[inner];
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<Encoding>
-      sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Encoding>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-              deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Document> sse_decode_list_document(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Document>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_document(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<SearchResult> sse_decode_list_search_result(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SimilarityScore> sse_decode_list_similarity_score(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimilarityScore>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_similarity_score(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Document? sse_decode_opt_box_autoadd_document(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_document(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (
-    Array2I64,
-    Array2I64
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-            deserializer);
-    var var_field1 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-            deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (
-    Tokenizer,
-    Session
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-            deserializer);
-    var var_field1 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-            deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_score = sse_decode_f_32(deserializer);
-    var var_doc = sse_decode_document(deserializer);
-    return SearchResult(score: var_score, doc: var_doc);
-  }
-
-  @protected
-  SimilarityScore sse_decode_similarity_score(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_index = sse_decode_usize(deserializer);
-    var var_score = sse_decode_f_32(deserializer);
-    return SimilarityScore(index: var_index, score: var_score);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          Array2F32 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as Array2F32Impl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          Array2I64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as Array2I64Impl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          Encoding self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as EncodingImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          Session self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SessionImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          Tokenizer self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as TokenizerImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          Session self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SessionImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          Tokenizer self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as TokenizerImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          Array2F32 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as Array2F32Impl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          Array2I64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as Array2I64Impl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          Encoding self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as EncodingImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          Session self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SessionImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          Tokenizer self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as TokenizerImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_document(
-      Document self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_document(self, serializer);
-  }
-
-  @protected
-  void sse_encode_document(Document self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.text, serializer);
-  }
-
-  @protected
-  void sse_encode_encoding_type(EncodingType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void
-      sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          List<Encoding> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_document(List<Document> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_document(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_loose(
-      List<double> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(
-        self is Float32List ? self : Float32List.fromList(self));
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-      Float32List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_search_result(
-      List<SearchResult> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_similarity_score(
-      List<SimilarityScore> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_similarity_score(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_document(
-      Document? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_document(self, serializer);
-    }
-  }
-
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(
-          (Array2I64, Array2I64) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-        self.$1, serializer);
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-        self.$2, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(
-          (Tokenizer, Session) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-        self.$1, serializer);
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-        self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.score, serializer);
-    sse_encode_document(self.doc, serializer);
-  }
-
-  @protected
-  void sse_encode_similarity_score(
-      SimilarityScore self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.index, serializer);
-    sse_encode_f_32(self.score, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
-
-@sealed
-class Array2F32Impl extends RustOpaque implements Array2F32 {
-  // Not to be used by end users
-  Array2F32Impl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Array2F32Impl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Array2F32,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Array2F32,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Array2F32Ptr,
-  );
-}
-
-@sealed
-class Array2I64Impl extends RustOpaque implements Array2I64 {
-  // Not to be used by end users
-  Array2I64Impl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Array2I64Impl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Array2I64,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Array2I64,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Array2I64Ptr,
-  );
-}
-
-@sealed
-class EncodingImpl extends RustOpaque implements Encoding {
-  // Not to be used by end users
-  EncodingImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  EncodingImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Encoding,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Encoding,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_EncodingPtr,
-  );
-}
-
-@sealed
-class SessionImpl extends RustOpaque implements Session {
-  // Not to be used by end users
-  SessionImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Session,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Session,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SessionPtr,
-  );
-}
-
-@sealed
-class TokenizerImpl extends RustOpaque implements Tokenizer {
-  // Not to be used by end users
-  TokenizerImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  TokenizerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Tokenizer,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Tokenizer,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_TokenizerPtr,
-  );
-}
+Future<CrawlConfig> crateApiCrawlerCrawlConfigDefault();
+
+Stream<CrawlEvent> crateApiCrawlerCrawlFiles({required CrawlConfig config });
+
+Future<CrawlSummary> crateApiCrawlerCrawlSummaryDefault();
+
+Future<DeleteReport> crateApiTantivyDeleteByFolder({required String folder });
+
+Future<DeleteReport> crateApiTantivyDeleteByPath({required String path });
+
+Future<DeleteReport> crateApiTantivyDeleteByQuery({required String query });
+
+Future<void> crateApiTantivyDeleteDocument({required String id });
+
+Future<void> crateApiTantivyDeleteDocumentNoCommit({required String id });
+
+Future<void> crateApiTantivyDeleteDocumentsBatch({required List<String> ids });
+
+Future<DocumentMetadata> crateApiTantivyDocumentMetadataDefault();
+
+Future<SnapshotInfo> crateApiSnapshotExportSnapshot({required SnapshotPaths paths , String? settingsJson , required String storageRoot , required String archivePath });
+
+Future<ExtractedFile> crateApiExtractExtractFile({required String path });
+
+Future<PdfText> crateApiPdfExtractPdf({required String path });
+
+Future<BigInt> crateApiTantivyGarbageCollectIndex();
+
+DocumentWithMetadata? crateApiTantivyGetDocumentById({required String id });
+
+Future<Array2I64> crateApiAchoGetEncodingArray({required List<Encoding> encodings , required EncodingType encodingType });
+
+Future<ManifestEntry?> crateApiManifestGetManifestEntry({required String path });
+
+List<String> crateApiHistoryGetSearchHistory({required BigInt limit });
+
+Future<List<SimilarityScore>> crateApiAchoGetTopK({required List<double> scores , required BigInt k });
+
+String crateApiSimpleGreet({required String name });
+
+Future<SnapshotInfo> crateApiSnapshotImportSnapshot({required String archivePath , required SnapshotPaths paths , required String storageRoot });
+
+Future<List<String>> crateApiExtractIndexFile({required String path });
+
+Future<IndexHealth> crateApiTantivyIndexHealth();
+
+Future<IndexStats> crateApiTantivyIndexStats({String? embeddingStoreDir });
+
+IndexStatus crateApiMigrationIndexStatus({required String dirPath });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<void> crateApiOcrInitOcr({required String detectionModelPath , required String recognitionModelPath , required String dictionaryPath });
+
+void crateApiHistoryInitSearchHistory({required String dirPath });
+
+void crateApiTantivyInitTantivy({required String dirPath });
+
+Future<void> crateApiAudioInitTranscription({required String modelPath , required String vocabularyPath , String? language });
+
+bool crateApiOcrIsOcrEnabled();
+
+bool crateApiAudioIsTranscriptionEnabled();
+
+Future<(Tokenizer,Session)> crateApiAchoLoadArtifacts({required String modelPath , required String tokenizerPath });
+
+Future<MergePolicyOptions> crateApiTantivyMergePolicyOptionsDefault();
+
+Future<MigrationReport> crateApiMigrationMigrateIndex({required String dirPath });
+
+MigrationProgress crateApiMigrationMigrationProgress();
+
+Future<MigrationProgress> crateApiMigrationMigrationProgressDefault();
+
+Future<IndexHealth> crateApiTantivyOptimizeIndex();
+
+Future<PdfInfo> crateApiPdfPdfInfoDefault();
+
+Future<PdfInfo> crateApiPdfReadPdfInfo({required String path });
+
+Future<SnapshotInfo> crateApiSnapshotReadSnapshotInfo({required String archivePath });
+
+bool crateApiRecoveryRebuildPending({required String dirPath });
+
+Future<String> crateApiOcrRecognizeImage({required String path });
+
+Future<ReconcileReport> crateApiManifestReconcileFiles({required CrawlConfig config });
+
+Future<ReconcileReport> crateApiManifestReconcileReportDefault();
+
+Future<List<String>> crateApiManifestRecordIndexedFiles({required List<IndexedFile> files });
+
+Future<void> crateApiHistoryRecordSearch({required String query });
+
+Future<void> crateApiTantivyReplaceFileDocuments({required String path , required List<DocumentWithMetadata> docs });
+
+Future<Array2F32> crateApiAchoRunInference({required List<String> text , required Session model , required Tokenizer tokenizer });
+
+Future<SearchResponse> crateApiTantivySearch({required String query , required SearchOptions options });
+
+Future<List<SearchResult>> crateApiTantivySearchDocuments({required String query , required BigInt topK });
+
+Future<SearchFacets> crateApiTantivySearchFacetsDefault();
+
+Future<SearchFilter> crateApiTantivySearchFilterDefault();
+
+Future<SearchOptions> crateApiTantivySearchOptionsDefault();
+
+Future<SearchResponse> crateApiTantivySearchStructured({required Query query , required SearchOptions options });
+
+void crateApiTantivySetMergePolicy({required MergePolicyOptions options });
+
+Future<List<SimilarityScore>> crateApiAchoSimilarity({required List<String> query , required List<String> texts , required String modelPath , required String tokenizerPath , required BigInt topK });
+
+Stream<PdfPage> crateApiPdfStreamPdfPages({required String path });
+
+Future<List<Suggestion>> crateApiHistorySuggest({required String prefix , required BigInt limit });
+
+RecoveryReport? crateApiRecoveryTakeRecoveryReport();
+
+Future<(Array2I64,Array2I64)> crateApiAchoTokenize({required List<String> texts , required Tokenizer tokenizer });
+
+Future<List<TranscriptSegment>> crateApiAudioTranscribeAudio({required String path });
+
+Future<void> crateApiTantivyUpdateDocument({required Document doc });
+
+Future<RecoveryReport?> crateApiTantivyVerifyIndex();
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Array2F32;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Array2F32;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2F32Ptr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Array2I64;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Array2I64;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2I64Ptr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Encoding;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Encoding;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EncodingPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Session;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Session;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Tokenizer;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Tokenizer;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TokenizerPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiTantivyAddDocument({required Document doc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_document(doc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyAddDocumentConstMeta,
+            argValues: [doc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyAddDocumentConstMeta => const TaskConstMeta(
+            debugName: "add_document",
+            argNames: ["doc"],
+        );
+        
+
+@override Future<void> crateApiTantivyAddDocumentNoCommit({required Document doc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_document(doc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyAddDocumentNoCommitConstMeta,
+            argValues: [doc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyAddDocumentNoCommitConstMeta => const TaskConstMeta(
+            debugName: "add_document_no_commit",
+            argNames: ["doc"],
+        );
+        
+
+@override Future<void> crateApiTantivyAddDocumentWithMetadata({required Document doc , required DocumentMetadata metadata })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_document(doc, serializer);
+sse_encode_box_autoadd_document_metadata(metadata, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyAddDocumentWithMetadataConstMeta,
+            argValues: [doc, metadata],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyAddDocumentWithMetadataConstMeta => const TaskConstMeta(
+            debugName: "add_document_with_metadata",
+            argNames: ["doc", "metadata"],
+        );
+        
+
+@override Future<void> crateApiTantivyAddDocumentsBatch({required List<Document> docs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_document(docs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyAddDocumentsBatchConstMeta,
+            argValues: [docs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyAddDocumentsBatchConstMeta => const TaskConstMeta(
+            debugName: "add_documents_batch",
+            argNames: ["docs"],
+        );
+        
+
+@override Future<void> crateApiRecoveryClearRebuildPending({required String dirPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dirPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiRecoveryClearRebuildPendingConstMeta,
+            argValues: [dirPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecoveryClearRebuildPendingConstMeta => const TaskConstMeta(
+            debugName: "clear_rebuild_pending",
+            argNames: ["dirPath"],
+        );
+        
+
+@override Future<void> crateApiHistoryClearSearchHistory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHistoryClearSearchHistoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHistoryClearSearchHistoryConstMeta => const TaskConstMeta(
+            debugName: "clear_search_history",
+            argNames: [],
+        );
+        
+
+@override void crateApiOcrCloseOcr()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOcrCloseOcrConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOcrCloseOcrConstMeta => const TaskConstMeta(
+            debugName: "close_ocr",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTantivyCloseTantivy()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyCloseTantivyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyCloseTantivyConstMeta => const TaskConstMeta(
+            debugName: "close_tantivy",
+            argNames: [],
+        );
+        
+
+@override void crateApiAudioCloseTranscription()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioCloseTranscriptionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioCloseTranscriptionConstMeta => const TaskConstMeta(
+            debugName: "close_transcription",
+            argNames: [],
+        );
+        
+
+@override void crateApiTantivyCommit()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyCommitConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyCommitConstMeta => const TaskConstMeta(
+            debugName: "commit",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiTantivyCommitGeneration()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyCommitGenerationConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyCommitGenerationConstMeta => const TaskConstMeta(
+            debugName: "commit_generation",
+            argNames: [],
+        );
+        
+
+@override Future<CrawlConfig> crateApiCrawlerCrawlConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crawl_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCrawlerCrawlConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrawlerCrawlConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "crawl_config_default",
+            argNames: [],
+        );
+        
+
+@override Stream<CrawlEvent> crateApiCrawlerCrawlFiles({required CrawlConfig config })  { 
+            final sink = RustStreamSink<CrawlEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_crawl_config(config, serializer);
+sse_encode_StreamSink_crawl_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crawl_summary,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCrawlerCrawlFilesConstMeta,
+            argValues: [config, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCrawlerCrawlFilesConstMeta => const TaskConstMeta(
+            debugName: "crawl_files",
+            argNames: ["config", "sink"],
+        );
+        
+
+@override Future<CrawlSummary> crateApiCrawlerCrawlSummaryDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_crawl_summary,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCrawlerCrawlSummaryDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCrawlerCrawlSummaryDefaultConstMeta => const TaskConstMeta(
+            debugName: "crawl_summary_default",
+            argNames: [],
+        );
+        
+
+@override Future<DeleteReport> crateApiTantivyDeleteByFolder({required String folder })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(folder, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delete_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyDeleteByFolderConstMeta,
+            argValues: [folder],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDeleteByFolderConstMeta => const TaskConstMeta(
+            debugName: "delete_by_folder",
+            argNames: ["folder"],
+        );
+        
+
+@override Future<DeleteReport> crateApiTantivyDeleteByPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delete_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyDeleteByPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDeleteByPathConstMeta => const TaskConstMeta(
+            debugName: "delete_by_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<DeleteReport> crateApiTantivyDeleteByQuery({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delete_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyDeleteByQueryConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDeleteByQueryConstMeta => const TaskConstMeta(
+            debugName: "delete_by_query",
+            argNames: ["query"],
+        );
+        
+
+@override Future<void> crateApiTantivyDeleteDocument({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyDeleteDocumentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDeleteDocumentConstMeta => const TaskConstMeta(
+            debugName: "delete_document",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiTantivyDeleteDocumentNoCommit({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyDeleteDocumentNoCommitConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDeleteDocumentNoCommitConstMeta => const TaskConstMeta(
+            debugName: "delete_document_no_commit",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiTantivyDeleteDocumentsBatch({required List<String> ids })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(ids, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyDeleteDocumentsBatchConstMeta,
+            argValues: [ids],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDeleteDocumentsBatchConstMeta => const TaskConstMeta(
+            debugName: "delete_documents_batch",
+            argNames: ["ids"],
+        );
+        
+
+@override Future<DocumentMetadata> crateApiTantivyDocumentMetadataDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_document_metadata,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTantivyDocumentMetadataDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyDocumentMetadataDefaultConstMeta => const TaskConstMeta(
+            debugName: "document_metadata_default",
+            argNames: [],
+        );
+        
+
+@override Future<SnapshotInfo> crateApiSnapshotExportSnapshot({required SnapshotPaths paths , String? settingsJson , required String storageRoot , required String archivePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_snapshot_paths(paths, serializer);
+sse_encode_opt_String(settingsJson, serializer);
+sse_encode_String(storageRoot, serializer);
+sse_encode_String(archivePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snapshot_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSnapshotExportSnapshotConstMeta,
+            argValues: [paths, settingsJson, storageRoot, archivePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSnapshotExportSnapshotConstMeta => const TaskConstMeta(
+            debugName: "export_snapshot",
+            argNames: ["paths", "settingsJson", "storageRoot", "archivePath"],
+        );
+        
+
+@override Future<ExtractedFile> crateApiExtractExtractFile({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_extracted_file,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExtractExtractFileConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExtractExtractFileConstMeta => const TaskConstMeta(
+            debugName: "extract_file",
+            argNames: ["path"],
+        );
+        
+
+@override Future<PdfText> crateApiPdfExtractPdf({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pdf_text,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPdfExtractPdfConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPdfExtractPdfConstMeta => const TaskConstMeta(
+            debugName: "extract_pdf",
+            argNames: ["path"],
+        );
+        
+
+@override Future<BigInt> crateApiTantivyGarbageCollectIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyGarbageCollectIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyGarbageCollectIndexConstMeta => const TaskConstMeta(
+            debugName: "garbage_collect_index",
+            argNames: [],
+        );
+        
+
+@override DocumentWithMetadata? crateApiTantivyGetDocumentById({required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_document_with_metadata,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyGetDocumentByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyGetDocumentByIdConstMeta => const TaskConstMeta(
+            debugName: "get_document_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Array2I64> crateApiAchoGetEncodingArray({required List<Encoding> encodings , required EncodingType encodingType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(encodings, serializer);
+sse_encode_encoding_type(encodingType, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAchoGetEncodingArrayConstMeta,
+            argValues: [encodings, encodingType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAchoGetEncodingArrayConstMeta => const TaskConstMeta(
+            debugName: "get_encoding_array",
+            argNames: ["encodings", "encodingType"],
+        );
+        
+
+@override Future<ManifestEntry?> crateApiManifestGetManifestEntry({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_manifest_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiManifestGetManifestEntryConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiManifestGetManifestEntryConstMeta => const TaskConstMeta(
+            debugName: "get_manifest_entry",
+            argNames: ["path"],
+        );
+        
+
+@override List<String> crateApiHistoryGetSearchHistory({required BigInt limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHistoryGetSearchHistoryConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHistoryGetSearchHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_search_history",
+            argNames: ["limit"],
+        );
+        
+
+@override Future<List<SimilarityScore>> crateApiAchoGetTopK({required List<double> scores , required BigInt k })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_f_32_loose(scores, serializer);
+sse_encode_usize(k, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_similarity_score,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAchoGetTopKConstMeta,
+            argValues: [scores, k],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAchoGetTopKConstMeta => const TaskConstMeta(
+            debugName: "get_top_k",
+            argNames: ["scores", "k"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<SnapshotInfo> crateApiSnapshotImportSnapshot({required String archivePath , required SnapshotPaths paths , required String storageRoot })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(archivePath, serializer);
+sse_encode_box_autoadd_snapshot_paths(paths, serializer);
+sse_encode_String(storageRoot, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snapshot_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSnapshotImportSnapshotConstMeta,
+            argValues: [archivePath, paths, storageRoot],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSnapshotImportSnapshotConstMeta => const TaskConstMeta(
+            debugName: "import_snapshot",
+            argNames: ["archivePath", "paths", "storageRoot"],
+        );
+        
+
+@override Future<List<String>> crateApiExtractIndexFile({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiExtractIndexFileConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExtractIndexFileConstMeta => const TaskConstMeta(
+            debugName: "index_file",
+            argNames: ["path"],
+        );
+        
+
+@override Future<IndexHealth> crateApiTantivyIndexHealth()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_health,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyIndexHealthConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyIndexHealthConstMeta => const TaskConstMeta(
+            debugName: "index_health",
+            argNames: [],
+        );
+        
+
+@override Future<IndexStats> crateApiTantivyIndexStats({String? embeddingStoreDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(embeddingStoreDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyIndexStatsConstMeta,
+            argValues: [embeddingStoreDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyIndexStatsConstMeta => const TaskConstMeta(
+            debugName: "index_stats",
+            argNames: ["embeddingStoreDir"],
+        );
+        
+
+@override IndexStatus crateApiMigrationIndexStatus({required String dirPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dirPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMigrationIndexStatusConstMeta,
+            argValues: [dirPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMigrationIndexStatusConstMeta => const TaskConstMeta(
+            debugName: "index_status",
+            argNames: ["dirPath"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiOcrInitOcr({required String detectionModelPath , required String recognitionModelPath , required String dictionaryPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(detectionModelPath, serializer);
+sse_encode_String(recognitionModelPath, serializer);
+sse_encode_String(dictionaryPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOcrInitOcrConstMeta,
+            argValues: [detectionModelPath, recognitionModelPath, dictionaryPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOcrInitOcrConstMeta => const TaskConstMeta(
+            debugName: "init_ocr",
+            argNames: ["detectionModelPath", "recognitionModelPath", "dictionaryPath"],
+        );
+        
+
+@override void crateApiHistoryInitSearchHistory({required String dirPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dirPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHistoryInitSearchHistoryConstMeta,
+            argValues: [dirPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHistoryInitSearchHistoryConstMeta => const TaskConstMeta(
+            debugName: "init_search_history",
+            argNames: ["dirPath"],
+        );
+        
+
+@override void crateApiTantivyInitTantivy({required String dirPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dirPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyInitTantivyConstMeta,
+            argValues: [dirPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyInitTantivyConstMeta => const TaskConstMeta(
+            debugName: "init_tantivy",
+            argNames: ["dirPath"],
+        );
+        
+
+@override Future<void> crateApiAudioInitTranscription({required String modelPath , required String vocabularyPath , String? language })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(modelPath, serializer);
+sse_encode_String(vocabularyPath, serializer);
+sse_encode_opt_String(language, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAudioInitTranscriptionConstMeta,
+            argValues: [modelPath, vocabularyPath, language],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioInitTranscriptionConstMeta => const TaskConstMeta(
+            debugName: "init_transcription",
+            argNames: ["modelPath", "vocabularyPath", "language"],
+        );
+        
+
+@override bool crateApiOcrIsOcrEnabled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOcrIsOcrEnabledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOcrIsOcrEnabledConstMeta => const TaskConstMeta(
+            debugName: "is_ocr_enabled",
+            argNames: [],
+        );
+        
+
+@override bool crateApiAudioIsTranscriptionEnabled()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAudioIsTranscriptionEnabledConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioIsTranscriptionEnabledConstMeta => const TaskConstMeta(
+            debugName: "is_transcription_enabled",
+            argNames: [],
+        );
+        
+
+@override Future<(Tokenizer,Session)> crateApiAchoLoadArtifacts({required String modelPath , required String tokenizerPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(modelPath, serializer);
+sse_encode_String(tokenizerPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAchoLoadArtifactsConstMeta,
+            argValues: [modelPath, tokenizerPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAchoLoadArtifactsConstMeta => const TaskConstMeta(
+            debugName: "load_artifacts",
+            argNames: ["modelPath", "tokenizerPath"],
+        );
+        
+
+@override Future<MergePolicyOptions> crateApiTantivyMergePolicyOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_merge_policy_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTantivyMergePolicyOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyMergePolicyOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "merge_policy_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<MigrationReport> crateApiMigrationMigrateIndex({required String dirPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dirPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_migration_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMigrationMigrateIndexConstMeta,
+            argValues: [dirPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMigrationMigrateIndexConstMeta => const TaskConstMeta(
+            debugName: "migrate_index",
+            argNames: ["dirPath"],
+        );
+        
+
+@override MigrationProgress crateApiMigrationMigrationProgress()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_migration_progress,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMigrationMigrationProgressConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMigrationMigrationProgressConstMeta => const TaskConstMeta(
+            debugName: "migration_progress",
+            argNames: [],
+        );
+        
+
+@override Future<MigrationProgress> crateApiMigrationMigrationProgressDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_migration_progress,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMigrationMigrationProgressDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMigrationMigrationProgressDefaultConstMeta => const TaskConstMeta(
+            debugName: "migration_progress_default",
+            argNames: [],
+        );
+        
+
+@override Future<IndexHealth> crateApiTantivyOptimizeIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_index_health,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyOptimizeIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyOptimizeIndexConstMeta => const TaskConstMeta(
+            debugName: "optimize_index",
+            argNames: [],
+        );
+        
+
+@override Future<PdfInfo> crateApiPdfPdfInfoDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pdf_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPdfPdfInfoDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPdfPdfInfoDefaultConstMeta => const TaskConstMeta(
+            debugName: "pdf_info_default",
+            argNames: [],
+        );
+        
+
+@override Future<PdfInfo> crateApiPdfReadPdfInfo({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pdf_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPdfReadPdfInfoConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPdfReadPdfInfoConstMeta => const TaskConstMeta(
+            debugName: "read_pdf_info",
+            argNames: ["path"],
+        );
+        
+
+@override Future<SnapshotInfo> crateApiSnapshotReadSnapshotInfo({required String archivePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(archivePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snapshot_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSnapshotReadSnapshotInfoConstMeta,
+            argValues: [archivePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSnapshotReadSnapshotInfoConstMeta => const TaskConstMeta(
+            debugName: "read_snapshot_info",
+            argNames: ["archivePath"],
+        );
+        
+
+@override bool crateApiRecoveryRebuildPending({required String dirPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dirPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecoveryRebuildPendingConstMeta,
+            argValues: [dirPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecoveryRebuildPendingConstMeta => const TaskConstMeta(
+            debugName: "rebuild_pending",
+            argNames: ["dirPath"],
+        );
+        
+
+@override Future<String> crateApiOcrRecognizeImage({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOcrRecognizeImageConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOcrRecognizeImageConstMeta => const TaskConstMeta(
+            debugName: "recognize_image",
+            argNames: ["path"],
+        );
+        
+
+@override Future<ReconcileReport> crateApiManifestReconcileFiles({required CrawlConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_crawl_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reconcile_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiManifestReconcileFilesConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiManifestReconcileFilesConstMeta => const TaskConstMeta(
+            debugName: "reconcile_files",
+            argNames: ["config"],
+        );
+        
+
+@override Future<ReconcileReport> crateApiManifestReconcileReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reconcile_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiManifestReconcileReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiManifestReconcileReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "reconcile_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiManifestRecordIndexedFiles({required List<IndexedFile> files })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_indexed_file(files, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiManifestRecordIndexedFilesConstMeta,
+            argValues: [files],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiManifestRecordIndexedFilesConstMeta => const TaskConstMeta(
+            debugName: "record_indexed_files",
+            argNames: ["files"],
+        );
+        
+
+@override Future<void> crateApiHistoryRecordSearch({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHistoryRecordSearchConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHistoryRecordSearchConstMeta => const TaskConstMeta(
+            debugName: "record_search",
+            argNames: ["query"],
+        );
+        
+
+@override Future<void> crateApiTantivyReplaceFileDocuments({required String path , required List<DocumentWithMetadata> docs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_document_with_metadata(docs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyReplaceFileDocumentsConstMeta,
+            argValues: [path, docs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyReplaceFileDocumentsConstMeta => const TaskConstMeta(
+            debugName: "replace_file_documents",
+            argNames: ["path", "docs"],
+        );
+        
+
+@override Future<Array2F32> crateApiAchoRunInference({required List<String> text , required Session model , required Tokenizer tokenizer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(text, serializer);
+sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(model, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(tokenizer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAchoRunInferenceConstMeta,
+            argValues: [text, model, tokenizer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAchoRunInferenceConstMeta => const TaskConstMeta(
+            debugName: "run_inference",
+            argNames: ["text", "model", "tokenizer"],
+        );
+        
+
+@override Future<SearchResponse> crateApiTantivySearch({required String query , required SearchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivySearchConstMeta,
+            argValues: [query, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySearchConstMeta => const TaskConstMeta(
+            debugName: "search",
+            argNames: ["query", "options"],
+        );
+        
+
+@override Future<List<SearchResult>> crateApiTantivySearchDocuments({required String query , required BigInt topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_usize(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivySearchDocumentsConstMeta,
+            argValues: [query, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySearchDocumentsConstMeta => const TaskConstMeta(
+            debugName: "search_documents",
+            argNames: ["query", "topK"],
+        );
+        
+
+@override Future<SearchFacets> crateApiTantivySearchFacetsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_facets,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTantivySearchFacetsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySearchFacetsDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_facets_default",
+            argNames: [],
+        );
+        
+
+@override Future<SearchFilter> crateApiTantivySearchFilterDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_filter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTantivySearchFilterDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySearchFilterDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_filter_default",
+            argNames: [],
+        );
+        
+
+@override Future<SearchOptions> crateApiTantivySearchOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTantivySearchOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySearchOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<SearchResponse> crateApiTantivySearchStructured({required Query query , required SearchOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_query(query, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivySearchStructuredConstMeta,
+            argValues: [query, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySearchStructuredConstMeta => const TaskConstMeta(
+            debugName: "search_structured",
+            argNames: ["query", "options"],
+        );
+        
+
+@override void crateApiTantivySetMergePolicy({required MergePolicyOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_merge_policy_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivySetMergePolicyConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivySetMergePolicyConstMeta => const TaskConstMeta(
+            debugName: "set_merge_policy",
+            argNames: ["options"],
+        );
+        
+
+@override Future<List<SimilarityScore>> crateApiAchoSimilarity({required List<String> query , required List<String> texts , required String modelPath , required String tokenizerPath , required BigInt topK })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(query, serializer);
+sse_encode_list_String(texts, serializer);
+sse_encode_String(modelPath, serializer);
+sse_encode_String(tokenizerPath, serializer);
+sse_encode_usize(topK, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_similarity_score,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAchoSimilarityConstMeta,
+            argValues: [query, texts, modelPath, tokenizerPath, topK],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAchoSimilarityConstMeta => const TaskConstMeta(
+            debugName: "similarity",
+            argNames: ["query", "texts", "modelPath", "tokenizerPath", "topK"],
+        );
+        
+
+@override Stream<PdfPage> crateApiPdfStreamPdfPages({required String path })  { 
+            final sink = RustStreamSink<PdfPage>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_StreamSink_pdf_page_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pdf_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPdfStreamPdfPagesConstMeta,
+            argValues: [path, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiPdfStreamPdfPagesConstMeta => const TaskConstMeta(
+            debugName: "stream_pdf_pages",
+            argNames: ["path", "sink"],
+        );
+        
+
+@override Future<List<Suggestion>> crateApiHistorySuggest({required String prefix , required BigInt limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+sse_encode_usize(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_suggestion,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiHistorySuggestConstMeta,
+            argValues: [prefix, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHistorySuggestConstMeta => const TaskConstMeta(
+            debugName: "suggest",
+            argNames: ["prefix", "limit"],
+        );
+        
+
+@override RecoveryReport? crateApiRecoveryTakeRecoveryReport()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_recovery_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecoveryTakeRecoveryReportConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecoveryTakeRecoveryReportConstMeta => const TaskConstMeta(
+            debugName: "take_recovery_report",
+            argNames: [],
+        );
+        
+
+@override Future<(Array2I64,Array2I64)> crateApiAchoTokenize({required List<String> texts , required Tokenizer tokenizer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(texts, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(tokenizer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAchoTokenizeConstMeta,
+            argValues: [texts, tokenizer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAchoTokenizeConstMeta => const TaskConstMeta(
+            debugName: "tokenize",
+            argNames: ["texts", "tokenizer"],
+        );
+        
+
+@override Future<List<TranscriptSegment>> crateApiAudioTranscribeAudio({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_transcript_segment,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAudioTranscribeAudioConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAudioTranscribeAudioConstMeta => const TaskConstMeta(
+            debugName: "transcribe_audio",
+            argNames: ["path"],
+        );
+        
+
+@override Future<void> crateApiTantivyUpdateDocument({required Document doc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_document(doc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyUpdateDocumentConstMeta,
+            argValues: [doc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyUpdateDocumentConstMeta => const TaskConstMeta(
+            debugName: "update_document",
+            argNames: ["doc"],
+        );
+        
+
+@override Future<RecoveryReport?> crateApiTantivyVerifyIndex()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_recovery_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTantivyVerifyIndexConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTantivyVerifyIndexConstMeta => const TaskConstMeta(
+            debugName: "verify_index",
+            argNames: [],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Array2F32 => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Array2F32 => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Array2I64 => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Array2I64 => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Encoding => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Encoding => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Session => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Session => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Tokenizer => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Tokenizer => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected Array2F32 dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Array2F32Impl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Array2I64 dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Array2I64Impl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Encoding dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EncodingImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Session dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Tokenizer dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TokenizerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Session dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Tokenizer dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TokenizerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Array2F32 dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Array2F32Impl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Array2I64 dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Array2I64Impl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Encoding dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EncodingImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Session dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Tokenizer dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TokenizerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<CrawlEvent> dco_decode_StreamSink_crawl_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<PdfPage> dco_decode_StreamSink_pdf_page_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CrawlConfig dco_decode_box_autoadd_crawl_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_crawl_config(raw); }
+
+@protected Document dco_decode_box_autoadd_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_document(raw); }
+
+@protected DocumentMetadata dco_decode_box_autoadd_document_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_document_metadata(raw); }
+
+@protected DocumentWithMetadata dco_decode_box_autoadd_document_with_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_document_with_metadata(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected ManifestEntry dco_decode_box_autoadd_manifest_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_manifest_entry(raw); }
+
+@protected MergePolicyOptions dco_decode_box_autoadd_merge_policy_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_merge_policy_options(raw); }
+
+@protected Query dco_decode_box_autoadd_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query(raw); }
+
+@protected RecoveryReport dco_decode_box_autoadd_recovery_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_recovery_report(raw); }
+
+@protected SearchOptions dco_decode_box_autoadd_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_options(raw); }
+
+@protected SnapshotPaths dco_decode_box_autoadd_snapshot_paths(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_snapshot_paths(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected Query dco_decode_box_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query(raw); }
+
+@protected ChangeKind dco_decode_change_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChangeKind.values[raw as int]; }
+
+@protected CrawlConfig dco_decode_crawl_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CrawlConfig(roots: dco_decode_list_String(arr[0]),
+extensions: dco_decode_list_String(arr[1]),
+excludeGlobs: dco_decode_list_String(arr[2]),
+maxDepth: dco_decode_opt_box_autoadd_u_32(arr[3]),
+symlinks: dco_decode_symlink_policy(arr[4]),); }
+
+@protected CrawlEvent dco_decode_crawl_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CrawlEvent_File(path: dco_decode_String(raw[1]),size: dco_decode_u_64(raw[2]),modified: dco_decode_opt_box_autoadd_i_64(raw[3]),);
+case 1: return CrawlEvent_Skipped(path: dco_decode_String(raw[1]),reason: dco_decode_skip_reason(raw[2]),);
+case 2: return CrawlEvent_Error(path: dco_decode_String(raw[1]),message: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected CrawlSummary dco_decode_crawl_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CrawlSummary(files: dco_decode_u_64(arr[0]),
+skipped: dco_decode_u_64(arr[1]),
+errors: dco_decode_u_64(arr[2]),
+cancelled: dco_decode_bool(arr[3]),); }
+
+@protected DeleteReport dco_decode_delete_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DeleteReport(deletedCount: dco_decode_u_64(arr[0]),
+deletedIds: dco_decode_list_String(arr[1]),
+committed: dco_decode_bool(arr[2]),); }
+
+@protected Document dco_decode_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Document(id: dco_decode_String(arr[0]),
+text: dco_decode_String(arr[1]),); }
+
+@protected DocumentMetadata dco_decode_document_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+                return DocumentMetadata(path: dco_decode_String(arr[0]),
+modified: dco_decode_opt_box_autoadd_i_64(arr[1]),
+language: dco_decode_opt_String(arr[2]),
+title: dco_decode_opt_String(arr[3]),
+author: dco_decode_opt_String(arr[4]),
+chapter: dco_decode_opt_String(arr[5]),
+chapterIndex: dco_decode_opt_box_autoadd_u_32(arr[6]),
+headings: dco_decode_list_String(arr[7]),
+chat: dco_decode_opt_String(arr[8]),
+senders: dco_decode_list_String(arr[9]),
+timestamp: dco_decode_opt_box_autoadd_i_64(arr[10]),
+sheet: dco_decode_opt_String(arr[11]),
+row: dco_decode_opt_box_autoadd_u_32(arr[12]),
+startMs: dco_decode_opt_box_autoadd_u_64(arr[13]),
+endMs: dco_decode_opt_box_autoadd_u_64(arr[14]),
+subject: dco_decode_opt_String(arr[15]),
+recipients: dco_decode_list_String(arr[16]),); }
+
+@protected DocumentWithMetadata dco_decode_document_with_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DocumentWithMetadata(doc: dco_decode_document(arr[0]),
+metadata: dco_decode_document_metadata(arr[1]),); }
+
+@protected EncodingType dco_decode_encoding_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EncodingType.values[raw as int]; }
+
+@protected ExtractedFile dco_decode_extracted_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ExtractedFile(path: dco_decode_String(arr[0]),
+title: dco_decode_opt_String(arr[1]),
+author: dco_decode_opt_String(arr[2]),
+documents: dco_decode_list_document_with_metadata(arr[3]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FacetCount dco_decode_facet_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FacetCount(value: dco_decode_String(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected FileToIndex dco_decode_file_to_index(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FileToIndex(path: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),
+modified: dco_decode_opt_box_autoadd_i_64(arr[2]),
+hash: dco_decode_String(arr[3]),
+kind: dco_decode_change_kind(arr[4]),); }
+
+@protected HighlightRange dco_decode_highlight_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HighlightRange(start: dco_decode_usize(arr[0]),
+end: dco_decode_usize(arr[1]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IndexHealth dco_decode_index_health(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return IndexHealth(segmentCount: dco_decode_usize(arr[0]),
+numDocs: dco_decode_u_64(arr[1]),
+numDeletedDocs: dco_decode_u_64(arr[2]),
+deletedRatio: dco_decode_f_64(arr[3]),
+diskBytes: dco_decode_u_64(arr[4]),); }
+
+@protected IndexStats dco_decode_index_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return IndexStats(chunkCount: dco_decode_u_64(arr[0]),
+fileCount: dco_decode_u_64(arr[1]),
+extensions: dco_decode_list_facet_count(arr[2]),
+languages: dco_decode_list_facet_count(arr[3]),
+segmentCount: dco_decode_usize(arr[4]),
+deletedCount: dco_decode_u_64(arr[5]),
+indexBytes: dco_decode_u_64(arr[6]),
+embeddingStoreBytes: dco_decode_u_64(arr[7]),
+lastCommit: dco_decode_opt_box_autoadd_i_64(arr[8]),); }
+
+@protected IndexStatus dco_decode_index_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return IndexStatus(version: dco_decode_opt_box_autoadd_u_32(arr[0]),
+currentVersion: dco_decode_u_32(arr[1]),
+needsMigration: dco_decode_bool(arr[2]),); }
+
+@protected IndexedFile dco_decode_indexed_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IndexedFile(file: dco_decode_file_to_index(arr[0]),
+chunkIds: dco_decode_list_String(arr[1]),); }
+
+@protected List<Encoding> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding).toList(); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Document> dco_decode_list_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_document).toList(); }
+
+@protected List<DocumentWithMetadata> dco_decode_list_document_with_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_document_with_metadata).toList(); }
+
+@protected List<FacetCount> dco_decode_list_facet_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_facet_count).toList(); }
+
+@protected List<FileToIndex> dco_decode_list_file_to_index(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_file_to_index).toList(); }
+
+@protected List<HighlightRange> dco_decode_list_highlight_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_highlight_range).toList(); }
+
+@protected List<IndexedFile> dco_decode_list_indexed_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_indexed_file).toList(); }
+
+@protected List<PdfPage> dco_decode_list_pdf_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_pdf_page).toList(); }
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<Query> dco_decode_list_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<SearchResult> dco_decode_list_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_result).toList(); }
+
+@protected List<SimilarityScore> dco_decode_list_similarity_score(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_similarity_score).toList(); }
+
+@protected List<Snippet> dco_decode_list_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_snippet).toList(); }
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_suggestion).toList(); }
+
+@protected List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_transcript_segment).toList(); }
+
+@protected ManifestEntry dco_decode_manifest_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ManifestEntry(path: dco_decode_String(arr[0]),
+size: dco_decode_u_64(arr[1]),
+modified: dco_decode_opt_box_autoadd_i_64(arr[2]),
+hash: dco_decode_String(arr[3]),
+chunkIds: dco_decode_list_String(arr[4]),
+indexedAt: dco_decode_i_64(arr[5]),); }
+
+@protected MergePolicyOptions dco_decode_merge_policy_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MergePolicyOptions(enabled: dco_decode_bool(arr[0]),
+minNumSegments: dco_decode_usize(arr[1]),
+maxDocsBeforeMerge: dco_decode_usize(arr[2]),
+deletedRatioBeforeMerge: dco_decode_f_32(arr[3]),); }
+
+@protected MigrationProgress dco_decode_migration_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MigrationProgress(running: dco_decode_bool(arr[0]),
+processed: dco_decode_u_64(arr[1]),
+total: dco_decode_u_64(arr[2]),); }
+
+@protected MigrationReport dco_decode_migration_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MigrationReport(fromVersion: dco_decode_u_32(arr[0]),
+toVersion: dco_decode_u_32(arr[1]),
+documentsMigrated: dco_decode_u_64(arr[2]),
+rebuildRequired: dco_decode_bool(arr[3]),
+error: dco_decode_opt_String(arr[4]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected DocumentWithMetadata? dco_decode_opt_box_autoadd_document_with_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_document_with_metadata(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected ManifestEntry? dco_decode_opt_box_autoadd_manifest_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_manifest_entry(raw); }
+
+@protected RecoveryReport? dco_decode_opt_box_autoadd_recovery_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_recovery_report(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected PdfInfo dco_decode_pdf_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PdfInfo(title: dco_decode_opt_String(arr[0]),
+author: dco_decode_opt_String(arr[1]),
+creationDate: dco_decode_opt_box_autoadd_i_64(arr[2]),
+pageCount: dco_decode_u_32(arr[3]),); }
+
+@protected PdfPage dco_decode_pdf_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PdfPage(pageNumber: dco_decode_u_32(arr[0]),
+text: dco_decode_String(arr[1]),
+error: dco_decode_opt_String(arr[2]),); }
+
+@protected PdfText dco_decode_pdf_text(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PdfText(info: dco_decode_pdf_info(arr[0]),
+pages: dco_decode_list_pdf_page(arr[1]),); }
+
+@protected Query dco_decode_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return Query_Term(text: dco_decode_String(raw[1]),);
+case 1: return Query_Phrase(text: dco_decode_String(raw[1]),slop: dco_decode_u_32(raw[2]),);
+case 2: return Query_Boolean(must: dco_decode_list_query(raw[1]),should: dco_decode_list_query(raw[2]),mustNot: dco_decode_list_query(raw[3]),);
+case 3: return Query_Field(field: dco_decode_String(raw[1]),query: dco_decode_box_query(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ReconcileReport dco_decode_reconcile_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ReconcileReport(toIndex: dco_decode_list_file_to_index(arr[0]),
+removed: dco_decode_list_String(arr[1]),
+deletedChunkIds: dco_decode_list_String(arr[2]),
+unchanged: dco_decode_u_64(arr[3]),
+errors: dco_decode_list_String(arr[4]),); }
+
+@protected (Array2I64,Array2I64) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(arr[0]),dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(arr[1]),); }
+
+@protected (Tokenizer,Session) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(arr[0]),dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(arr[1]),); }
+
+@protected RecoveryReason dco_decode_recovery_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecoveryReason.values[raw as int]; }
+
+@protected RecoveryReport dco_decode_recovery_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RecoveryReport(reason: dco_decode_recovery_reason(arr[0]),
+problems: dco_decode_list_String(arr[1]),
+quarantineDir: dco_decode_opt_String(arr[2]),
+occurredAt: dco_decode_i_64(arr[3]),); }
+
+@protected SearchFacets dco_decode_search_facets(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SearchFacets(extensions: dco_decode_list_facet_count(arr[0]),
+folders: dco_decode_list_facet_count(arr[1]),
+years: dco_decode_list_facet_count(arr[2]),
+languages: dco_decode_list_facet_count(arr[3]),); }
+
+@protected SearchFilter dco_decode_search_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SearchFilter(extensions: dco_decode_list_String(arr[0]),
+folderPrefix: dco_decode_opt_String(arr[1]),
+modifiedAfter: dco_decode_opt_box_autoadd_i_64(arr[2]),
+modifiedBefore: dco_decode_opt_box_autoadd_i_64(arr[3]),
+languages: dco_decode_list_String(arr[4]),); }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SearchHit(score: dco_decode_f_32(arr[0]),
+doc: dco_decode_document(arr[1]),
+metadata: dco_decode_document_metadata(arr[2]),
+snippets: dco_decode_list_snippet(arr[3]),); }
+
+@protected SearchOptions dco_decode_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return SearchOptions(topK: dco_decode_usize(arr[0]),
+offset: dco_decode_usize(arr[1]),
+filter: dco_decode_search_filter(arr[2]),
+maxSnippets: dco_decode_usize(arr[3]),
+snippetMaxChars: dco_decode_usize(arr[4]),
+minGeneration: dco_decode_u_64(arr[5]),
+fuzzyDistance: dco_decode_u_8(arr[6]),
+suggestBelowHits: dco_decode_usize(arr[7]),); }
+
+@protected SearchResponse dco_decode_search_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SearchResponse(hits: dco_decode_list_search_hit(arr[0]),
+totalHits: dco_decode_usize(arr[1]),
+facets: dco_decode_search_facets(arr[2]),
+suggestions: dco_decode_list_String(arr[3]),
+queryWarnings: dco_decode_list_String(arr[4]),); }
+
+@protected SearchResult dco_decode_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SearchResult(score: dco_decode_f_32(arr[0]),
+doc: dco_decode_document(arr[1]),
+metadata: dco_decode_document_metadata(arr[2]),); }
+
+@protected SimilarityScore dco_decode_similarity_score(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SimilarityScore(index: dco_decode_usize(arr[0]),
+score: dco_decode_f_32(arr[1]),); }
+
+@protected SkipReason dco_decode_skip_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SkipReason.values[raw as int]; }
+
+@protected SnapshotInfo dco_decode_snapshot_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SnapshotInfo(schemaVersion: dco_decode_u_32(arr[0]),
+createdAt: dco_decode_i_64(arr[1]),
+storageRoot: dco_decode_String(arr[2]),
+documentCount: dco_decode_u_64(arr[3]),
+hasEmbeddings: dco_decode_bool(arr[4]),
+hasFileManifest: dco_decode_bool(arr[5]),
+settingsJson: dco_decode_opt_String(arr[6]),); }
+
+@protected SnapshotPaths dco_decode_snapshot_paths(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SnapshotPaths(indexDir: dco_decode_String(arr[0]),
+embeddingStoreDir: dco_decode_opt_String(arr[1]),
+fileManifestPath: dco_decode_opt_String(arr[2]),); }
+
+@protected Snippet dco_decode_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Snippet(fragment: dco_decode_String(arr[0]),
+offset: dco_decode_usize(arr[1]),
+highlights: dco_decode_list_highlight_range(arr[2]),); }
+
+@protected Suggestion dco_decode_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Suggestion(text: dco_decode_String(arr[0]),
+source: dco_decode_suggestion_source(arr[1]),); }
+
+@protected SuggestionSource dco_decode_suggestion_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SuggestionSource.values[raw as int]; }
+
+@protected SymlinkPolicy dco_decode_symlink_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SymlinkPolicy.values[raw as int]; }
+
+@protected TranscriptSegment dco_decode_transcript_segment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TranscriptSegment(startMs: dco_decode_u_64(arr[0]),
+endMs: dco_decode_u_64(arr[1]),
+text: dco_decode_String(arr[2]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected Array2F32 sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Array2F32Impl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Array2I64 sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Array2I64Impl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Encoding sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EncodingImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Session sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Tokenizer sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TokenizerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Session sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Tokenizer sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TokenizerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Array2F32 sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Array2F32Impl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Array2I64 sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Array2I64Impl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Encoding sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EncodingImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Session sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Tokenizer sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return TokenizerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<CrawlEvent> sse_decode_StreamSink_crawl_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<PdfPage> sse_decode_StreamSink_pdf_page_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected CrawlConfig sse_decode_box_autoadd_crawl_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_crawl_config(deserializer)); }
+
+@protected Document sse_decode_box_autoadd_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_document(deserializer)); }
+
+@protected DocumentMetadata sse_decode_box_autoadd_document_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_document_metadata(deserializer)); }
+
+@protected DocumentWithMetadata sse_decode_box_autoadd_document_with_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_document_with_metadata(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected ManifestEntry sse_decode_box_autoadd_manifest_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_manifest_entry(deserializer)); }
+
+@protected MergePolicyOptions sse_decode_box_autoadd_merge_policy_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_merge_policy_options(deserializer)); }
+
+@protected Query sse_decode_box_autoadd_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query(deserializer)); }
+
+@protected RecoveryReport sse_decode_box_autoadd_recovery_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_recovery_report(deserializer)); }
+
+@protected SearchOptions sse_decode_box_autoadd_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_options(deserializer)); }
+
+@protected SnapshotPaths sse_decode_box_autoadd_snapshot_paths(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_snapshot_paths(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected Query sse_decode_box_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query(deserializer)); }
+
+@protected ChangeKind sse_decode_change_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChangeKind.values[inner]; }
+
+@protected CrawlConfig sse_decode_crawl_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_roots = sse_decode_list_String(deserializer);
+var var_extensions = sse_decode_list_String(deserializer);
+var var_excludeGlobs = sse_decode_list_String(deserializer);
+var var_maxDepth = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_symlinks = sse_decode_symlink_policy(deserializer);
+return CrawlConfig(roots: var_roots, extensions: var_extensions, excludeGlobs: var_excludeGlobs, maxDepth: var_maxDepth, symlinks: var_symlinks); }
+
+@protected CrawlEvent sse_decode_crawl_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_modified = sse_decode_opt_box_autoadd_i_64(deserializer);
+return CrawlEvent_File(path: var_path, size: var_size, modified: var_modified);case 1: var var_path = sse_decode_String(deserializer);
+var var_reason = sse_decode_skip_reason(deserializer);
+return CrawlEvent_Skipped(path: var_path, reason: var_reason);case 2: var var_path = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return CrawlEvent_Error(path: var_path, message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected CrawlSummary sse_decode_crawl_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_files = sse_decode_u_64(deserializer);
+var var_skipped = sse_decode_u_64(deserializer);
+var var_errors = sse_decode_u_64(deserializer);
+var var_cancelled = sse_decode_bool(deserializer);
+return CrawlSummary(files: var_files, skipped: var_skipped, errors: var_errors, cancelled: var_cancelled); }
+
+@protected DeleteReport sse_decode_delete_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_deletedCount = sse_decode_u_64(deserializer);
+var var_deletedIds = sse_decode_list_String(deserializer);
+var var_committed = sse_decode_bool(deserializer);
+return DeleteReport(deletedCount: var_deletedCount, deletedIds: var_deletedIds, committed: var_committed); }
+
+@protected Document sse_decode_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+return Document(id: var_id, text: var_text); }
+
+@protected DocumentMetadata sse_decode_document_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_modified = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_language = sse_decode_opt_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_author = sse_decode_opt_String(deserializer);
+var var_chapter = sse_decode_opt_String(deserializer);
+var var_chapterIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_headings = sse_decode_list_String(deserializer);
+var var_chat = sse_decode_opt_String(deserializer);
+var var_senders = sse_decode_list_String(deserializer);
+var var_timestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_sheet = sse_decode_opt_String(deserializer);
+var var_row = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_startMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_endMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_subject = sse_decode_opt_String(deserializer);
+var var_recipients = sse_decode_list_String(deserializer);
+return DocumentMetadata(path: var_path, modified: var_modified, language: var_language, title: var_title, author: var_author, chapter: var_chapter, chapterIndex: var_chapterIndex, headings: var_headings, chat: var_chat, senders: var_senders, timestamp: var_timestamp, sheet: var_sheet, row: var_row, startMs: var_startMs, endMs: var_endMs, subject: var_subject, recipients: var_recipients); }
+
+@protected DocumentWithMetadata sse_decode_document_with_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_doc = sse_decode_document(deserializer);
+var var_metadata = sse_decode_document_metadata(deserializer);
+return DocumentWithMetadata(doc: var_doc, metadata: var_metadata); }
+
+@protected EncodingType sse_decode_encoding_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return EncodingType.values[inner]; }
+
+@protected ExtractedFile sse_decode_extracted_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_title = sse_decode_opt_String(deserializer);
+var var_author = sse_decode_opt_String(deserializer);
+var var_documents = sse_decode_list_document_with_metadata(deserializer);
+return ExtractedFile(path: var_path, title: var_title, author: var_author, documents: var_documents); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FacetCount sse_decode_facet_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_String(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return FacetCount(value: var_value, count: var_count); }
+
+@protected FileToIndex sse_decode_file_to_index(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_modified = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_hash = sse_decode_String(deserializer);
+var var_kind = sse_decode_change_kind(deserializer);
+return FileToIndex(path: var_path, size: var_size, modified: var_modified, hash: var_hash, kind: var_kind); }
+
+@protected HighlightRange sse_decode_highlight_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_usize(deserializer);
+var var_end = sse_decode_usize(deserializer);
+return HighlightRange(start: var_start, end: var_end); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IndexHealth sse_decode_index_health(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_segmentCount = sse_decode_usize(deserializer);
+var var_numDocs = sse_decode_u_64(deserializer);
+var var_numDeletedDocs = sse_decode_u_64(deserializer);
+var var_deletedRatio = sse_decode_f_64(deserializer);
+var var_diskBytes = sse_decode_u_64(deserializer);
+return IndexHealth(segmentCount: var_segmentCount, numDocs: var_numDocs, numDeletedDocs: var_numDeletedDocs, deletedRatio: var_deletedRatio, diskBytes: var_diskBytes); }
+
+@protected IndexStats sse_decode_index_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_chunkCount = sse_decode_u_64(deserializer);
+var var_fileCount = sse_decode_u_64(deserializer);
+var var_extensions = sse_decode_list_facet_count(deserializer);
+var var_languages = sse_decode_list_facet_count(deserializer);
+var var_segmentCount = sse_decode_usize(deserializer);
+var var_deletedCount = sse_decode_u_64(deserializer);
+var var_indexBytes = sse_decode_u_64(deserializer);
+var var_embeddingStoreBytes = sse_decode_u_64(deserializer);
+var var_lastCommit = sse_decode_opt_box_autoadd_i_64(deserializer);
+return IndexStats(chunkCount: var_chunkCount, fileCount: var_fileCount, extensions: var_extensions, languages: var_languages, segmentCount: var_segmentCount, deletedCount: var_deletedCount, indexBytes: var_indexBytes, embeddingStoreBytes: var_embeddingStoreBytes, lastCommit: var_lastCommit); }
+
+@protected IndexStatus sse_decode_index_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_version = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_currentVersion = sse_decode_u_32(deserializer);
+var var_needsMigration = sse_decode_bool(deserializer);
+return IndexStatus(version: var_version, currentVersion: var_currentVersion, needsMigration: var_needsMigration); }
+
+@protected IndexedFile sse_decode_indexed_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_file = sse_decode_file_to_index(deserializer);
+var var_chunkIds = sse_decode_list_String(deserializer);
+return IndexedFile(file: var_file, chunkIds: var_chunkIds); }
+
+@protected List<Encoding> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Encoding>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Document> sse_decode_list_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Document>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_document(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DocumentWithMetadata> sse_decode_list_document_with_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DocumentWithMetadata>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_document_with_metadata(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FacetCount> sse_decode_list_facet_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FacetCount>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_facet_count(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FileToIndex> sse_decode_list_file_to_index(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FileToIndex>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_file_to_index(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HighlightRange> sse_decode_list_highlight_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HighlightRange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_highlight_range(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IndexedFile> sse_decode_list_indexed_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IndexedFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_indexed_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PdfPage> sse_decode_list_pdf_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PdfPage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_pdf_page(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<Query> sse_decode_list_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Query>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchResult> sse_decode_list_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SimilarityScore> sse_decode_list_similarity_score(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SimilarityScore>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_similarity_score(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Snippet> sse_decode_list_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Snippet>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_snippet(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Suggestion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_suggestion(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TranscriptSegment> sse_decode_list_transcript_segment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TranscriptSegment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_transcript_segment(deserializer)); }
+        return ans_;
+         }
+
+@protected ManifestEntry sse_decode_manifest_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_modified = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_hash = sse_decode_String(deserializer);
+var var_chunkIds = sse_decode_list_String(deserializer);
+var var_indexedAt = sse_decode_i_64(deserializer);
+return ManifestEntry(path: var_path, size: var_size, modified: var_modified, hash: var_hash, chunkIds: var_chunkIds, indexedAt: var_indexedAt); }
+
+@protected MergePolicyOptions sse_decode_merge_policy_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_enabled = sse_decode_bool(deserializer);
+var var_minNumSegments = sse_decode_usize(deserializer);
+var var_maxDocsBeforeMerge = sse_decode_usize(deserializer);
+var var_deletedRatioBeforeMerge = sse_decode_f_32(deserializer);
+return MergePolicyOptions(enabled: var_enabled, minNumSegments: var_minNumSegments, maxDocsBeforeMerge: var_maxDocsBeforeMerge, deletedRatioBeforeMerge: var_deletedRatioBeforeMerge); }
+
+@protected MigrationProgress sse_decode_migration_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_running = sse_decode_bool(deserializer);
+var var_processed = sse_decode_u_64(deserializer);
+var var_total = sse_decode_u_64(deserializer);
+return MigrationProgress(running: var_running, processed: var_processed, total: var_total); }
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fromVersion = sse_decode_u_32(deserializer);
+var var_toVersion = sse_decode_u_32(deserializer);
+var var_documentsMigrated = sse_decode_u_64(deserializer);
+var var_rebuildRequired = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return MigrationReport(fromVersion: var_fromVersion, toVersion: var_toVersion, documentsMigrated: var_documentsMigrated, rebuildRequired: var_rebuildRequired, error: var_error); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DocumentWithMetadata? sse_decode_opt_box_autoadd_document_with_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_document_with_metadata(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ManifestEntry? sse_decode_opt_box_autoadd_manifest_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_manifest_entry(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RecoveryReport? sse_decode_opt_box_autoadd_recovery_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_recovery_report(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PdfInfo sse_decode_pdf_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_opt_String(deserializer);
+var var_author = sse_decode_opt_String(deserializer);
+var var_creationDate = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_pageCount = sse_decode_u_32(deserializer);
+return PdfInfo(title: var_title, author: var_author, creationDate: var_creationDate, pageCount: var_pageCount); }
+
+@protected PdfPage sse_decode_pdf_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pageNumber = sse_decode_u_32(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return PdfPage(pageNumber: var_pageNumber, text: var_text, error: var_error); }
+
+@protected PdfText sse_decode_pdf_text(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_info = sse_decode_pdf_info(deserializer);
+var var_pages = sse_decode_list_pdf_page(deserializer);
+return PdfText(info: var_info, pages: var_pages); }
+
+@protected Query sse_decode_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_text = sse_decode_String(deserializer);
+return Query_Term(text: var_text);case 1: var var_text = sse_decode_String(deserializer);
+var var_slop = sse_decode_u_32(deserializer);
+return Query_Phrase(text: var_text, slop: var_slop);case 2: var var_must = sse_decode_list_query(deserializer);
+var var_should = sse_decode_list_query(deserializer);
+var var_mustNot = sse_decode_list_query(deserializer);
+return Query_Boolean(must: var_must, should: var_should, mustNot: var_mustNot);case 3: var var_field = sse_decode_String(deserializer);
+var var_query = sse_decode_box_query(deserializer);
+return Query_Field(field: var_field, query: var_query); default: throw UnimplementedError(''); }
+             }
+
+@protected ReconcileReport sse_decode_reconcile_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_toIndex = sse_decode_list_file_to_index(deserializer);
+var var_removed = sse_decode_list_String(deserializer);
+var var_deletedChunkIds = sse_decode_list_String(deserializer);
+var var_unchanged = sse_decode_u_64(deserializer);
+var var_errors = sse_decode_list_String(deserializer);
+return ReconcileReport(toIndex: var_toIndex, removed: var_removed, deletedChunkIds: var_deletedChunkIds, unchanged: var_unchanged, errors: var_errors); }
+
+@protected (Array2I64,Array2I64) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(deserializer);
+var var_field1 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(deserializer);
+return (var_field0, var_field1); }
+
+@protected (Tokenizer,Session) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(deserializer);
+var var_field1 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(deserializer);
+return (var_field0, var_field1); }
+
+@protected RecoveryReason sse_decode_recovery_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RecoveryReason.values[inner]; }
+
+@protected RecoveryReport sse_decode_recovery_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_reason = sse_decode_recovery_reason(deserializer);
+var var_problems = sse_decode_list_String(deserializer);
+var var_quarantineDir = sse_decode_opt_String(deserializer);
+var var_occurredAt = sse_decode_i_64(deserializer);
+return RecoveryReport(reason: var_reason, problems: var_problems, quarantineDir: var_quarantineDir, occurredAt: var_occurredAt); }
+
+@protected SearchFacets sse_decode_search_facets(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_extensions = sse_decode_list_facet_count(deserializer);
+var var_folders = sse_decode_list_facet_count(deserializer);
+var var_years = sse_decode_list_facet_count(deserializer);
+var var_languages = sse_decode_list_facet_count(deserializer);
+return SearchFacets(extensions: var_extensions, folders: var_folders, years: var_years, languages: var_languages); }
+
+@protected SearchFilter sse_decode_search_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_extensions = sse_decode_list_String(deserializer);
+var var_folderPrefix = sse_decode_opt_String(deserializer);
+var var_modifiedAfter = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_modifiedBefore = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_languages = sse_decode_list_String(deserializer);
+return SearchFilter(extensions: var_extensions, folderPrefix: var_folderPrefix, modifiedAfter: var_modifiedAfter, modifiedBefore: var_modifiedBefore, languages: var_languages); }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_score = sse_decode_f_32(deserializer);
+var var_doc = sse_decode_document(deserializer);
+var var_metadata = sse_decode_document_metadata(deserializer);
+var var_snippets = sse_decode_list_snippet(deserializer);
+return SearchHit(score: var_score, doc: var_doc, metadata: var_metadata, snippets: var_snippets); }
+
+@protected SearchOptions sse_decode_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topK = sse_decode_usize(deserializer);
+var var_offset = sse_decode_usize(deserializer);
+var var_filter = sse_decode_search_filter(deserializer);
+var var_maxSnippets = sse_decode_usize(deserializer);
+var var_snippetMaxChars = sse_decode_usize(deserializer);
+var var_minGeneration = sse_decode_u_64(deserializer);
+var var_fuzzyDistance = sse_decode_u_8(deserializer);
+var var_suggestBelowHits = sse_decode_usize(deserializer);
+return SearchOptions(topK: var_topK, offset: var_offset, filter: var_filter, maxSnippets: var_maxSnippets, snippetMaxChars: var_snippetMaxChars, minGeneration: var_minGeneration, fuzzyDistance: var_fuzzyDistance, suggestBelowHits: var_suggestBelowHits); }
+
+@protected SearchResponse sse_decode_search_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_search_hit(deserializer);
+var var_totalHits = sse_decode_usize(deserializer);
+var var_facets = sse_decode_search_facets(deserializer);
+var var_suggestions = sse_decode_list_String(deserializer);
+var var_queryWarnings = sse_decode_list_String(deserializer);
+return SearchResponse(hits: var_hits, totalHits: var_totalHits, facets: var_facets, suggestions: var_suggestions, queryWarnings: var_queryWarnings); }
+
+@protected SearchResult sse_decode_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_score = sse_decode_f_32(deserializer);
+var var_doc = sse_decode_document(deserializer);
+var var_metadata = sse_decode_document_metadata(deserializer);
+return SearchResult(score: var_score, doc: var_doc, metadata: var_metadata); }
+
+@protected SimilarityScore sse_decode_similarity_score(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_usize(deserializer);
+var var_score = sse_decode_f_32(deserializer);
+return SimilarityScore(index: var_index, score: var_score); }
+
+@protected SkipReason sse_decode_skip_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SkipReason.values[inner]; }
+
+@protected SnapshotInfo sse_decode_snapshot_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_schemaVersion = sse_decode_u_32(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_storageRoot = sse_decode_String(deserializer);
+var var_documentCount = sse_decode_u_64(deserializer);
+var var_hasEmbeddings = sse_decode_bool(deserializer);
+var var_hasFileManifest = sse_decode_bool(deserializer);
+var var_settingsJson = sse_decode_opt_String(deserializer);
+return SnapshotInfo(schemaVersion: var_schemaVersion, createdAt: var_createdAt, storageRoot: var_storageRoot, documentCount: var_documentCount, hasEmbeddings: var_hasEmbeddings, hasFileManifest: var_hasFileManifest, settingsJson: var_settingsJson); }
+
+@protected SnapshotPaths sse_decode_snapshot_paths(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_indexDir = sse_decode_String(deserializer);
+var var_embeddingStoreDir = sse_decode_opt_String(deserializer);
+var var_fileManifestPath = sse_decode_opt_String(deserializer);
+return SnapshotPaths(indexDir: var_indexDir, embeddingStoreDir: var_embeddingStoreDir, fileManifestPath: var_fileManifestPath); }
+
+@protected Snippet sse_decode_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fragment = sse_decode_String(deserializer);
+var var_offset = sse_decode_usize(deserializer);
+var var_highlights = sse_decode_list_highlight_range(deserializer);
+return Snippet(fragment: var_fragment, offset: var_offset, highlights: var_highlights); }
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_source = sse_decode_suggestion_source(deserializer);
+return Suggestion(text: var_text, source: var_source); }
+
+@protected SuggestionSource sse_decode_suggestion_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SuggestionSource.values[inner]; }
+
+@protected SymlinkPolicy sse_decode_symlink_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SymlinkPolicy.values[inner]; }
+
+@protected TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startMs = sse_decode_u_64(deserializer);
+var var_endMs = sse_decode_u_64(deserializer);
+var var_text = sse_decode_String(deserializer);
+return TranscriptSegment(startMs: var_startMs, endMs: var_endMs, text: var_text); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(Array2F32 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Array2F32Impl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(Array2I64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Array2I64Impl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(Encoding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EncodingImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SessionImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(Tokenizer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TokenizerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SessionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(Tokenizer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TokenizerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(Array2F32 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Array2F32Impl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(Array2I64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Array2I64Impl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(Encoding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EncodingImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SessionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(Tokenizer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as TokenizerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_crawl_event_Sse(RustStreamSink<CrawlEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_crawl_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_pdf_page_Sse(RustStreamSink<PdfPage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_pdf_page,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_crawl_config(CrawlConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_crawl_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_document(Document self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_document(self, serializer); }
+
+@protected void sse_encode_box_autoadd_document_metadata(DocumentMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_document_metadata(self, serializer); }
+
+@protected void sse_encode_box_autoadd_document_with_metadata(DocumentWithMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_document_with_metadata(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_manifest_entry(ManifestEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_manifest_entry(self, serializer); }
+
+@protected void sse_encode_box_autoadd_merge_policy_options(MergePolicyOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_merge_policy_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_query(Query self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_recovery_report(RecoveryReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_recovery_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_options(SearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_snapshot_paths(SnapshotPaths self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_snapshot_paths(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_query(Query self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query(self, serializer); }
+
+@protected void sse_encode_change_kind(ChangeKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_crawl_config(CrawlConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.roots, serializer);
+sse_encode_list_String(self.extensions, serializer);
+sse_encode_list_String(self.excludeGlobs, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxDepth, serializer);
+sse_encode_symlink_policy(self.symlinks, serializer);
+ }
+
+@protected void sse_encode_crawl_event(CrawlEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CrawlEvent_File(path: final path,size: final size,modified: final modified): sse_encode_i_32(0, serializer); sse_encode_String(path, serializer);
+sse_encode_u_64(size, serializer);
+sse_encode_opt_box_autoadd_i_64(modified, serializer);
+case CrawlEvent_Skipped(path: final path,reason: final reason): sse_encode_i_32(1, serializer); sse_encode_String(path, serializer);
+sse_encode_skip_reason(reason, serializer);
+case CrawlEvent_Error(path: final path,message: final message): sse_encode_i_32(2, serializer); sse_encode_String(path, serializer);
+sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_crawl_summary(CrawlSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.files, serializer);
+sse_encode_u_64(self.skipped, serializer);
+sse_encode_u_64(self.errors, serializer);
+sse_encode_bool(self.cancelled, serializer);
+ }
+
+@protected void sse_encode_delete_report(DeleteReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.deletedCount, serializer);
+sse_encode_list_String(self.deletedIds, serializer);
+sse_encode_bool(self.committed, serializer);
+ }
+
+@protected void sse_encode_document(Document self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_document_metadata(DocumentMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_opt_box_autoadd_i_64(self.modified, serializer);
+sse_encode_opt_String(self.language, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.author, serializer);
+sse_encode_opt_String(self.chapter, serializer);
+sse_encode_opt_box_autoadd_u_32(self.chapterIndex, serializer);
+sse_encode_list_String(self.headings, serializer);
+sse_encode_opt_String(self.chat, serializer);
+sse_encode_list_String(self.senders, serializer);
+sse_encode_opt_box_autoadd_i_64(self.timestamp, serializer);
+sse_encode_opt_String(self.sheet, serializer);
+sse_encode_opt_box_autoadd_u_32(self.row, serializer);
+sse_encode_opt_box_autoadd_u_64(self.startMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.endMs, serializer);
+sse_encode_opt_String(self.subject, serializer);
+sse_encode_list_String(self.recipients, serializer);
+ }
+
+@protected void sse_encode_document_with_metadata(DocumentWithMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_document(self.doc, serializer);
+sse_encode_document_metadata(self.metadata, serializer);
+ }
+
+@protected void sse_encode_encoding_type(EncodingType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_extracted_file(ExtractedFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.author, serializer);
+sse_encode_list_document_with_metadata(self.documents, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_facet_count(FacetCount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.value, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_file_to_index(FileToIndex self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_opt_box_autoadd_i_64(self.modified, serializer);
+sse_encode_String(self.hash, serializer);
+sse_encode_change_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_highlight_range(HighlightRange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.start, serializer);
+sse_encode_usize(self.end, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_index_health(IndexHealth self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.segmentCount, serializer);
+sse_encode_u_64(self.numDocs, serializer);
+sse_encode_u_64(self.numDeletedDocs, serializer);
+sse_encode_f_64(self.deletedRatio, serializer);
+sse_encode_u_64(self.diskBytes, serializer);
+ }
+
+@protected void sse_encode_index_stats(IndexStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.chunkCount, serializer);
+sse_encode_u_64(self.fileCount, serializer);
+sse_encode_list_facet_count(self.extensions, serializer);
+sse_encode_list_facet_count(self.languages, serializer);
+sse_encode_usize(self.segmentCount, serializer);
+sse_encode_u_64(self.deletedCount, serializer);
+sse_encode_u_64(self.indexBytes, serializer);
+sse_encode_u_64(self.embeddingStoreBytes, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastCommit, serializer);
+ }
+
+@protected void sse_encode_index_status(IndexStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.version, serializer);
+sse_encode_u_32(self.currentVersion, serializer);
+sse_encode_bool(self.needsMigration, serializer);
+ }
+
+@protected void sse_encode_indexed_file(IndexedFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_file_to_index(self.file, serializer);
+sse_encode_list_String(self.chunkIds, serializer);
+ }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(List<Encoding> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(item, serializer); } }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_document(List<Document> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_document(item, serializer); } }
+
+@protected void sse_encode_list_document_with_metadata(List<DocumentWithMetadata> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_document_with_metadata(item, serializer); } }
+
+@protected void sse_encode_list_facet_count(List<FacetCount> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_facet_count(item, serializer); } }
+
+@protected void sse_encode_list_file_to_index(List<FileToIndex> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_file_to_index(item, serializer); } }
+
+@protected void sse_encode_list_highlight_range(List<HighlightRange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_highlight_range(item, serializer); } }
+
+@protected void sse_encode_list_indexed_file(List<IndexedFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_indexed_file(item, serializer); } }
+
+@protected void sse_encode_list_pdf_page(List<PdfPage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_pdf_page(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self is Float32List ? self : Float32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query(List<Query> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_search_result(List<SearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_result(item, serializer); } }
+
+@protected void sse_encode_list_similarity_score(List<SimilarityScore> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_similarity_score(item, serializer); } }
+
+@protected void sse_encode_list_snippet(List<Snippet> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_snippet(item, serializer); } }
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_suggestion(item, serializer); } }
+
+@protected void sse_encode_list_transcript_segment(List<TranscriptSegment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_transcript_segment(item, serializer); } }
+
+@protected void sse_encode_manifest_entry(ManifestEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_opt_box_autoadd_i_64(self.modified, serializer);
+sse_encode_String(self.hash, serializer);
+sse_encode_list_String(self.chunkIds, serializer);
+sse_encode_i_64(self.indexedAt, serializer);
+ }
+
+@protected void sse_encode_merge_policy_options(MergePolicyOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.enabled, serializer);
+sse_encode_usize(self.minNumSegments, serializer);
+sse_encode_usize(self.maxDocsBeforeMerge, serializer);
+sse_encode_f_32(self.deletedRatioBeforeMerge, serializer);
+ }
+
+@protected void sse_encode_migration_progress(MigrationProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.running, serializer);
+sse_encode_u_64(self.processed, serializer);
+sse_encode_u_64(self.total, serializer);
+ }
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.fromVersion, serializer);
+sse_encode_u_32(self.toVersion, serializer);
+sse_encode_u_64(self.documentsMigrated, serializer);
+sse_encode_bool(self.rebuildRequired, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_document_with_metadata(DocumentWithMetadata? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_document_with_metadata(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_manifest_entry(ManifestEntry? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_manifest_entry(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_recovery_report(RecoveryReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_recovery_report(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_pdf_info(PdfInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.title, serializer);
+sse_encode_opt_String(self.author, serializer);
+sse_encode_opt_box_autoadd_i_64(self.creationDate, serializer);
+sse_encode_u_32(self.pageCount, serializer);
+ }
+
+@protected void sse_encode_pdf_page(PdfPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pageNumber, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_pdf_text(PdfText self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pdf_info(self.info, serializer);
+sse_encode_list_pdf_page(self.pages, serializer);
+ }
+
+@protected void sse_encode_query(Query self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case Query_Term(text: final text): sse_encode_i_32(0, serializer); sse_encode_String(text, serializer);
+case Query_Phrase(text: final text,slop: final slop): sse_encode_i_32(1, serializer); sse_encode_String(text, serializer);
+sse_encode_u_32(slop, serializer);
+case Query_Boolean(must: final must,should: final should,mustNot: final mustNot): sse_encode_i_32(2, serializer); sse_encode_list_query(must, serializer);
+sse_encode_list_query(should, serializer);
+sse_encode_list_query(mustNot, serializer);
+case Query_Field(field: final field,query: final query): sse_encode_i_32(3, serializer); sse_encode_String(field, serializer);
+sse_encode_box_query(query, serializer);
+  } }
+
+@protected void sse_encode_reconcile_report(ReconcileReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_file_to_index(self.toIndex, serializer);
+sse_encode_list_String(self.removed, serializer);
+sse_encode_list_String(self.deletedChunkIds, serializer);
+sse_encode_u_64(self.unchanged, serializer);
+sse_encode_list_String(self.errors, serializer);
+ }
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64((Array2I64,Array2I64) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(self.$1, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session((Tokenizer,Session) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(self.$1, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(self.$2, serializer);
+ }
+
+@protected void sse_encode_recovery_reason(RecoveryReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_recovery_report(RecoveryReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_recovery_reason(self.reason, serializer);
+sse_encode_list_String(self.problems, serializer);
+sse_encode_opt_String(self.quarantineDir, serializer);
+sse_encode_i_64(self.occurredAt, serializer);
+ }
+
+@protected void sse_encode_search_facets(SearchFacets self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_facet_count(self.extensions, serializer);
+sse_encode_list_facet_count(self.folders, serializer);
+sse_encode_list_facet_count(self.years, serializer);
+sse_encode_list_facet_count(self.languages, serializer);
+ }
+
+@protected void sse_encode_search_filter(SearchFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.extensions, serializer);
+sse_encode_opt_String(self.folderPrefix, serializer);
+sse_encode_opt_box_autoadd_i_64(self.modifiedAfter, serializer);
+sse_encode_opt_box_autoadd_i_64(self.modifiedBefore, serializer);
+sse_encode_list_String(self.languages, serializer);
+ }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.score, serializer);
+sse_encode_document(self.doc, serializer);
+sse_encode_document_metadata(self.metadata, serializer);
+sse_encode_list_snippet(self.snippets, serializer);
+ }
+
+@protected void sse_encode_search_options(SearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.topK, serializer);
+sse_encode_usize(self.offset, serializer);
+sse_encode_search_filter(self.filter, serializer);
+sse_encode_usize(self.maxSnippets, serializer);
+sse_encode_usize(self.snippetMaxChars, serializer);
+sse_encode_u_64(self.minGeneration, serializer);
+sse_encode_u_8(self.fuzzyDistance, serializer);
+sse_encode_usize(self.suggestBelowHits, serializer);
+ }
+
+@protected void sse_encode_search_response(SearchResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_hit(self.hits, serializer);
+sse_encode_usize(self.totalHits, serializer);
+sse_encode_search_facets(self.facets, serializer);
+sse_encode_list_String(self.suggestions, serializer);
+sse_encode_list_String(self.queryWarnings, serializer);
+ }
+
+@protected void sse_encode_search_result(SearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.score, serializer);
+sse_encode_document(self.doc, serializer);
+sse_encode_document_metadata(self.metadata, serializer);
+ }
+
+@protected void sse_encode_similarity_score(SimilarityScore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.index, serializer);
+sse_encode_f_32(self.score, serializer);
+ }
+
+@protected void sse_encode_skip_reason(SkipReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_snapshot_info(SnapshotInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.schemaVersion, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_String(self.storageRoot, serializer);
+sse_encode_u_64(self.documentCount, serializer);
+sse_encode_bool(self.hasEmbeddings, serializer);
+sse_encode_bool(self.hasFileManifest, serializer);
+sse_encode_opt_String(self.settingsJson, serializer);
+ }
+
+@protected void sse_encode_snapshot_paths(SnapshotPaths self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.indexDir, serializer);
+sse_encode_opt_String(self.embeddingStoreDir, serializer);
+sse_encode_opt_String(self.fileManifestPath, serializer);
+ }
+
+@protected void sse_encode_snippet(Snippet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fragment, serializer);
+sse_encode_usize(self.offset, serializer);
+sse_encode_list_highlight_range(self.highlights, serializer);
+ }
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_suggestion_source(self.source, serializer);
+ }
+
+@protected void sse_encode_suggestion_source(SuggestionSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_symlink_policy(SymlinkPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_transcript_segment(TranscriptSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.startMs, serializer);
+sse_encode_u_64(self.endMs, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class Array2F32Impl extends RustOpaque implements Array2F32 {
+                // Not to be used by end users
+                Array2F32Impl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Array2F32Impl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Array2F32,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Array2F32,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Array2F32Ptr,
+                );
+
+                
+            }
+            @sealed class Array2I64Impl extends RustOpaque implements Array2I64 {
+                // Not to be used by end users
+                Array2I64Impl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Array2I64Impl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Array2I64,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Array2I64,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Array2I64Ptr,
+                );
+
+                
+            }
+            @sealed class EncodingImpl extends RustOpaque implements Encoding {
+                // Not to be used by end users
+                EncodingImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                EncodingImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Encoding,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Encoding,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_EncodingPtr,
+                );
+
+                
+            }
+            @sealed class SessionImpl extends RustOpaque implements Session {
+                // Not to be used by end users
+                SessionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Session,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Session,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SessionPtr,
+                );
+
+                
+            }
+            @sealed class TokenizerImpl extends RustOpaque implements Tokenizer {
+                // Not to be used by end users
+                TokenizerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                TokenizerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Tokenizer,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Tokenizer,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_TokenizerPtr,
+                );
+
+                
+            }
\ No newline at end of file
@@ -4,7 +4,18 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/acho.dart';
+import 'api/audio.dart';
+import 'api/crawler.dart';
+import 'api/extract.dart';
+import 'api/history.dart';
+import 'api/manifest.dart';
+import 'api/migration.dart';
+import 'api/ocr.dart';
+import 'api/pdf.dart';
+import 'api/query.dart';
+import 'api/recovery.dart';
 import 'api/simple.dart';
+import 'api/snapshot.dart';
 import 'api/tantivy.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -12,630 +23,824 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2F32Ptr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2I64Ptr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EncodingPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TokenizerPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr;
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2F32Ptr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr;
 
-  @protected
-  Array2F32
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Array2I64Ptr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr;
 
-  @protected
-  Array2I64
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EncodingPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr;
 
-  @protected
-  Encoding
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr;
 
-  @protected
-  Session
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_TokenizerPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr;
 
-  @protected
-  Tokenizer
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          dynamic raw);
 
-  @protected
-  Session
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          dynamic raw);
 
-  @protected
-  Tokenizer
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  Array2F32
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          dynamic raw);
+@protected Array2F32 dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(dynamic raw);
 
-  @protected
-  Array2I64
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          dynamic raw);
+@protected Array2I64 dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(dynamic raw);
 
-  @protected
-  Encoding
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          dynamic raw);
+@protected Encoding dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(dynamic raw);
 
-  @protected
-  Session
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          dynamic raw);
+@protected Session dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw);
 
-  @protected
-  Tokenizer
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          dynamic raw);
+@protected Tokenizer dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected Session dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw);
 
-  @protected
-  Document dco_decode_box_autoadd_document(dynamic raw);
+@protected Tokenizer dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(dynamic raw);
 
-  @protected
-  Document dco_decode_document(dynamic raw);
+@protected Array2F32 dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(dynamic raw);
 
-  @protected
-  EncodingType dco_decode_encoding_type(dynamic raw);
+@protected Array2I64 dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(dynamic raw);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
-
-  @protected
-  int dco_decode_i_32(dynamic raw);
-
-  @protected
-  List<Encoding>
-      dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          dynamic raw);
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
-
-  @protected
-  List<Document> dco_decode_list_document(dynamic raw);
-
-  @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
-
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
-
-  @protected
-  List<SearchResult> dco_decode_list_search_result(dynamic raw);
-
-  @protected
-  List<SimilarityScore> dco_decode_list_similarity_score(dynamic raw);
-
-  @protected
-  Document? dco_decode_opt_box_autoadd_document(dynamic raw);
-
-  @protected
-  (
-    Array2I64,
-    Array2I64
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(
-      dynamic raw);
+@protected Encoding dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(dynamic raw);
 
-  @protected
-  (
-    Tokenizer,
-    Session
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(
-      dynamic raw);
-
-  @protected
-  SearchResult dco_decode_search_result(dynamic raw);
+@protected Session dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(dynamic raw);
 
-  @protected
-  SimilarityScore dco_decode_similarity_score(dynamic raw);
+@protected Tokenizer dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected RustStreamSink<CrawlEvent> dco_decode_StreamSink_crawl_event_Sse(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected RustStreamSink<PdfPage> dco_decode_StreamSink_pdf_page_Sse(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  Array2F32
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          SseDeserializer deserializer);
+@protected CrawlConfig dco_decode_box_autoadd_crawl_config(dynamic raw);
 
-  @protected
-  Array2I64
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          SseDeserializer deserializer);
+@protected Document dco_decode_box_autoadd_document(dynamic raw);
 
-  @protected
-  Encoding
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          SseDeserializer deserializer);
+@protected DocumentMetadata dco_decode_box_autoadd_document_metadata(dynamic raw);
 
-  @protected
-  Session
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          SseDeserializer deserializer);
+@protected DocumentWithMetadata dco_decode_box_autoadd_document_with_metadata(dynamic raw);
 
-  @protected
-  Tokenizer
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  Session
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          SseDeserializer deserializer);
+@protected ManifestEntry dco_decode_box_autoadd_manifest_entry(dynamic raw);
 
-  @protected
-  Tokenizer
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          SseDeserializer deserializer);
+@protected MergePolicyOptions dco_decode_box_autoadd_merge_policy_options(dynamic raw);
 
-  @protected
-  Array2F32
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          SseDeserializer deserializer);
+@protected Query dco_decode_box_autoadd_query(dynamic raw);
 
-  @protected
-  Array2I64
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          SseDeserializer deserializer);
+@protected RecoveryReport dco_decode_box_autoadd_recovery_report(dynamic raw);
 
-  @protected
-  Encoding
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          SseDeserializer deserializer);
+@protected SearchOptions dco_decode_box_autoadd_search_options(dynamic raw);
 
-  @protected
-  Session
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          SseDeserializer deserializer);
+@protected SnapshotPaths dco_decode_box_autoadd_snapshot_paths(dynamic raw);
 
-  @protected
-  Tokenizer
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  Document sse_decode_box_autoadd_document(SseDeserializer deserializer);
-
-  @protected
-  Document sse_decode_document(SseDeserializer deserializer);
-
-  @protected
-  EncodingType sse_decode_encoding_type(SseDeserializer deserializer);
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  List<Encoding>
-      sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          SseDeserializer deserializer);
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
-
-  @protected
-  List<Document> sse_decode_list_document(SseDeserializer deserializer);
-
-  @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
-
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  List<SearchResult> sse_decode_list_search_result(
-      SseDeserializer deserializer);
-
-  @protected
-  List<SimilarityScore> sse_decode_list_similarity_score(
-      SseDeserializer deserializer);
-
-  @protected
-  Document? sse_decode_opt_box_autoadd_document(SseDeserializer deserializer);
-
-  @protected
-  (
-    Array2I64,
-    Array2I64
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(
-      SseDeserializer deserializer);
-
-  @protected
-  (
-    Tokenizer,
-    Session
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(
-      SseDeserializer deserializer);
+@protected Query dco_decode_box_query(dynamic raw);
 
-  @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer);
-
-  @protected
-  SimilarityScore sse_decode_similarity_score(SseDeserializer deserializer);
+@protected ChangeKind dco_decode_change_kind(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected CrawlConfig dco_decode_crawl_config(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected CrawlEvent dco_decode_crawl_event(dynamic raw);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected CrawlSummary dco_decode_crawl_summary(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected DeleteReport dco_decode_delete_report(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected Document dco_decode_document(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          Array2F32 self, SseSerializer serializer);
+@protected DocumentMetadata dco_decode_document_metadata(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          Array2I64 self, SseSerializer serializer);
+@protected DocumentWithMetadata dco_decode_document_with_metadata(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          Encoding self, SseSerializer serializer);
+@protected EncodingType dco_decode_encoding_type(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          Session self, SseSerializer serializer);
+@protected ExtractedFile dco_decode_extracted_file(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          Tokenizer self, SseSerializer serializer);
+@protected double dco_decode_f_32(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          Session self, SseSerializer serializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          Tokenizer self, SseSerializer serializer);
+@protected FacetCount dco_decode_facet_count(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-          Array2F32 self, SseSerializer serializer);
+@protected FileToIndex dco_decode_file_to_index(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-          Array2I64 self, SseSerializer serializer);
+@protected HighlightRange dco_decode_highlight_range(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          Encoding self, SseSerializer serializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-          Session self, SseSerializer serializer);
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-          Tokenizer self, SseSerializer serializer);
+@protected IndexHealth dco_decode_index_health(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected IndexStats dco_decode_index_stats(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_document(Document self, SseSerializer serializer);
+@protected IndexStatus dco_decode_index_status(dynamic raw);
 
-  @protected
-  void sse_encode_document(Document self, SseSerializer serializer);
+@protected IndexedFile dco_decode_indexed_file(dynamic raw);
 
-  @protected
-  void sse_encode_encoding_type(EncodingType self, SseSerializer serializer);
+@protected List<Encoding> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(dynamic raw);
 
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected List<Document> dco_decode_list_document(dynamic raw);
 
-  @protected
-  void
-      sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-          List<Encoding> self, SseSerializer serializer);
+@protected List<DocumentWithMetadata> dco_decode_list_document_with_metadata(dynamic raw);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected List<FacetCount> dco_decode_list_facet_count(dynamic raw);
 
-  @protected
-  void sse_encode_list_document(List<Document> self, SseSerializer serializer);
+@protected List<FileToIndex> dco_decode_list_file_to_index(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_f_32_loose(
-      List<double> self, SseSerializer serializer);
+@protected List<HighlightRange> dco_decode_list_highlight_range(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-      Float32List self, SseSerializer serializer);
+@protected List<IndexedFile> dco_decode_list_indexed_file(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected List<PdfPage> dco_decode_list_pdf_page(dynamic raw);
 
-  @protected
-  void sse_encode_list_search_result(
-      List<SearchResult> self, SseSerializer serializer);
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
 
-  @protected
-  void sse_encode_list_similarity_score(
-      List<SimilarityScore> self, SseSerializer serializer);
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_document(
-      Document? self, SseSerializer serializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(
-          (Array2I64, Array2I64) self, SseSerializer serializer);
+@protected List<Query> dco_decode_list_query(dynamic raw);
 
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(
-          (Tokenizer, Session) self, SseSerializer serializer);
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
 
-  @protected
-  void sse_encode_search_result(SearchResult self, SseSerializer serializer);
+@protected List<SearchResult> dco_decode_list_search_result(dynamic raw);
 
-  @protected
-  void sse_encode_similarity_score(
-      SimilarityScore self, SseSerializer serializer);
+@protected List<SimilarityScore> dco_decode_list_similarity_score(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected List<Snippet> dco_decode_list_snippet(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw);
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+@protected List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw);
+
+@protected ManifestEntry dco_decode_manifest_entry(dynamic raw);
+
+@protected MergePolicyOptions dco_decode_merge_policy_options(dynamic raw);
+
+@protected MigrationProgress dco_decode_migration_progress(dynamic raw);
+
+@protected MigrationReport dco_decode_migration_report(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected DocumentWithMetadata? dco_decode_opt_box_autoadd_document_with_metadata(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected ManifestEntry? dco_decode_opt_box_autoadd_manifest_entry(dynamic raw);
+
+@protected RecoveryReport? dco_decode_opt_box_autoadd_recovery_report(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected PdfInfo dco_decode_pdf_info(dynamic raw);
+
+@protected PdfPage dco_decode_pdf_page(dynamic raw);
+
+@protected PdfText dco_decode_pdf_text(dynamic raw);
+
+@protected Query dco_decode_query(dynamic raw);
+
+@protected ReconcileReport dco_decode_reconcile_report(dynamic raw);
+
+@protected (Array2I64,Array2I64) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(dynamic raw);
+
+@protected (Tokenizer,Session) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(dynamic raw);
+
+@protected RecoveryReason dco_decode_recovery_reason(dynamic raw);
+
+@protected RecoveryReport dco_decode_recovery_report(dynamic raw);
+
+@protected SearchFacets dco_decode_search_facets(dynamic raw);
+
+@protected SearchFilter dco_decode_search_filter(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SearchOptions dco_decode_search_options(dynamic raw);
+
+@protected SearchResponse dco_decode_search_response(dynamic raw);
+
+@protected SearchResult dco_decode_search_result(dynamic raw);
+
+@protected SimilarityScore dco_decode_similarity_score(dynamic raw);
+
+@protected SkipReason dco_decode_skip_reason(dynamic raw);
+
+@protected SnapshotInfo dco_decode_snapshot_info(dynamic raw);
+
+@protected SnapshotPaths dco_decode_snapshot_paths(dynamic raw);
+
+@protected Snippet dco_decode_snippet(dynamic raw);
+
+@protected Suggestion dco_decode_suggestion(dynamic raw);
+
+@protected SuggestionSource dco_decode_suggestion_source(dynamic raw);
+
+@protected SymlinkPolicy dco_decode_symlink_policy(dynamic raw);
+
+@protected TranscriptSegment dco_decode_transcript_segment(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected Array2F32 sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(SseDeserializer deserializer);
+
+@protected Array2I64 sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(SseDeserializer deserializer);
+
+@protected Encoding sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(SseDeserializer deserializer);
+
+@protected Session sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer);
+
+@protected Tokenizer sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(SseDeserializer deserializer);
+
+@protected Session sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer);
+
+@protected Tokenizer sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(SseDeserializer deserializer);
+
+@protected Array2F32 sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(SseDeserializer deserializer);
+
+@protected Array2I64 sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(SseDeserializer deserializer);
+
+@protected Encoding sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(SseDeserializer deserializer);
+
+@protected Session sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(SseDeserializer deserializer);
+
+@protected Tokenizer sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(SseDeserializer deserializer);
+
+@protected RustStreamSink<CrawlEvent> sse_decode_StreamSink_crawl_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<PdfPage> sse_decode_StreamSink_pdf_page_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected CrawlConfig sse_decode_box_autoadd_crawl_config(SseDeserializer deserializer);
+
+@protected Document sse_decode_box_autoadd_document(SseDeserializer deserializer);
+
+@protected DocumentMetadata sse_decode_box_autoadd_document_metadata(SseDeserializer deserializer);
+
+@protected DocumentWithMetadata sse_decode_box_autoadd_document_with_metadata(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected ManifestEntry sse_decode_box_autoadd_manifest_entry(SseDeserializer deserializer);
+
+@protected MergePolicyOptions sse_decode_box_autoadd_merge_policy_options(SseDeserializer deserializer);
+
+@protected Query sse_decode_box_autoadd_query(SseDeserializer deserializer);
+
+@protected RecoveryReport sse_decode_box_autoadd_recovery_report(SseDeserializer deserializer);
+
+@protected SearchOptions sse_decode_box_autoadd_search_options(SseDeserializer deserializer);
+
+@protected SnapshotPaths sse_decode_box_autoadd_snapshot_paths(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected Query sse_decode_box_query(SseDeserializer deserializer);
+
+@protected ChangeKind sse_decode_change_kind(SseDeserializer deserializer);
+
+@protected CrawlConfig sse_decode_crawl_config(SseDeserializer deserializer);
+
+@protected CrawlEvent sse_decode_crawl_event(SseDeserializer deserializer);
+
+@protected CrawlSummary sse_decode_crawl_summary(SseDeserializer deserializer);
+
+@protected DeleteReport sse_decode_delete_report(SseDeserializer deserializer);
+
+@protected Document sse_decode_document(SseDeserializer deserializer);
+
+@protected DocumentMetadata sse_decode_document_metadata(SseDeserializer deserializer);
+
+@protected DocumentWithMetadata sse_decode_document_with_metadata(SseDeserializer deserializer);
+
+@protected EncodingType sse_decode_encoding_type(SseDeserializer deserializer);
+
+@protected ExtractedFile sse_decode_extracted_file(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FacetCount sse_decode_facet_count(SseDeserializer deserializer);
+
+@protected FileToIndex sse_decode_file_to_index(SseDeserializer deserializer);
+
+@protected HighlightRange sse_decode_highlight_range(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IndexHealth sse_decode_index_health(SseDeserializer deserializer);
+
+@protected IndexStats sse_decode_index_stats(SseDeserializer deserializer);
+
+@protected IndexStatus sse_decode_index_status(SseDeserializer deserializer);
+
+@protected IndexedFile sse_decode_indexed_file(SseDeserializer deserializer);
+
+@protected List<Encoding> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<Document> sse_decode_list_document(SseDeserializer deserializer);
+
+@protected List<DocumentWithMetadata> sse_decode_list_document_with_metadata(SseDeserializer deserializer);
+
+@protected List<FacetCount> sse_decode_list_facet_count(SseDeserializer deserializer);
+
+@protected List<FileToIndex> sse_decode_list_file_to_index(SseDeserializer deserializer);
+
+@protected List<HighlightRange> sse_decode_list_highlight_range(SseDeserializer deserializer);
+
+@protected List<IndexedFile> sse_decode_list_indexed_file(SseDeserializer deserializer);
+
+@protected List<PdfPage> sse_decode_list_pdf_page(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<Query> sse_decode_list_query(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<SearchResult> sse_decode_list_search_result(SseDeserializer deserializer);
+
+@protected List<SimilarityScore> sse_decode_list_similarity_score(SseDeserializer deserializer);
+
+@protected List<Snippet> sse_decode_list_snippet(SseDeserializer deserializer);
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer);
+
+@protected List<TranscriptSegment> sse_decode_list_transcript_segment(SseDeserializer deserializer);
+
+@protected ManifestEntry sse_decode_manifest_entry(SseDeserializer deserializer);
+
+@protected MergePolicyOptions sse_decode_merge_policy_options(SseDeserializer deserializer);
+
+@protected MigrationProgress sse_decode_migration_progress(SseDeserializer deserializer);
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected DocumentWithMetadata? sse_decode_opt_box_autoadd_document_with_metadata(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected ManifestEntry? sse_decode_opt_box_autoadd_manifest_entry(SseDeserializer deserializer);
+
+@protected RecoveryReport? sse_decode_opt_box_autoadd_recovery_report(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected PdfInfo sse_decode_pdf_info(SseDeserializer deserializer);
+
+@protected PdfPage sse_decode_pdf_page(SseDeserializer deserializer);
+
+@protected PdfText sse_decode_pdf_text(SseDeserializer deserializer);
+
+@protected Query sse_decode_query(SseDeserializer deserializer);
+
+@protected ReconcileReport sse_decode_reconcile_report(SseDeserializer deserializer);
+
+@protected (Array2I64,Array2I64) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64(SseDeserializer deserializer);
+
+@protected (Tokenizer,Session) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session(SseDeserializer deserializer);
+
+@protected RecoveryReason sse_decode_recovery_reason(SseDeserializer deserializer);
+
+@protected RecoveryReport sse_decode_recovery_report(SseDeserializer deserializer);
+
+@protected SearchFacets sse_decode_search_facets(SseDeserializer deserializer);
+
+@protected SearchFilter sse_decode_search_filter(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SearchOptions sse_decode_search_options(SseDeserializer deserializer);
+
+@protected SearchResponse sse_decode_search_response(SseDeserializer deserializer);
+
+@protected SearchResult sse_decode_search_result(SseDeserializer deserializer);
+
+@protected SimilarityScore sse_decode_similarity_score(SseDeserializer deserializer);
+
+@protected SkipReason sse_decode_skip_reason(SseDeserializer deserializer);
+
+@protected SnapshotInfo sse_decode_snapshot_info(SseDeserializer deserializer);
+
+@protected SnapshotPaths sse_decode_snapshot_paths(SseDeserializer deserializer);
+
+@protected Snippet sse_decode_snippet(SseDeserializer deserializer);
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer);
+
+@protected SuggestionSource sse_decode_suggestion_source(SseDeserializer deserializer);
+
+@protected SymlinkPolicy sse_decode_symlink_policy(SseDeserializer deserializer);
+
+@protected TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(Array2F32 self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(Array2I64 self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(Encoding self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(Tokenizer self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(Tokenizer self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(Array2F32 self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(Array2I64 self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(Encoding self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(Session self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(Tokenizer self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_crawl_event_Sse(RustStreamSink<CrawlEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_pdf_page_Sse(RustStreamSink<PdfPage> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_crawl_config(CrawlConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_document(Document self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_document_metadata(DocumentMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_document_with_metadata(DocumentWithMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_manifest_entry(ManifestEntry self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_merge_policy_options(MergePolicyOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query(Query self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_recovery_report(RecoveryReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_options(SearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_snapshot_paths(SnapshotPaths self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_query(Query self, SseSerializer serializer);
+
+@protected void sse_encode_change_kind(ChangeKind self, SseSerializer serializer);
+
+@protected void sse_encode_crawl_config(CrawlConfig self, SseSerializer serializer);
+
+@protected void sse_encode_crawl_event(CrawlEvent self, SseSerializer serializer);
+
+@protected void sse_encode_crawl_summary(CrawlSummary self, SseSerializer serializer);
+
+@protected void sse_encode_delete_report(DeleteReport self, SseSerializer serializer);
+
+@protected void sse_encode_document(Document self, SseSerializer serializer);
+
+@protected void sse_encode_document_metadata(DocumentMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_document_with_metadata(DocumentWithMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_encoding_type(EncodingType self, SseSerializer serializer);
+
+@protected void sse_encode_extracted_file(ExtractedFile self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_facet_count(FacetCount self, SseSerializer serializer);
+
+@protected void sse_encode_file_to_index(FileToIndex self, SseSerializer serializer);
+
+@protected void sse_encode_highlight_range(HighlightRange self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_index_health(IndexHealth self, SseSerializer serializer);
+
+@protected void sse_encode_index_stats(IndexStats self, SseSerializer serializer);
+
+@protected void sse_encode_index_status(IndexStatus self, SseSerializer serializer);
+
+@protected void sse_encode_indexed_file(IndexedFile self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(List<Encoding> self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_document(List<Document> self, SseSerializer serializer);
+
+@protected void sse_encode_list_document_with_metadata(List<DocumentWithMetadata> self, SseSerializer serializer);
+
+@protected void sse_encode_list_facet_count(List<FacetCount> self, SseSerializer serializer);
+
+@protected void sse_encode_list_file_to_index(List<FileToIndex> self, SseSerializer serializer);
+
+@protected void sse_encode_list_highlight_range(List<HighlightRange> self, SseSerializer serializer);
+
+@protected void sse_encode_list_indexed_file(List<IndexedFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_pdf_page(List<PdfPage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query(List<Query> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_result(List<SearchResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_similarity_score(List<SimilarityScore> self, SseSerializer serializer);
+
+@protected void sse_encode_list_snippet(List<Snippet> self, SseSerializer serializer);
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_transcript_segment(List<TranscriptSegment> self, SseSerializer serializer);
+
+@protected void sse_encode_manifest_entry(ManifestEntry self, SseSerializer serializer);
+
+@protected void sse_encode_merge_policy_options(MergePolicyOptions self, SseSerializer serializer);
+
+@protected void sse_encode_migration_progress(MigrationProgress self, SseSerializer serializer);
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_document_with_metadata(DocumentWithMetadata? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_manifest_entry(ManifestEntry? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_recovery_report(RecoveryReport? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_pdf_info(PdfInfo self, SseSerializer serializer);
+
+@protected void sse_encode_pdf_page(PdfPage self, SseSerializer serializer);
+
+@protected void sse_encode_pdf_text(PdfText self, SseSerializer serializer);
+
+@protected void sse_encode_query(Query self, SseSerializer serializer);
+
+@protected void sse_encode_reconcile_report(ReconcileReport self, SseSerializer serializer);
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_array_2_i_64((Array2I64,Array2I64) self, SseSerializer serializer);
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_tokenizer_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_session((Tokenizer,Session) self, SseSerializer serializer);
+
+@protected void sse_encode_recovery_reason(RecoveryReason self, SseSerializer serializer);
+
+@protected void sse_encode_recovery_report(RecoveryReport self, SseSerializer serializer);
+
+@protected void sse_encode_search_facets(SearchFacets self, SseSerializer serializer);
+
+@protected void sse_encode_search_filter(SearchFilter self, SseSerializer serializer);
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_search_options(SearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_search_response(SearchResponse self, SseSerializer serializer);
+
+@protected void sse_encode_search_result(SearchResult self, SseSerializer serializer);
+
+@protected void sse_encode_similarity_score(SimilarityScore self, SseSerializer serializer);
+
+@protected void sse_encode_skip_reason(SkipReason self, SseSerializer serializer);
+
+@protected void sse_encode_snapshot_info(SnapshotInfo self, SseSerializer serializer);
+
+@protected void sse_encode_snapshot_paths(SnapshotPaths self, SseSerializer serializer);
+
+@protected void sse_encode_snippet(Snippet self, SseSerializer serializer);
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer);
+
+@protected void sse_encode_suggestion_source(SuggestionSource self, SseSerializer serializer);
+
+@protected void sse_encode_symlink_policy(SymlinkPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_transcript_segment(TranscriptSegment self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64 =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64 =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32 = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2f32Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64 = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64 = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArray2i64Ptr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncoding = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerEncodingPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSession = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_mobile_app_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizer = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTokenizerPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
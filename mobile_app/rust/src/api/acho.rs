@@ -40,7 +40,7 @@ pub fn load_artifacts(model_path: String, tokenizer_path:String) -> Result<(Toke
     Ok((tokenizer, session))
 }
 
-pub fn get_encoding_array(encodings: &Vec<Encoding>, encoding_type: EncodingType) -> Result<EncodingArray> {
+pub fn get_encoding_array(encodings: &[Encoding], encoding_type: EncodingType) -> Result<EncodingArray> {
     let extract: fn(&Encoding) -> &[u32] = match encoding_type {
         EncodingType::Ids => |e: &Encoding| e.get_ids(),
         EncodingType::AttentionMask => |e: &Encoding| e.get_attention_mask(),
@@ -57,7 +57,7 @@ pub fn get_encoding_array(encodings: &Vec<Encoding>, encoding_type: EncodingType
     )
 }
 
-pub fn tokenize(texts: &Vec<String>, tokenizer: &Tokenizer) -> Result<(InputIds, AttentionMask)> {
+pub fn tokenize(texts: &[String], tokenizer: &Tokenizer) -> Result<(InputIds, AttentionMask)> {
     let encodings = tokenizer.encode_batch(texts.to_vec(), true)
         .expect("Tokenization failed.");
 
@@ -66,7 +66,7 @@ pub fn tokenize(texts: &Vec<String>, tokenizer: &Tokenizer) -> Result<(InputIds,
     Ok((input_ids, attention_mask))
 }
 
-pub fn run_inference<'a>(text: &Vec<String>, model: &'a mut Session, tokenizer: &Tokenizer) -> Result<Embeddings>{
+pub fn run_inference(text: &[String], model: &mut Session, tokenizer: &Tokenizer) -> Result<Embeddings>{
     let (tokens, attn_mask) = tokenize(text, tokenizer)?;
     let token_input_value = ort::value::Tensor::from_array(tokens)?;
     let attn_mask_input_value = ort::value::Tensor::from_array(attn_mask)?;
@@ -74,16 +74,16 @@ pub fn run_inference<'a>(text: &Vec<String>, model: &'a mut Session, tokenizer:
         ort::inputs!["input_ids" => token_input_value, "attention_mask" => attn_mask_input_value]
     ).expect("Embedding model inference failed.");
 
-    let dense_embeddings = (&dense["dense_embeddings"]).try_extract_array::<f32>()?
+    let dense_embeddings = dense["dense_embeddings"].try_extract_array::<f32>()?
         .into_dimensionality::<ndarray::Ix2>()
         .expect("Failed to convert embeddings to 2D array.");
     Ok(dense_embeddings.to_owned())
 }
 
-pub fn similarity(query: &Vec<String>, texts: &Vec<String>, model_path: String, tokenizer_path:String, top_k: usize) -> Result<Vec<SimilarityScore>> {
+pub fn similarity(query: &[String], texts: &[String], model_path: String, tokenizer_path:String, top_k: usize) -> Result<Vec<SimilarityScore>> {
     let (tokenizer, mut model) = load_artifacts(model_path, tokenizer_path)?;
-    let all_embeddings: Embeddings = run_inference(&texts, &mut model, &tokenizer)?;
-    let query_embeddings: Embeddings = run_inference(&query, &mut model, &tokenizer)?;
+    let all_embeddings: Embeddings = run_inference(texts, &mut model, &tokenizer)?;
+    let query_embeddings: Embeddings = run_inference(query, &mut model, &tokenizer)?;
     
     let similarity_matrix = query_embeddings.dot(&all_embeddings.t());
     println!("Similarity matrix:\n{:?}", similarity_matrix);
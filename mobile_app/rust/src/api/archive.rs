@@ -0,0 +1,244 @@
+use anyhow::{anyhow, Result};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use zip::ZipArchive;
+
+use crate::api::extract::{chunk_id, extract_file, ExtractedFile, SUPPORTED_EXTENSIONS};
+use crate::api::tantivy::{index_dir, DocumentWithMetadata};
+
+
+// Separates the path of an archive or email from the path of a file inside it, as in
+// "bulletins.zip!/2024/march.pdf".
+pub(crate) const ARCHIVE_SEPARATOR: &str = "!/";
+// Archives inside archives are opened down to this depth, the archive on disk being at 0.
+const MAX_ARCHIVE_DEPTH: u32 = 2;
+// Bounds on what the files of an archive, those of nested archives included, may
+// uncompress to and on their number, against zip bombs.
+const MAX_ARCHIVE_SIZE: u64 = 512 * 1024 * 1024;
+const MAX_ARCHIVE_FILES: usize = 10_000;
+// Folder the files of archives are written to while they are extracted.
+const SCRATCH_DIR: &str = "archive_scratch";
+
+
+static SCRATCH_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+
+pub(crate) fn is_archive(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("zip"))
+}
+
+
+/// What is left of the limits of the archive being extracted.
+struct Budget {
+    bytes: u64,
+    files: usize,
+    exceeded: bool,
+}
+
+
+impl Budget {
+    fn take(&mut self, bytes: u64, virtual_path: &str) -> Result<()> {
+        if self.files == 0 || bytes > self.bytes {
+            self.exceeded = true;
+            return Err(anyhow!(
+                "{} holds more than {} files or {} bytes uncompressed",
+                virtual_path.split(ARCHIVE_SEPARATOR).next().unwrap_or_default(),
+                MAX_ARCHIVE_FILES,
+                MAX_ARCHIVE_SIZE,
+            ));
+        }
+        self.files -= 1;
+        self.bytes -= bytes;
+        Ok(())
+    }
+}
+
+
+/// A folder the files of one archive or email are written to, removed with what it holds
+/// when dropped.
+pub(crate) struct ScratchDir(PathBuf);
+
+
+impl ScratchDir {
+    pub(crate) fn new() -> Result<Self> {
+        // Apps cannot write to the system temporary folder on Android, but can to the index.
+        let parent = index_dir().unwrap_or_else(|_| std::env::temp_dir()).join(SCRATCH_DIR);
+        let name = format!("{}-{}", std::process::id(), SCRATCH_COUNTER.fetch_add(1, Ordering::Relaxed));
+        let dir = parent.join(name);
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self(dir))
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+
+/// Extracts the files of the archive at `path`, known as `virtual_path`, that can be indexed
+/// and appends their documents to `documents` under their virtual path. Files that are
+/// damaged, password protected or of an unsupported kind are left out, like unreadable
+/// files of a crawl.
+fn extract_entries(
+    path: &Path,
+    virtual_path: &str,
+    depth: u32,
+    budget: &mut Budget,
+    documents: &mut Vec<DocumentWithMetadata>,
+) -> Result<()> {
+    let file = File::open(path).map_err(|error| anyhow!("Cannot read {}: {}", virtual_path, error))?;
+    let mut archive = ZipArchive::new(file).map_err(|error| anyhow!("{} is not a valid ZIP archive: {}", virtual_path, error))?;
+    let scratch = ScratchDir::new()?;
+
+    for index in 0..archive.len() {
+        let Ok(mut entry) = archive.by_index(index) else { continue };
+        // Names leading out of the archive, such as "../x", are left out.
+        let Some(inner_path) = entry.enclosed_name().filter(|_| entry.is_file()) else { continue };
+        let extension = inner_path.extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default();
+        let nested = extension == "zip";
+        if !(nested && depth < MAX_ARCHIVE_DEPTH || !nested && SUPPORTED_EXTENSIONS.contains(&extension.as_str())) {
+            continue;
+        }
+
+        let entry_path = format!("{}{}{}", virtual_path, ARCHIVE_SEPARATOR, inner_path.to_string_lossy().replace('\\', "/"));
+        let size = entry.size();
+        budget.take(size, &entry_path)?;
+
+        // The inner folders are kept, as some extractors name things after them.
+        let target = scratch.path().join(&inner_path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // The declared size may be a lie.
+        let copied = File::create(&target).and_then(|mut file| io::copy(&mut (&mut entry).take(size + 1), &mut file));
+        drop(entry);
+        if !copied.is_ok_and(|copied| copied == size) {
+            let _ = std::fs::remove_file(&target);
+            continue;
+        }
+
+        if nested {
+            match extract_entries(&target, &entry_path, depth + 1, budget, documents) {
+                Err(error) if budget.exceeded => return Err(error),
+                _ => {}
+            }
+            let _ = std::fs::remove_file(&target);
+        } else {
+            documents.extend(extract_embedded(&target, &entry_path));
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Extracts `target`, a file embedded in another one and written to a scratch folder, into
+/// documents whose path is `virtual_path`, followed by the path inside it for archives, and
+/// removes it. Nothing is extracted from files the extractors fail on.
+pub(crate) fn extract_embedded(target: &Path, virtual_path: &str) -> Vec<DocumentWithMetadata> {
+    let extracted = extract_file(target.to_string_lossy().to_string());
+    let _ = std::fs::remove_file(target);
+
+    let target = target.to_string_lossy();
+    let documents = extracted.map(|extracted| extracted.documents).unwrap_or_default();
+    documents
+        .into_iter()
+        .map(|mut document| {
+            let inner_path = document.metadata.path.strip_prefix(target.as_ref()).unwrap_or_default();
+            document.metadata.path = format!("{}{}", virtual_path, inner_path);
+            // Ids are made from the path, the one in the scratch folder would collide.
+            let index = document.doc.id.rsplit('-').next().and_then(|index| index.parse().ok()).unwrap_or_default();
+            document.doc.id = chunk_id(Path::new(&document.metadata.path), index);
+            document
+        })
+        .collect()
+}
+
+
+/// Extracts the files of the ZIP archive at `path`, and of the archives it holds down to
+/// `MAX_ARCHIVE_DEPTH`, into documents whose path is "<archive>!/<path in the archive>".
+pub(crate) fn extract_archive(path: &Path) -> Result<ExtractedFile> {
+    let mut budget = Budget { bytes: MAX_ARCHIVE_SIZE, files: MAX_ARCHIVE_FILES, exceeded: false };
+    let archive_path = path.to_string_lossy().to_string();
+    let mut documents = Vec::new();
+    extract_entries(path, &archive_path, 0, &mut budget, &mut documents)?;
+
+    Ok(ExtractedFile { path: archive_path, title: None, author: None, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::ZipWriter;
+
+    fn write_zip(path: &Path, files: &[(&str, &[u8])]) {
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        for (name, content) in files {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn budget_runs_out_of_files_and_bytes() {
+        let mut budget = Budget { bytes: 100, files: 2, exceeded: false };
+
+        assert!(budget.take(60, "a.zip!/one.txt").is_ok());
+        let error = budget.take(60, "a.zip!/two.txt").unwrap_err();
+        assert!(error.to_string().starts_with("a.zip holds more than"));
+        assert!(budget.exceeded);
+
+        let mut budget = Budget { bytes: 100, files: 1, exceeded: false };
+        assert!(budget.take(10, "b.zip!/one.txt").is_ok());
+        assert!(budget.take(0, "b.zip!/two.txt").is_err());
+    }
+
+    #[test]
+    fn files_of_the_same_name_get_their_own_path_and_ids() {
+        let scratch = ScratchDir::new().unwrap();
+        let path = scratch.path().join("notes.zip");
+        write_zip(&path, &[
+            ("2023/notes.txt", b"Minutes of the 2023 meeting"),
+            ("2024/notes.txt", b"Minutes of the 2024 meeting"),
+            ("../escape.txt", b"Outside the archive"),
+            ("photo.bin", b"Not indexed"),
+        ]);
+
+        let extracted = extract_archive(&path).unwrap();
+
+        let archive = path.to_string_lossy();
+        let paths: Vec<_> = extracted.documents.iter().map(|document| document.metadata.path.clone()).collect();
+        assert_eq!(paths, vec![format!("{}!/2023/notes.txt", archive), format!("{}!/2024/notes.txt", archive)]);
+        assert_ne!(extracted.documents[0].doc.id, extracted.documents[1].doc.id);
+        assert_eq!(extracted.documents[0].doc.id, chunk_id(Path::new(&paths[0]), 0));
+    }
+
+    #[test]
+    fn nested_archives_are_extracted_under_their_path() {
+        let scratch = ScratchDir::new().unwrap();
+        let inner = scratch.path().join("inner.zip");
+        write_zip(&inner, &[("report.txt", b"Quarterly report")]);
+        let path = scratch.path().join("outer.zip");
+        write_zip(&path, &[("archives/inner.zip", &std::fs::read(&inner).unwrap())]);
+
+        let extracted = extract_archive(&path).unwrap();
+
+        assert_eq!(extracted.documents.len(), 1);
+        let document = &extracted.documents[0];
+        assert_eq!(document.metadata.path, format!("{}!/archives/inner.zip!/report.txt", path.to_string_lossy()));
+        assert_eq!(document.doc.id, chunk_id(Path::new(&document.metadata.path), 0));
+    }
+}
@@ -0,0 +1,421 @@
+use anyhow::{anyhow, Result};
+use ndarray::Array2;
+use once_cell::sync::Lazy;
+use opus_decoder::OpusDecoder;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::sync::Mutex;
+use symphonia::core::codecs::audio::AudioDecoderOptions;
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::probe::Hint;
+use symphonia::core::formats::{FormatOptions, TrackType};
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+
+use crate::api::extract::{chunk_id, file_metadata, ExtractedFile};
+use crate::api::tantivy::{Document, DocumentMetadata, DocumentWithMetadata};
+
+
+// Sample rate speech models take their input at.
+const SAMPLE_RATE: u32 = 16_000;
+// Longer recordings are lectures or music rather than voice notes, and would not fit in
+// memory once decoded.
+const MAX_AUDIO_SECONDS: usize = 30 * 60;
+// Audio is given to the model in windows of at most this long, cut at the quietest moment
+// of their last `CUT_SEARCH_SECONDS` so that words are not split.
+const WINDOW_SECONDS: usize = 30;
+const CUT_SEARCH_SECONDS: usize = 5;
+// A pause this long between words, or a segment this long, starts a new segment.
+const SEGMENT_PAUSE_MS: u64 = 1000;
+const SEGMENT_MAX_MS: u64 = 20_000;
+// Tokens of the vocabulary that are not text.
+const SPECIAL_TOKENS: &[&str] = &["<pad>", "<s>", "</s>", "<unk>"];
+// Token separating words in the vocabulary.
+const WORD_DELIMITER: &str = "|";
+
+
+/// A stretch of a recording and what was said in it.
+#[derive(Debug, Clone)]
+pub struct TranscriptSegment {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
+
+struct SpeechModel {
+    session: Session,
+    /// Text of each token id, `None` for special tokens.
+    tokens: Vec<Option<String>>,
+    /// Id of the CTC blank token.
+    blank: usize,
+    language: Option<String>,
+}
+
+
+static SPEECH: Lazy<Mutex<Option<SpeechModel>>> = Lazy::new(|| Mutex::new(None));
+
+
+struct Word {
+    text: String,
+    start_ms: u64,
+    end_ms: u64,
+}
+
+
+/// Loads a CTC speech recognition model exported to ONNX, such as an MMS or wav2vec 2.0
+/// model fine-tuned for one language, with its `vocab.json` mapping tokens to ids. The model
+/// takes 16 kHz mono samples and returns logits per frame. `language` is the ISO 639 code
+/// stored with the transcripts, e.g. "yo" or "ha".
+pub fn init_transcription(model_path: String, vocabulary_path: String, language: Option<String>) -> Result<()> {
+    let vocabulary = std::fs::read_to_string(&vocabulary_path)
+        .map_err(|error| anyhow!("Cannot read {}: {}", vocabulary_path, error))?;
+    let vocabulary: HashMap<String, usize> = serde_json::from_str(&vocabulary)
+        .map_err(|error| anyhow!("Invalid vocabulary {}: {}", vocabulary_path, error))?;
+
+    let size = vocabulary.values().max().map_or(0, |id| id + 1);
+    let mut tokens = vec![None; size];
+    for (token, &id) in &vocabulary {
+        if !SPECIAL_TOKENS.contains(&token.as_str()) {
+            tokens[id] = Some(if token == WORD_DELIMITER { " ".to_string() } else { token.clone() });
+        }
+    }
+    let blank = vocabulary.get("<pad>").copied().unwrap_or(0);
+
+    let session = Session::builder()?
+        .with_optimization_level(GraphOptimizationLevel::Level1)?
+        .with_intra_threads(4)?
+        .commit_from_file(&model_path)
+        .map_err(|error| anyhow!("Cannot load the speech model {}: {}", model_path, error))?;
+    *SPEECH.lock().unwrap() = Some(SpeechModel { session, tokens, blank, language });
+
+    Ok(())
+}
+
+
+/// Unloads the speech model.
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_transcription() {
+    SPEECH.lock().unwrap().take();
+}
+
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_transcription_enabled() -> bool {
+    SPEECH.lock().unwrap().is_some()
+}
+
+
+/// Whether the file at `path` is an Ogg Opus stream, as WhatsApp voice notes are whatever
+/// their extension.
+fn is_ogg_opus(path: &Path) -> Result<bool> {
+    let mut head = Vec::new();
+    File::open(path)?.take(128).read_to_end(&mut head)?;
+    Ok(head.starts_with(b"OggS") && head.windows(8).any(|window| window == b"OpusHead"))
+}
+
+
+/// Decodes an Ogg Opus file to 16 kHz mono, which the decoder outputs directly.
+fn decode_ogg_opus(path: &Path) -> Result<Vec<f32>> {
+    let mut reader = ogg::PacketReader::new(BufReader::new(File::open(path)?));
+    let head = reader.read_packet()?.ok_or_else(|| anyhow!("{} is empty", path.display()))?;
+    if !head.data.starts_with(b"OpusHead") || head.data.len() < 19 {
+        return Err(anyhow!("{} has no Opus header", path.display()));
+    }
+    let serial = head.stream_serial();
+    // Samples at 48 kHz to drop from the start, which only prime the decoder.
+    let pre_skip = u16::from_le_bytes([head.data[10], head.data[11]]) as usize;
+
+    let mut decoder = OpusDecoder::new(SAMPLE_RATE, 1)?;
+    let mut frame = vec![0.0; decoder.max_frame_size_per_channel()];
+    let mut samples = Vec::new();
+    while let Some(packet) = reader.read_packet()? {
+        if packet.stream_serial() != serial || packet.data.starts_with(b"OpusTags") {
+            continue;
+        }
+        // A damaged packet only loses its few milliseconds.
+        if let Ok(count) = decoder.decode_float(&packet.data, &mut frame, false) {
+            samples.extend_from_slice(&frame[..count]);
+        }
+        if samples.len() > MAX_AUDIO_SECONDS * SAMPLE_RATE as usize {
+            return Err(anyhow!("{} is longer than {} seconds", path.display(), MAX_AUDIO_SECONDS));
+        }
+    }
+
+    samples.drain(..(pre_skip * SAMPLE_RATE as usize / 48_000).min(samples.len()));
+    Ok(samples)
+}
+
+
+/// Converts mono audio to 16 kHz as it is decoded, each output sample being the mean of the
+/// input samples it spans.
+struct Resampler {
+    // Input samples per output sample.
+    ratio: f64,
+    pending: Vec<f32>,
+    // Position of the next output sample in `pending`.
+    next: f64,
+}
+
+
+impl Resampler {
+    fn new(rate: u32) -> Self {
+        Self { ratio: rate as f64 / SAMPLE_RATE as f64, pending: Vec::new(), next: 0.0 }
+    }
+
+    fn push(&mut self, samples: &[f32], output: &mut Vec<f32>) {
+        self.pending.extend_from_slice(samples);
+        while self.next + self.ratio <= self.pending.len() as f64 {
+            let start = self.next as usize;
+            let end = ((self.next + self.ratio) as usize).max(start + 1);
+            output.push(self.pending[start..end].iter().sum::<f32>() / (end - start) as f32);
+            self.next += self.ratio;
+        }
+        let consumed = self.next as usize;
+        self.pending.drain(..consumed);
+        self.next -= consumed as f64;
+    }
+}
+
+
+/// Decodes a WAV, Ogg Vorbis or M4A file to 16 kHz mono.
+fn decode_with_symphonia(path: &Path) -> Result<Vec<f32>> {
+    let source = MediaSourceStream::new(Box::new(File::open(path)?), Default::default());
+    let mut hint = Hint::new();
+    if let Some(extension) = path.extension() {
+        hint.with_extension(&extension.to_string_lossy());
+    }
+    let mut format = symphonia::default::get_probe()
+        .probe(&hint, source, FormatOptions::default(), MetadataOptions::default())
+        .map_err(|error| anyhow!("Cannot read audio {}: {}", path.display(), error))?;
+
+    let track = format.default_track(TrackType::Audio).ok_or_else(|| anyhow!("{} has no audio track", path.display()))?;
+    let parameters = track.codec_params.as_ref().and_then(|parameters| parameters.audio());
+    let parameters = parameters.ok_or_else(|| anyhow!("{} has no audio track", path.display()))?;
+    let mut decoder = symphonia::default::get_codecs()
+        .make_audio_decoder(parameters, &AudioDecoderOptions::default())
+        .map_err(|error| anyhow!("Unsupported audio in {}: {}", path.display(), error))?;
+    let track_id = track.id;
+
+    let mut samples = Vec::new();
+    let mut resampler: Option<Resampler> = None;
+    let mut interleaved: Vec<f32> = Vec::new();
+    while let Some(packet) = format.next_packet()? {
+        if packet.track_id != track_id {
+            continue;
+        }
+        let buffer = match decoder.decode(&packet) {
+            Ok(buffer) => buffer,
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(error) => return Err(anyhow!("Cannot decode {}: {}", path.display(), error)),
+        };
+
+        let channels = buffer.spec().channels().count().max(1);
+        let resampler = resampler.get_or_insert_with(|| Resampler::new(buffer.spec().rate()));
+        interleaved.resize(buffer.samples_interleaved(), 0.0);
+        buffer.copy_to_slice_interleaved(&mut interleaved);
+        let mono: Vec<f32> = interleaved.chunks_exact(channels).map(|frame| frame.iter().sum::<f32>() / channels as f32).collect();
+        resampler.push(&mono, &mut samples);
+
+        if samples.len() > MAX_AUDIO_SECONDS * SAMPLE_RATE as usize {
+            return Err(anyhow!("{} is longer than {} seconds", path.display(), MAX_AUDIO_SECONDS));
+        }
+    }
+
+    Ok(samples)
+}
+
+
+/// Decodes the audio file at `path` to 16 kHz mono samples.
+fn decode_audio(path: &Path) -> Result<Vec<f32>> {
+    if is_ogg_opus(path).map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))? {
+        decode_ogg_opus(path)
+    } else {
+        decode_with_symphonia(path)
+    }
+}
+
+
+/// Where to end a window starting at `start`: at the quietest 20 ms of its last seconds, or
+/// at the end of the audio.
+fn window_end(samples: &[f32], start: usize) -> usize {
+    let window = WINDOW_SECONDS * SAMPLE_RATE as usize;
+    if samples.len() - start <= window {
+        return samples.len();
+    }
+
+    let frame = SAMPLE_RATE as usize / 50;
+    let search_start = start + window - CUT_SEARCH_SECONDS * SAMPLE_RATE as usize;
+    (search_start..start + window - frame)
+        .step_by(frame)
+        .min_by(|&a, &b| {
+            let energy = |at: usize| samples[at..at + frame].iter().map(|sample| sample * sample).sum::<f32>();
+            energy(a).total_cmp(&energy(b))
+        })
+        .map_or(start + window, |quietest| quietest + frame / 2)
+}
+
+
+/// Transcribes `samples`, which start `offset` samples into the recording, appending the
+/// words heard with their timing to `words`.
+fn transcribe_window(model: &mut SpeechModel, samples: &[f32], offset: usize, words: &mut Vec<Word>) -> Result<()> {
+    // The models expect audio normalised to zero mean and unit variance.
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    let variance = samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f32>() / samples.len() as f32;
+    let scale = 1.0 / (variance + 1e-7).sqrt();
+    let input = Array2::from_shape_fn((1, samples.len()), |(_, index)| (samples[index] - mean) * scale);
+
+    let input = ort::value::Tensor::from_array(input)?;
+    let outputs = model.session.run(ort::inputs![input])?;
+    let logits = outputs[0].try_extract_array::<f32>()?;
+    let (frames, classes) = match logits.shape() {
+        [.., frames, classes] => (*frames, *classes),
+        shape => return Err(anyhow!("Unexpected speech model output shape {:?}", shape)),
+    };
+    if frames == 0 {
+        return Ok(());
+    }
+    let logits: Vec<f32> = logits.iter().copied().collect();
+    let samples_per_frame = samples.len() as f64 / frames as f64;
+    let time_ms = |frame: usize| ((offset as f64 + frame as f64 * samples_per_frame) * 1000.0 / SAMPLE_RATE as f64) as u64;
+
+    // Greedy CTC decoding: the best token of each frame, without repeats and blanks.
+    let mut word: Option<Word> = None;
+    let mut previous = model.blank;
+    for (frame, scores) in logits.chunks_exact(classes).enumerate() {
+        let token = scores.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).map_or(model.blank, |(token, _)| token);
+        if token != model.blank && token != previous {
+            match model.tokens.get(token).cloned().flatten() {
+                Some(text) if text.trim().is_empty() => words.extend(word.take()),
+                Some(text) => {
+                    let current = word.get_or_insert_with(|| Word { text: String::new(), start_ms: time_ms(frame), end_ms: 0 });
+                    current.text.push_str(&text);
+                    current.end_ms = time_ms(frame + 1);
+                }
+                None => {}
+            }
+        }
+        previous = token;
+    }
+    words.extend(word);
+
+    Ok(())
+}
+
+
+/// Groups `words` into segments separated by pauses of at least `SEGMENT_PAUSE_MS`, and
+/// lasting at most `SEGMENT_MAX_MS`.
+fn group_segments(words: Vec<Word>) -> Vec<TranscriptSegment> {
+    let mut segments: Vec<TranscriptSegment> = Vec::new();
+    for word in words {
+        match segments.last_mut() {
+            Some(segment)
+                if word.start_ms < segment.end_ms + SEGMENT_PAUSE_MS && word.end_ms < segment.start_ms + SEGMENT_MAX_MS =>
+            {
+                segment.text.push(' ');
+                segment.text.push_str(&word.text);
+                segment.end_ms = word.end_ms;
+            }
+            _ => segments.push(TranscriptSegment { start_ms: word.start_ms, end_ms: word.end_ms, text: word.text }),
+        }
+    }
+    segments
+}
+
+
+/// Transcribes the recording at `path` with the loaded speech model, returning the language
+/// of the model with the segments.
+fn transcribe(path: &Path) -> Result<(Vec<TranscriptSegment>, Option<String>)> {
+    let samples = decode_audio(path)?;
+
+    let mut speech_lock = SPEECH.lock().unwrap();
+    let model = speech_lock.as_mut().ok_or_else(|| anyhow!("Transcription not initialized"))?;
+    let mut words = Vec::new();
+    let mut start = 0;
+    while start < samples.len() {
+        let end = window_end(&samples, start);
+        transcribe_window(model, &samples[start..end], start, &mut words)?;
+        start = end;
+    }
+
+    Ok((group_segments(words), model.language.clone()))
+}
+
+
+/// Transcribes the voice note or recording at `path`, in segments with their time in the
+/// recording, so that playback can start where something was said.
+pub fn transcribe_audio(path: String) -> Result<Vec<TranscriptSegment>> {
+    Ok(transcribe(Path::new(&path))?.0)
+}
+
+
+/// Transcribes a recording into one document per segment, carrying its time in the
+/// recording.
+pub(crate) fn extract_audio(path: &Path) -> Result<ExtractedFile> {
+    let (segments, language) = transcribe(path)?;
+
+    let metadata = DocumentMetadata { language, ..file_metadata(path, &None, &None) };
+    let documents = segments
+        .into_iter()
+        .enumerate()
+        .map(|(index, segment)| DocumentWithMetadata {
+            doc: Document { id: chunk_id(path, index), text: segment.text },
+            metadata: DocumentMetadata {
+                start_ms: Some(segment.start_ms),
+                end_ms: Some(segment.end_ms),
+                ..metadata.clone()
+            },
+        })
+        .collect();
+
+    Ok(ExtractedFile { path: metadata.path, title: None, author: None, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: u64, end_ms: u64) -> Word {
+        Word { text: text.to_string(), start_ms, end_ms }
+    }
+
+    #[test]
+    fn groups_words_between_pauses() {
+        let segments = group_segments(vec![
+            word("call", 0, 300),
+            word("me", 400, 600),
+            word("back", 1500, 1900),
+            word("tomorrow", 3000, 3600),
+        ]);
+
+        let segments: Vec<_> = segments.iter().map(|segment| (segment.text.as_str(), segment.start_ms, segment.end_ms)).collect();
+        assert_eq!(segments, vec![("call me back", 0, 1900), ("tomorrow", 3000, 3600)]);
+    }
+
+    #[test]
+    fn cuts_long_segments() {
+        let words = (0..30).map(|index| word("word", index * 1000, index * 1000 + 800)).collect();
+
+        let segments = group_segments(words);
+
+        assert_eq!(segments.len(), 2);
+        assert!(segments.iter().all(|segment| segment.end_ms - segment.start_ms < SEGMENT_MAX_MS));
+        assert_eq!(segments[1].start_ms, segments[0].end_ms + 200);
+    }
+
+    #[test]
+    fn ends_windows_at_a_quiet_moment() {
+        let rate = SAMPLE_RATE as usize;
+        let mut samples = vec![0.5; (WINDOW_SECONDS + 10) * rate];
+        let quiet = WINDOW_SECONDS * rate - rate;
+        samples[quiet..quiet + rate / 50].fill(0.0);
+
+        assert_eq!(window_end(&samples, 0), quiet + rate / 100);
+        assert_eq!(window_end(&samples[..rate], 0), rate);
+    }
+}
@@ -0,0 +1,410 @@
+use anyhow::{anyhow, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::api::date::days_from_civil;
+use crate::api::extract::{chunk_id, file_metadata, ExtractedFile, CHUNK_CHARS};
+use crate::api::office::attribute;
+use crate::api::tantivy::{Document, DocumentMetadata, DocumentWithMetadata};
+
+
+// Messages further apart than this start a new document.
+const WINDOW_GAP_SECS: i64 = 30 * 60;
+// Number of lines of a text file looked at to recognise a WhatsApp export.
+const SNIFF_LINES: usize = 20;
+// Number of bytes of an XML file looked at to recognise an SMS backup.
+const SNIFF_BYTES: u64 = 4096;
+// Name given to the phone owner in SMS backups.
+const OWN_NAME: &str = "Me";
+// WhatsApp placeholders for content that is not text.
+const PLACEHOLDERS: &[&str] = &["<Media omitted>", "This message was deleted", "You deleted this message", "null"];
+// Parts of WhatsApp system messages that can contain ": ", e.g. in a quoted group subject.
+const SYSTEM_MESSAGE_PARTS: &[&str] = &["\"", "\u{201c}", " changed the subject", " changed this group's", " created group"];
+
+
+struct Message {
+    chat: String,
+    sender: String,
+    /// Seconds since the Unix epoch.
+    timestamp: i64,
+    text: String,
+}
+
+
+/// Date and time of a WhatsApp message line, before the order of day and month is known.
+struct RawTimestamp {
+    date: [u32; 3],
+    year_first: bool,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+
+struct RawMessage {
+    timestamp: RawTimestamp,
+    // `None` for system messages such as "Ada added Bola".
+    sender: Option<String>,
+    text: String,
+}
+
+
+/// Parses "9:41", "21:41:05", "9:41 PM" or "9:41 p.m.".
+fn parse_time(time: &str) -> Option<(u32, u32, u32)> {
+    let time = time.trim();
+    let digits_end = time.find(|character: char| !(character.is_ascii_digit() || character == ':')).unwrap_or(time.len());
+    let (clock, suffix) = time.split_at(digits_end);
+    let parts: Vec<u32> = clock.split(':').map(|part| part.parse().ok()).collect::<Option<_>>()?;
+    let (mut hour, minute, second) = match parts[..] {
+        [hour, minute] => (hour, minute, 0),
+        [hour, minute, second] => (hour, minute, second),
+        _ => return None,
+    };
+
+    let suffix: String = suffix.chars().filter(char::is_ascii_alphabetic).collect::<String>().to_lowercase();
+    match suffix.as_str() {
+        "" => {}
+        "am" if (1..=12).contains(&hour) => hour %= 12,
+        "pm" if (1..=12).contains(&hour) => hour = hour % 12 + 12,
+        _ => return None,
+    }
+    (hour < 24 && minute < 60 && second <= 60).then_some((hour, minute, second))
+}
+
+
+/// Parses "31/12/2020", "12/31/20", "31.12.20" or "2020-12-31", leaving the order of day
+/// and month to the caller.
+fn parse_date(date: &str) -> Option<([u32; 3], bool)> {
+    let parts: Vec<&str> = date.trim().split(['/', '.', '-']).collect();
+    let [first, second, third] = parts[..] else { return None };
+    if [first, second, third].iter().any(|part| part.is_empty() || part.len() > 4 || !part.bytes().all(|byte| byte.is_ascii_digit())) {
+        return None;
+    }
+    Some(([first.parse().ok()?, second.parse().ok()?, third.parse().ok()?], first.len() == 4))
+}
+
+
+/// Parses the header of a WhatsApp message line, in the Android "31/12/2020, 21:41 - " or
+/// the iOS "[31/12/2020, 21:41:05] " form, returning the timestamp and the rest of the line.
+fn parse_header(line: &str) -> Option<(RawTimestamp, &str)> {
+    let line = line.trim_start_matches(['\u{200e}', '\u{feff}']);
+    let (header, rest) = match line.strip_prefix('[') {
+        Some(line) => {
+            let end = line.find(']')?;
+            (&line[..end], line[end + 1..].trim_start())
+        }
+        None => {
+            let end = line.find(" - ")?;
+            (&line[..end], &line[end + 3..])
+        }
+    };
+
+    let (date, time) = header.split_once([',', ' '])?;
+    let (date, year_first) = parse_date(date)?;
+    let (hour, minute, second) = parse_time(&time.replace(['\u{202f}', '\u{a0}'], " "))?;
+    Some((RawTimestamp { date, year_first, hour, minute, second }, rest))
+}
+
+
+fn parse_whatsapp_lines(text: &str) -> Vec<RawMessage> {
+    let mut messages: Vec<RawMessage> = Vec::new();
+    for line in text.lines() {
+        match parse_header(line) {
+            Some((timestamp, rest)) => {
+                let rest = rest.trim_start_matches('\u{200e}');
+                let (sender, text) = match rest.split_once(": ") {
+                    Some((sender, text)) if !SYSTEM_MESSAGE_PARTS.iter().any(|part| sender.contains(part)) => {
+                        (Some(sender.trim().to_string()), text.to_string())
+                    }
+                    _ => (None, rest.to_string()),
+                };
+                messages.push(RawMessage { timestamp, sender, text });
+            }
+            // Messages spanning several lines.
+            None => {
+                if let Some(message) = messages.last_mut() {
+                    message.text.push('\n');
+                    message.text.push_str(line);
+                }
+            }
+        }
+    }
+    messages
+}
+
+
+/// Whether `text` looks like a WhatsApp chat export: its first line and most of the
+/// following ones start with a message header.
+pub(crate) fn is_whatsapp_export(text: &str) -> bool {
+    let lines: Vec<&str> = text.lines().filter(|line| !line.trim().is_empty()).take(SNIFF_LINES).collect();
+    let headers = lines.iter().filter(|line| parse_header(line).is_some()).count();
+    lines.first().is_some_and(|line| parse_header(line).is_some()) && headers * 2 >= lines.len()
+}
+
+
+/// Name of the chat exported to `path`: Android names exports "WhatsApp Chat with <name>.txt",
+/// iOS puts "_chat.txt" in a "WhatsApp Chat - <name>" folder.
+fn whatsapp_chat_name(path: &Path) -> String {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let folder = path.parent().and_then(Path::file_name).map(|name| name.to_string_lossy().to_string());
+
+    let name = if stem == "_chat" { folder.unwrap_or_default() } else { stem };
+    for prefix in ["WhatsApp Chat with ", "WhatsApp Chat - ", "WhatsApp Chat "] {
+        if let Some(chat) = name.strip_prefix(prefix) {
+            return chat.trim().to_string();
+        }
+    }
+    name
+}
+
+
+/// Groups consecutive messages of the same chat into documents of at most `CHUNK_CHARS`
+/// characters, starting a new one after a pause of `WINDOW_GAP_SECS`. Each line of a
+/// document reads "<sender>: <text>".
+fn window_documents(path: &Path, messages: Vec<Message>, metadata: &DocumentMetadata) -> Vec<DocumentWithMetadata> {
+    let mut documents: Vec<DocumentWithMetadata> = Vec::new();
+    let mut last: Option<(String, i64)> = None;
+
+    for message in messages {
+        let line = format!("{}: {}", message.sender, message.text.trim());
+        let continues = documents.last().is_some_and(|document| {
+            last.as_ref().is_some_and(|(chat, timestamp)| {
+                *chat == message.chat && message.timestamp - timestamp <= WINDOW_GAP_SECS
+            }) && document.doc.text.chars().count() + 1 + line.chars().count() <= CHUNK_CHARS
+        });
+
+        if continues {
+            let document = documents.last_mut().unwrap();
+            document.doc.text.push('\n');
+            document.doc.text.push_str(&line);
+            if !document.metadata.senders.contains(&message.sender) {
+                document.metadata.senders.push(message.sender);
+            }
+        } else {
+            documents.push(DocumentWithMetadata {
+                doc: Document { id: chunk_id(path, documents.len()), text: line },
+                metadata: DocumentMetadata {
+                    chat: Some(message.chat.clone()),
+                    senders: vec![message.sender],
+                    timestamp: Some(message.timestamp),
+                    ..metadata.clone()
+                },
+            });
+        }
+        last = Some((message.chat, message.timestamp));
+    }
+
+    documents
+}
+
+
+/// Reads a WhatsApp chat export. Dates are written in the phone's format, so whether the
+/// day or the month comes first is decided from the dates that are only valid one way,
+/// defaulting to day first. Times are the local time of the phone, stored as if UTC.
+pub(crate) fn extract_whatsapp(path: &Path, text: &str) -> ExtractedFile {
+    let raw_messages = parse_whatsapp_lines(text);
+    let month_first = raw_messages.iter().any(|message| !message.timestamp.year_first && message.timestamp.date[1] > 12)
+        && !raw_messages.iter().any(|message| !message.timestamp.year_first && message.timestamp.date[0] > 12);
+
+    let chat = whatsapp_chat_name(path);
+    let messages = raw_messages
+        .into_iter()
+        .filter_map(|message| {
+            let sender = message.sender?;
+            let text = message.text.trim();
+            if text.is_empty() || PLACEHOLDERS.contains(&text) || text.ends_with(" omitted") && text.len() < 40 {
+                return None;
+            }
+
+            let RawTimestamp { date: [first, second, third], year_first, hour, minute, second: seconds } = message.timestamp;
+            let (year, month, day) = match (year_first, month_first) {
+                (true, _) => (first, second, third),
+                (false, true) => (third, first, second),
+                (false, false) => (third, second, first),
+            };
+            let year = if year < 100 { year + 2000 } else { year };
+            if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+                return None;
+            }
+            let timestamp = days_from_civil(year as i64, month as i64, day as i64) * 86_400
+                + (hour * 3600 + minute * 60 + seconds) as i64;
+
+            Some(Message { chat: chat.clone(), sender, timestamp, text: text.to_string() })
+        })
+        .collect();
+
+    let title = Some(chat);
+    let metadata = file_metadata(path, &title, &None);
+    let documents = window_documents(path, messages, &metadata);
+    ExtractedFile { path: metadata.path, title, author: None, documents }
+}
+
+
+fn sms_message(element: &BytesStart, sent: bool, text: String) -> Option<Message> {
+    let address = attribute(element, "address").unwrap_or_default();
+    let contact = attribute(element, "contact_name").filter(|name| !name.is_empty() && name != "(Unknown)");
+    let chat = contact.unwrap_or(address);
+    // Dates are in milliseconds.
+    let timestamp = attribute(element, "date")?.parse::<i64>().ok()? / 1000;
+
+    let text = text.trim().to_string();
+    if text.is_empty() || chat.is_empty() {
+        return None;
+    }
+    let sender = if sent { OWN_NAME.to_string() } else { chat.clone() };
+    Some(Message { chat, sender, timestamp, text })
+}
+
+
+/// Whether the XML file at `path` is an SMS backup, i.e. its root element is `smses`. Only
+/// the start of the file is read.
+pub(crate) fn is_sms_backup(path: &Path) -> Result<bool> {
+    let mut start = Vec::new();
+    File::open(path)?.take(SNIFF_BYTES).read_to_end(&mut start)?;
+    let start = String::from_utf8_lossy(&start);
+
+    let mut reader = Reader::from_str(&start);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element) | Event::Empty(element)) => return Ok(element.local_name().as_ref() == "smses"),
+            Ok(Event::Eof) | Err(_) => return Ok(false),
+            Ok(_) => {}
+        }
+    }
+}
+
+
+/// Reads an "SMS Backup & Restore" XML file: `sms` elements, and the text parts of `mms`
+/// elements. Messages are grouped by conversation, in time order.
+pub(crate) fn extract_sms_backup(path: &Path, xml: &str) -> Result<ExtractedFile> {
+    let mut reader = Reader::from_str(xml);
+    let mut messages = Vec::new();
+    let mut is_backup = false;
+    // MMS being read, with its text parts.
+    let mut mms: Option<(BytesStart<'static>, String)> = None;
+
+    loop {
+        match reader.read_event().map_err(|error| anyhow!("Invalid SMS backup: {}", error))? {
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "smses" => is_backup = true,
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "sms" => {
+                // Type 2 is a sent message.
+                let sent = attribute(&element, "type").as_deref() == Some("2");
+                messages.extend(sms_message(&element, sent, attribute(&element, "body").unwrap_or_default()));
+            }
+            Event::Start(element) if element.local_name().as_ref() == "mms" => mms = Some((element.into_owned(), String::new())),
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "part" => {
+                if let (Some((_, text)), Some("text/plain")) = (mms.as_mut(), attribute(&element, "ct").as_deref()) {
+                    if let Some(part) = attribute(&element, "text") {
+                        text.push_str(&part);
+                    }
+                }
+            }
+            Event::End(element) if element.local_name().as_ref() == "mms" => {
+                if let Some((element, text)) = mms.take() {
+                    let sent = attribute(&element, "msg_box").as_deref() == Some("2");
+                    messages.extend(sms_message(&element, sent, text));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if !is_backup {
+        return Err(anyhow!("{} is not an SMS backup", path.display()));
+    }
+
+    messages.sort_by(|a: &Message, b: &Message| a.chat.cmp(&b.chat).then(a.timestamp.cmp(&b.timestamp)));
+    let metadata = file_metadata(path, &None, &None);
+    let documents = window_documents(path, messages, &metadata);
+    Ok(ExtractedFile { path: metadata.path, title: None, author: None, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_clock_times() {
+        assert_eq!(parse_time("9:41"), Some((9, 41, 0)));
+        assert_eq!(parse_time("21:41:05"), Some((21, 41, 5)));
+        assert_eq!(parse_time("9:41 PM"), Some((21, 41, 0)));
+        assert_eq!(parse_time("12:05 a.m."), Some((0, 5, 0)));
+        assert_eq!(parse_time("13:00 PM"), None);
+        assert_eq!(parse_time("24:00"), None);
+    }
+
+    #[test]
+    fn parses_dates_in_any_order() {
+        assert_eq!(parse_date("31/12/2020"), Some(([31, 12, 2020], false)));
+        assert_eq!(parse_date("2020-12-31"), Some(([2020, 12, 31], true)));
+        assert_eq!(parse_date("31.12.20"), Some(([31, 12, 20], false)));
+        assert_eq!(parse_date("31/12"), None);
+        assert_eq!(parse_date("3l/12/20"), None);
+    }
+
+    #[test]
+    fn reads_month_first_whatsapp_exports() {
+        let text = "12/31/20, 9:41\u{202f}PM - Ada: Happy new year\nand many more\n1/2/21, 10:00 AM - Bola: Thanks\n";
+        let extracted = extract_whatsapp(Path::new("/chats/WhatsApp Chat with Ada.txt"), text);
+
+        assert_eq!(extracted.title.as_deref(), Some("Ada"));
+        let timestamps: Vec<_> = extracted.documents.iter().map(|document| document.metadata.timestamp).collect();
+        assert_eq!(timestamps, vec![Some(1_609_450_860), Some(1_609_581_600)]);
+        assert_eq!(extracted.documents[0].doc.text, "Ada: Happy new year\nand many more");
+    }
+
+    #[test]
+    fn reads_ios_whatsapp_exports_day_first() {
+        let text = "[05/03/2024, 08:15:30] Ada: Hello\n[05/03/2024, 08:16:00] Bola: <Media omitted>\n";
+        let extracted = extract_whatsapp(Path::new("/chats/WhatsApp Chat - Friends/_chat.txt"), text);
+
+        assert!(is_whatsapp_export(text));
+        assert_eq!(extracted.title.as_deref(), Some("Friends"));
+        assert_eq!(extracted.documents.len(), 1);
+        assert_eq!(extracted.documents[0].metadata.timestamp, Some(1_709_626_530));
+    }
+
+    #[test]
+    fn groups_sms_backups_by_conversation() {
+        let xml = r#"<smses count="3">
+            <sms address="+1555" contact_name="Ada" date="1709626530000" type="1" body="Hello" />
+            <sms address="+1666" contact_name="(Unknown)" date="1709626540000" type="1" body="Hi there" />
+            <sms address="+1555" contact_name="Ada" date="1709626590000" type="2" body="Hello Ada" />
+        </smses>"#;
+        let extracted = extract_sms_backup(Path::new("/backup/sms.xml"), xml).unwrap();
+
+        let chats: Vec<_> = extracted.documents.iter().map(|document| document.metadata.chat.clone().unwrap()).collect();
+        assert_eq!(chats, vec!["+1666", "Ada"]);
+        assert_eq!(extracted.documents[1].doc.text, "Ada: Hello\nMe: Hello Ada");
+        assert_eq!(extracted.documents[1].metadata.timestamp, Some(1_709_626_530));
+        assert!(extract_sms_backup(Path::new("/backup/other.xml"), "<notes />").is_err());
+    }
+
+    #[test]
+    fn system_messages_have_no_sender() {
+        let text = "31/12/2020, 21:41 - Ada: Hello\n31/12/2020, 21:42 - Ada changed the subject to \"Family: 2021\"\n";
+        let messages = parse_whatsapp_lines(text);
+
+        assert_eq!(messages[0].sender.as_deref(), Some("Ada"));
+        assert_eq!(messages[1].sender, None);
+        assert_eq!(messages[1].text, "Ada changed the subject to \"Family: 2021\"");
+    }
+
+    #[test]
+    fn recognises_sms_backups_from_their_root_element() {
+        let dir = std::env::temp_dir().join(format!("chat-sniff-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let backup = dir.join("sms.xml");
+        let other = dir.join("layout.xml");
+        std::fs::write(&backup, "<?xml version='1.0' encoding='UTF-8' standalone='yes' ?>\n<!--File Created By SMS Backup & Restore-->\n<smses count=\"0\" />").unwrap();
+        std::fs::write(&other, "<?xml version=\"1.0\"?>\n<LinearLayout><smses /></LinearLayout>").unwrap();
+
+        assert!(is_sms_backup(&backup).unwrap());
+        assert!(!is_sms_backup(&other).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
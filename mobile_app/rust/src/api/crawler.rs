@@ -0,0 +1,352 @@
+use anyhow::Result;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::api::archive::is_archive;
+use crate::frb_generated::StreamSink;
+
+
+/// What to do with symbolic links met while crawling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Links are reported as skipped and not followed.
+    Skip,
+    /// Links are followed. A folder already crawled is not crawled again, which also
+    /// protects against link cycles.
+    Follow,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct CrawlConfig {
+    pub roots: Vec<String>,
+    /// File extensions to report, without the leading dot and case insensitive. Every file
+    /// is reported when empty. ZIP archives are reported too: whether they hold such files is
+    /// only known once they are extracted, opening each one would slow the crawl down.
+    pub extensions: Vec<String>,
+    /// Glob patterns matched against full paths, e.g. "**/Android/data". Matching folders
+    /// are not entered.
+    pub exclude_globs: Vec<String>,
+    /// Number of folder levels entered below a root, unlimited when `None`. Files directly
+    /// in a root are at depth 0.
+    pub max_depth: Option<u32>,
+    pub symlinks: SymlinkPolicy,
+}
+
+
+impl Default for CrawlConfig {
+    /// Same rules as the Dart PDF scanner: hidden entries and the restricted Android
+    /// folders are skipped.
+    fn default() -> Self {
+        Self {
+            roots: vec!["/storage/emulated/0".to_string()],
+            extensions: vec!["pdf".to_string()],
+            exclude_globs: vec!["**/.*".to_string(), "**/Android/data".to_string(), "**/Android/obb".to_string()],
+            max_depth: None,
+            symlinks: SymlinkPolicy::Skip,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    Excluded,
+    MaxDepth,
+    Symlink,
+    /// A folder reached again through a symbolic link.
+    AlreadyVisited,
+}
+
+
+#[derive(Debug, Clone)]
+pub enum CrawlEvent {
+    File {
+        path: String,
+        size: u64,
+        /// Seconds since the Unix epoch.
+        modified: Option<i64>,
+    },
+    Skipped {
+        path: String,
+        reason: SkipReason,
+    },
+    /// A file or folder could not be read, e.g. for lack of permission. Crawling goes on.
+    Error {
+        path: String,
+        message: String,
+    },
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct CrawlSummary {
+    pub files: u64,
+    pub skipped: u64,
+    pub errors: u64,
+    /// The crawl was stopped before the end because the listener went away.
+    pub cancelled: bool,
+}
+
+
+struct Crawler<'a, F: FnMut(CrawlEvent) -> bool> {
+    config: &'a CrawlConfig,
+    extensions: HashSet<String>,
+    excludes: GlobSet,
+    visited_dirs: HashSet<PathBuf>,
+    summary: CrawlSummary,
+    emit: F,
+}
+
+
+impl<F: FnMut(CrawlEvent) -> bool> Crawler<'_, F> {
+    /// Sends `event`, returning false when crawling should stop.
+    fn send(&mut self, event: CrawlEvent) -> bool {
+        match &event {
+            CrawlEvent::File { .. } => self.summary.files += 1,
+            CrawlEvent::Skipped { .. } => self.summary.skipped += 1,
+            CrawlEvent::Error { .. } => self.summary.errors += 1,
+        }
+        if !(self.emit)(event) {
+            self.summary.cancelled = true;
+        }
+        !self.summary.cancelled
+    }
+
+    fn error(&mut self, path: &Path, error: impl ToString) -> bool {
+        self.send(CrawlEvent::Error { path: path.to_string_lossy().to_string(), message: error.to_string() })
+    }
+
+    fn skip(&mut self, path: &Path, reason: SkipReason) -> bool {
+        self.send(CrawlEvent::Skipped { path: path.to_string_lossy().to_string(), reason })
+    }
+
+    fn is_allowed_extension(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .is_some_and(|extension| self.extensions.contains(&extension.to_string_lossy().to_lowercase()))
+    }
+
+    fn crawl_dir(&mut self, dir: &Path, depth: u32) -> bool {
+        match std::fs::canonicalize(dir) {
+            Ok(canonical_dir) => {
+                if !self.visited_dirs.insert(canonical_dir) {
+                    return self.skip(dir, SkipReason::AlreadyVisited);
+                }
+            }
+            Err(error) => return self.error(dir, error),
+        }
+
+        let mut entries: Vec<PathBuf> = match std::fs::read_dir(dir) {
+            Ok(entries) => entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect(),
+            Err(error) => return self.error(dir, error),
+        };
+        entries.sort();
+
+        for path in entries {
+            if !self.crawl_entry(&path, depth) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn crawl_entry(&mut self, path: &Path, depth: u32) -> bool {
+        if self.excludes.is_match(path) {
+            return self.skip(path, SkipReason::Excluded);
+        }
+
+        let link_metadata = match std::fs::symlink_metadata(path) {
+            Ok(metadata) => metadata,
+            Err(error) => return self.error(path, error),
+        };
+        let metadata = if link_metadata.file_type().is_symlink() {
+            if self.config.symlinks == SymlinkPolicy::Skip {
+                return self.skip(path, SkipReason::Symlink);
+            }
+            match std::fs::metadata(path) {
+                Ok(metadata) => metadata,
+                Err(error) => return self.error(path, error),
+            }
+        } else {
+            link_metadata
+        };
+
+        if metadata.is_dir() {
+            if self.config.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                return self.skip(path, SkipReason::MaxDepth);
+            }
+            return self.crawl_dir(path, depth + 1);
+        }
+
+        if !metadata.is_file() {
+            return true;
+        }
+        if !self.is_allowed_extension(path) && !is_archive(path) {
+            return true;
+        }
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs() as i64);
+        self.send(CrawlEvent::File { path: path.to_string_lossy().to_string(), size: metadata.len(), modified })
+    }
+}
+
+
+/// Walks `config.roots` depth first, in path order, passing what it finds to `emit`.
+/// Crawling stops early when `emit` returns false.
+pub(crate) fn crawl_with(config: &CrawlConfig, emit: impl FnMut(CrawlEvent) -> bool) -> Result<CrawlSummary> {
+    let mut excludes = GlobSetBuilder::new();
+    for pattern in &config.exclude_globs {
+        excludes.add(Glob::new(pattern)?);
+    }
+
+    let mut crawler = Crawler {
+        config,
+        extensions: config
+            .extensions
+            .iter()
+            .map(|extension| extension.trim_start_matches('.').to_lowercase())
+            .collect(),
+        excludes: excludes.build()?,
+        visited_dirs: HashSet::new(),
+        summary: CrawlSummary::default(),
+        emit,
+    };
+
+    for root in &config.roots {
+        if !crawler.crawl_dir(Path::new(root), 0) {
+            break;
+        }
+    }
+
+    Ok(crawler.summary)
+}
+
+
+/// Crawls the folders described by `config`, streaming every file found, every path
+/// skipped and every error. Closing the stream on the Dart side stops the crawl.
+pub fn crawl_files(config: CrawlConfig, sink: StreamSink<CrawlEvent>) -> Result<CrawlSummary> {
+    crawl_with(&config, |event| sink.add(event).is_ok())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_tree(name: &str, files: &[&str]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("crawler-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        for file in files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "content").unwrap();
+        }
+        root
+    }
+
+    fn config(root: &Path) -> CrawlConfig {
+        CrawlConfig {
+            roots: vec![root.to_string_lossy().to_string()],
+            extensions: vec![],
+            exclude_globs: vec![],
+            max_depth: None,
+            symlinks: SymlinkPolicy::Skip,
+        }
+    }
+
+    /// Files found and paths skipped, relative to `root`.
+    fn crawl(root: &Path, config: &CrawlConfig) -> (Vec<String>, Vec<(String, SkipReason)>) {
+        let relative = |path: &str| Path::new(path).strip_prefix(root).unwrap().to_string_lossy().to_string();
+        let mut files = Vec::new();
+        let mut skipped = Vec::new();
+        crawl_with(config, |event| {
+            match event {
+                CrawlEvent::File { path, .. } => files.push(relative(&path)),
+                CrawlEvent::Skipped { path, reason } => skipped.push((relative(&path), reason)),
+                CrawlEvent::Error { path, message } => panic!("{}: {}", path, message),
+            }
+            true
+        })
+        .unwrap();
+        (files, skipped)
+    }
+
+    #[test]
+    fn stops_at_the_maximum_depth() {
+        let root = temp_tree("depth", &["a.pdf", "one/b.pdf", "one/two/c.pdf"]);
+
+        let (files, skipped) = crawl(&root, &CrawlConfig { max_depth: Some(1), ..config(&root) });
+
+        assert_eq!(files, vec!["a.pdf", "one/b.pdf"]);
+        assert_eq!(skipped, vec![("one/two".to_string(), SkipReason::MaxDepth)]);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn skips_excluded_paths_and_other_extensions() {
+        let root = temp_tree("excludes", &["a.PDF", "b.txt", ".hidden/c.pdf", "Android/data/d.pdf", "Android/e.pdf"]);
+
+        let config = CrawlConfig {
+            extensions: vec![".pdf".to_string()],
+            exclude_globs: vec!["**/.*".to_string(), "**/Android/data".to_string()],
+            ..config(&root)
+        };
+        let (files, skipped) = crawl(&root, &config);
+
+        assert_eq!(files, vec!["Android/e.pdf", "a.PDF"]);
+        assert_eq!(
+            skipped,
+            vec![(".hidden".to_string(), SkipReason::Excluded), ("Android/data".to_string(), SkipReason::Excluded)],
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn follows_symbolic_links_only_when_asked() {
+        let root = temp_tree("symlinks", &["docs/a.pdf"]);
+        std::os::unix::fs::symlink(root.join("docs"), root.join("link")).unwrap();
+
+        let (files, skipped) = crawl(&root, &config(&root));
+        assert_eq!(files, vec!["docs/a.pdf"]);
+        assert_eq!(skipped, vec![("link".to_string(), SkipReason::Symlink)]);
+
+        // The linked folder was already crawled through "docs".
+        let (files, skipped) = crawl(&root, &CrawlConfig { symlinks: SymlinkPolicy::Follow, ..config(&root) });
+        assert_eq!(files, vec!["docs/a.pdf"]);
+        assert_eq!(skipped, vec![("link".to_string(), SkipReason::AlreadyVisited)]);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_loop_on_link_cycles() {
+        let root = temp_tree("cycle", &["a/a.pdf"]);
+        std::os::unix::fs::symlink(&root, root.join("a/up")).unwrap();
+
+        let (files, skipped) = crawl(&root, &CrawlConfig { symlinks: SymlinkPolicy::Follow, ..config(&root) });
+
+        assert_eq!(files, vec!["a/a.pdf"]);
+        assert_eq!(skipped, vec![("a/up".to_string(), SkipReason::AlreadyVisited)]);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn reports_archives_without_opening_them() {
+        let root = temp_tree("archives", &["a.pdf", "b.txt", "bulletins.ZIP"]);
+
+        let (files, skipped) = crawl(&root, &CrawlConfig { extensions: vec!["pdf".to_string()], ..config(&root) });
+
+        assert_eq!(files, vec!["a.pdf", "bulletins.ZIP"]);
+        assert!(skipped.is_empty());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
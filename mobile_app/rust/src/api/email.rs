@@ -0,0 +1,260 @@
+use anyhow::{anyhow, Result};
+use mail_parser::mailbox::mbox::MessageIterator;
+use mail_parser::{Address, Message, MessageParser, MimeHeaders};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use crate::api::archive::{extract_embedded, is_archive, ScratchDir, ARCHIVE_SEPARATOR};
+use crate::api::extract::{chunk_id, file_metadata, split_paragraphs, ExtractedFile, CHUNK_CHARS, SUPPORTED_EXTENSIONS};
+use crate::api::tantivy::{Document, DocumentMetadata, DocumentWithMetadata};
+
+
+// Emails larger than this, attachments included, are not read.
+const MAX_MESSAGE_SIZE: u64 = 64 * 1024 * 1024;
+// Emails attached to emails, as when forwarding, are read down to this depth.
+const MAX_ATTACHED_DEPTH: u32 = 2;
+// Reading a mailbox stops after this many read errors in a row, the file being unreadable.
+const MAX_READ_ERRORS: u32 = 3;
+
+
+/// "Name <address>" for each address of `address`, or whichever of the two is known.
+fn format_addresses(address: Option<&Address>) -> Vec<String> {
+    let Some(address) = address else { return Vec::new() };
+    address
+        .iter()
+        .filter_map(|addr| match (addr.name().map(str::trim), addr.address()) {
+            (Some(name), Some(address)) if !name.is_empty() => Some(format!("{} <{}>", name, address)),
+            (name, address) => address.or(name).filter(|value| !value.is_empty()).map(str::to_string),
+        })
+        .collect()
+}
+
+
+/// Documents of the emails of one file, and of their attachments.
+struct MailExtraction<'a> {
+    path: &'a Path,
+    metadata: DocumentMetadata,
+    scratch: ScratchDir,
+    documents: Vec<DocumentWithMetadata>,
+    attachments: Vec<DocumentWithMetadata>,
+    attachment_paths: HashSet<String>,
+}
+
+
+impl<'a> MailExtraction<'a> {
+    fn new(path: &'a Path) -> Result<Self> {
+        Ok(Self {
+            path,
+            metadata: file_metadata(path, &None, &None),
+            scratch: ScratchDir::new()?,
+            documents: Vec::new(),
+            attachments: Vec::new(),
+            attachment_paths: HashSet::new(),
+        })
+    }
+
+    /// Adds the headers and body of `message` as documents of a few paragraphs, the first
+    /// starting with the subject, sender, recipients and date. Attachments the extractors
+    /// support are extracted under "<attachment_prefix><file name>", or "<name> (2).<ext>"
+    /// and so on when the name is already taken.
+    fn add_message(&mut self, message: &Message, attachment_prefix: &str, depth: u32) -> Result<()> {
+        let metadata = DocumentMetadata {
+            subject: message.subject().map(str::trim).filter(|subject| !subject.is_empty()).map(str::to_string),
+            senders: format_addresses(message.from()),
+            recipients: format_addresses(message.to()).into_iter().chain(format_addresses(message.cc())).collect(),
+            timestamp: message.date().map(|date| date.to_timestamp()),
+            ..self.metadata.clone()
+        };
+
+        let mut text = String::new();
+        let headers = [
+            ("Subject", metadata.subject.clone()),
+            ("From", Some(metadata.senders.join(", "))),
+            ("To", Some(metadata.recipients.join(", "))),
+            ("Date", message.header_raw("Date").map(|date| date.trim().to_string())),
+        ];
+        for (name, value) in headers {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                text.push_str(&format!("{}: {}\n", name, value));
+            }
+        }
+        // HTML bodies are turned into text, and alternatives of a body read once.
+        for index in 0..message.text_body_count() {
+            if let Some(body) = message.body_text(index) {
+                text.push('\n');
+                text.push_str(&body);
+            }
+        }
+
+        for chunk in split_paragraphs(&text, CHUNK_CHARS) {
+            self.documents.push(DocumentWithMetadata {
+                doc: Document { id: chunk_id(self.path, self.documents.len()), text: chunk },
+                metadata: metadata.clone(),
+            });
+        }
+
+        for part in message.attachments() {
+            if let Some(attached) = part.message() {
+                if depth < MAX_ATTACHED_DEPTH {
+                    self.add_message(attached, attachment_prefix, depth + 1)?;
+                }
+                continue;
+            }
+
+            // Only the file name is kept, names such as "../x.pdf" could lead out of the scratch folder.
+            let Some(name) = part.attachment_name().map(|name| name.replace('\\', "/")) else { continue };
+            let Some(name) = Path::new(&name).file_name().map(Path::new) else { continue };
+            let extension = name.extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default();
+            if !SUPPORTED_EXTENSIONS.contains(&extension.as_str()) && !is_archive(name) {
+                continue;
+            }
+
+            let mut virtual_path = format!("{}{}", attachment_prefix, name.to_string_lossy());
+            let mut copy = 1;
+            while !self.attachment_paths.insert(virtual_path.clone()) {
+                copy += 1;
+                let stem = name.file_stem().unwrap_or_default().to_string_lossy();
+                let dot_extension = name.extension().map(|extension| format!(".{}", extension.to_string_lossy())).unwrap_or_default();
+                virtual_path = format!("{}{} ({}){}", attachment_prefix, stem, copy, dot_extension);
+            }
+            let target = self.scratch.path().join(name);
+            std::fs::write(&target, part.contents())?;
+            for mut document in extract_embedded(&target, &virtual_path) {
+                document.metadata.subject = metadata.subject.clone();
+                document.metadata.senders = metadata.senders.clone();
+                document.metadata.recipients = metadata.recipients.clone();
+                document.metadata.timestamp = metadata.timestamp;
+                self.attachments.push(document);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> ExtractedFile {
+        self.documents.append(&mut self.attachments);
+        ExtractedFile { path: self.metadata.path, title: None, author: None, documents: self.documents }
+    }
+}
+
+
+/// Reads an RFC 5322 email, with its MIME parts decoded. Attachments are extracted under
+/// "<email>!/<file name>".
+pub(crate) fn extract_eml(path: &Path) -> Result<ExtractedFile> {
+    let file = File::open(path).map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?;
+    let mut bytes = Vec::new();
+    file.take(MAX_MESSAGE_SIZE + 1).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > MAX_MESSAGE_SIZE {
+        return Err(anyhow!("{} is larger than {} bytes", path.display(), MAX_MESSAGE_SIZE));
+    }
+    let message = MessageParser::default()
+        .parse(&bytes)
+        .ok_or_else(|| anyhow!("{} is not a valid email", path.display()))?;
+
+    let mut extraction = MailExtraction::new(path)?;
+    extraction.add_message(&message, &format!("{}{}", path.to_string_lossy(), ARCHIVE_SEPARATOR), 0)?;
+    Ok(extraction.finish())
+}
+
+
+/// Reads an mbox mailbox one email at a time. Attachments of the nth email, counting from 1,
+/// are extracted under "<mailbox>!/<n>/<file name>". Emails that are too large, cannot be
+/// parsed or cannot be read are left out.
+pub(crate) fn extract_mbox(path: &Path) -> Result<ExtractedFile> {
+    let file = File::open(path).map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?;
+    let mut extraction = MailExtraction::new(path)?;
+    let parser = MessageParser::default();
+    let (mut number, mut read_errors) = (0, 0);
+
+    for message in MessageIterator::new(BufReader::new(file)) {
+        let Ok(message) = message else {
+            read_errors += 1;
+            if read_errors == MAX_READ_ERRORS {
+                break;
+            }
+            continue;
+        };
+        read_errors = 0;
+        number += 1;
+
+        if message.contents().len() as u64 > MAX_MESSAGE_SIZE {
+            continue;
+        }
+        if let Some(parsed) = parser.parse(message.contents()) {
+            let attachment_prefix = format!("{}{}{}/", path.to_string_lossy(), ARCHIVE_SEPARATOR, number);
+            extraction.add_message(&parsed, &attachment_prefix, 0)?;
+        }
+    }
+
+    Ok(extraction.finish())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email(subject: &str, attachments: &[(&str, &str)]) -> String {
+        let mut email = format!(
+            "From: Ada Obi <ada@example.com>\r\nTo: bola@example.com\r\nSubject: {}\r\nDate: Tue, 5 Mar 2024 08:15:30 +0000\r\n\
+             MIME-Version: 1.0\r\nContent-Type: multipart/mixed; boundary=\"part\"\r\n\r\n\
+             --part\r\nContent-Type: text/plain\r\n\r\nSee the attached notes.\r\n",
+            subject,
+        );
+        for (name, content) in attachments {
+            email.push_str(&format!(
+                "--part\r\nContent-Type: text/plain\r\nContent-Disposition: attachment; filename=\"{}\"\r\n\r\n{}\r\n",
+                name, content,
+            ));
+        }
+        email.push_str("--part--\r\n");
+        email
+    }
+
+    #[test]
+    fn attachments_of_the_same_name_get_their_own_path_and_ids() {
+        let scratch = ScratchDir::new().unwrap();
+        let path = scratch.path().join("notes.eml");
+        std::fs::write(&path, email("Notes", &[("notes.txt", "First notes"), ("notes.txt", "Second notes")])).unwrap();
+
+        let extracted = extract_eml(&path).unwrap();
+
+        let email_path = path.to_string_lossy();
+        let documents: Vec<_> = extracted.documents.iter().map(|document| (document.metadata.path.clone(), document.doc.text.clone())).collect();
+        assert_eq!(documents, vec![
+            (email_path.to_string(), "Subject: Notes\nFrom: Ada Obi <ada@example.com>\nTo: bola@example.com\nDate: Tue, 5 Mar 2024 08:15:30 +0000\nSee the attached notes.".to_string()),
+            (format!("{}!/notes.txt", email_path), "First notes".to_string()),
+            (format!("{}!/notes (2).txt", email_path), "Second notes".to_string()),
+        ]);
+        let ids: HashSet<_> = extracted.documents.iter().map(|document| &document.doc.id).collect();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(extracted.documents[1].metadata.subject.as_deref(), Some("Notes"));
+    }
+
+    #[test]
+    fn mailbox_attachments_are_numbered_by_email() {
+        let scratch = ScratchDir::new().unwrap();
+        let path = scratch.path().join("inbox.mbox");
+        let mailbox = format!(
+            "From ada@example.com Tue Mar  5 08:15:30 2024\n{}\nFrom ada@example.com Wed Mar  6 08:15:30 2024\n{}",
+            email("First", &[("notes.txt", "First notes")]),
+            email("Second", &[("notes.txt", "Second notes")]),
+        );
+        std::fs::write(&path, mailbox).unwrap();
+
+        let extracted = extract_mbox(&path).unwrap();
+
+        let mailbox_path = path.to_string_lossy();
+        let paths: Vec<_> = extracted.documents.iter().map(|document| document.metadata.path.clone()).collect();
+        assert_eq!(paths, vec![
+            mailbox_path.to_string(),
+            mailbox_path.to_string(),
+            format!("{}!/1/notes.txt", mailbox_path),
+            format!("{}!/2/notes.txt", mailbox_path),
+        ]);
+        let subjects: Vec<_> = extracted.documents.iter().map(|document| document.metadata.subject.clone().unwrap()).collect();
+        assert_eq!(subjects, vec!["First", "Second", "First", "Second"]);
+    }
+}
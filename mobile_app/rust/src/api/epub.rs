@@ -0,0 +1,447 @@
+use anyhow::{anyhow, Result};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::api::extract::{chunk_id, file_metadata, ExtractedFile};
+use crate::api::office::{attribute, event_text, open_package, read_part};
+use crate::api::tantivy::{Document, DocumentMetadata, DocumentWithMetadata};
+
+
+// Elements whose end starts a new line of text.
+pub(crate) const BLOCK_ELEMENTS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "br", "dd", "div", "dl", "dt", "figcaption", "figure", "footer",
+    "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr", "li", "ol", "p", "pre", "section", "table", "td", "th",
+    "tr", "ul",
+];
+// Elements whose content is not text of the chapter.
+const SKIPPED_ELEMENTS: &[&str] = &["head", "script", "style", "svg", "math"];
+// Fonts are commonly obfuscated, which does not prevent reading the text.
+const FONT_EXTENSIONS: &[&str] = &[".ttf", ".otf", ".woff", ".woff2"];
+
+
+struct ManifestItem {
+    // Path of the item within the package.
+    path: String,
+    media_type: String,
+    properties: String,
+}
+
+
+struct Package {
+    title: Option<String>,
+    authors: Vec<String>,
+    language: Option<String>,
+    manifest: HashMap<String, ManifestItem>,
+    // Manifest ids of the items in reading order.
+    spine: Vec<String>,
+    // Manifest id of the EPUB 2 table of contents.
+    ncx_id: Option<String>,
+}
+
+
+struct ChapterText {
+    text: String,
+    heading: Option<String>,
+    title: Option<String>,
+}
+
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes.get(index + 1..index + 3).and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+
+/// Resolves `href`, relative to the package file `base`, to a path within the package.
+/// Fragments are dropped.
+fn resolve_href(base: &str, href: &str) -> String {
+    let href = percent_decode(href.split('#').next().unwrap_or_default());
+    let mut parts: Vec<&str> = match base.rfind('/') {
+        Some(end) if !href.starts_with('/') => base[..end].split('/').collect(),
+        _ => Vec::new(),
+    };
+    for part in href.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+
+/// Path of the OPF package document, from `META-INF/container.xml`.
+fn package_path(package: &mut ZipArchive<File>) -> Result<String> {
+    let container = read_part(package, "META-INF/container.xml")?
+        .ok_or_else(|| anyhow!("Not an EPUB: META-INF/container.xml is missing"))?;
+
+    let mut reader = Reader::from_str(&container);
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "rootfile" => {
+                if let Some(path) = attribute(&element, "full-path") {
+                    return Ok(path);
+                }
+            }
+            Event::Eof => return Err(anyhow!("META-INF/container.xml has no rootfile")),
+            _ => {}
+        }
+    }
+}
+
+
+/// Fails for books whose content is encrypted, usually by DRM.
+fn check_encryption(package: &mut ZipArchive<File>) -> Result<()> {
+    let Some(encryption) = read_part(package, "META-INF/encryption.xml")? else {
+        return Ok(());
+    };
+
+    let mut reader = Reader::from_str(&encryption);
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "CipherReference" => {
+                let uri = attribute(&element, "URI").unwrap_or_default().to_lowercase();
+                if !FONT_EXTENSIONS.iter().any(|extension| uri.ends_with(extension)) {
+                    return Err(anyhow!("The book is protected by DRM"));
+                }
+            }
+            Event::Eof => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+
+fn read_package(opf_path: &str, opf: &str) -> Result<Package> {
+    let mut reader = Reader::from_str(opf);
+    let mut package = Package {
+        title: None,
+        authors: Vec::new(),
+        language: None,
+        manifest: HashMap::new(),
+        spine: Vec::new(),
+        ncx_id: None,
+    };
+    // Local name and text of the metadata element being read.
+    let mut metadata_element: Option<(String, String)> = None;
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(element) | Event::Empty(element) => match element.local_name().as_ref() {
+                name @ ("title" | "creator" | "language") => {
+                    metadata_element = Some((name.to_string(), String::new()));
+                }
+                "item" => {
+                    if let (Some(id), Some(href)) = (attribute(element, "id"), attribute(element, "href")) {
+                        package.manifest.insert(id, ManifestItem {
+                            path: resolve_href(opf_path, &href),
+                            media_type: attribute(element, "media-type").unwrap_or_default(),
+                            properties: attribute(element, "properties").unwrap_or_default(),
+                        });
+                    }
+                }
+                "spine" => package.ncx_id = attribute(element, "toc"),
+                "itemref" => package.spine.extend(attribute(element, "idref")),
+                _ => {}
+            },
+            Event::End(_) => {
+                if let Some((name, value)) = metadata_element.take() {
+                    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if !value.is_empty() {
+                        match name.as_str() {
+                            "title" if package.title.is_none() => package.title = Some(value),
+                            "creator" => package.authors.push(value),
+                            "language" if package.language.is_none() => {
+                                package.language = value.split(['-', '_']).next().map(str::to_lowercase);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {
+                if let (Some((_, value)), Some(text)) = (metadata_element.as_mut(), event_text(&event)) {
+                    value.push_str(&text);
+                }
+            }
+        }
+    }
+
+    Ok(package)
+}
+
+
+/// Chapter titles from the table of contents, by package path of the chapter. Reads the
+/// EPUB 3 navigation document, or the EPUB 2 NCX file.
+fn read_toc(package: &Package, zip: &mut ZipArchive<File>) -> HashMap<String, String> {
+    let nav = package.manifest.values().find(|item| item.properties.split_whitespace().any(|property| property == "nav"));
+    let ncx = package.ncx_id.as_ref().and_then(|id| package.manifest.get(id));
+
+    let mut titles = HashMap::new();
+    for (item, is_nav) in [(nav, true), (ncx, false)] {
+        let Some(item) = item else { continue };
+        let Some(xml) = read_part(zip, &item.path).ok().flatten() else { continue };
+
+        let mut reader = Reader::from_str(&xml);
+        reader.config_mut().check_end_names = false;
+        let mut in_toc = !is_nav;
+        // Label of the entry being read. A nav anchor holds its label, an NCX navPoint has
+        // its label before the content element pointing to the chapter.
+        let mut label: Option<String> = None;
+        let mut reading_label = false;
+        let mut target: Option<String> = None;
+        let mut add_title = |target: Option<String>, label: Option<String>| {
+            if let (Some(href), Some(label)) = (target, label) {
+                let label = label.split_whitespace().collect::<Vec<_>>().join(" ");
+                if !label.is_empty() {
+                    titles.entry(resolve_href(&item.path, &href)).or_insert(label);
+                }
+            }
+        };
+
+        while let Ok(event) = reader.read_event() {
+            match &event {
+                Event::Start(element) | Event::Empty(element) => match element.local_name().as_ref() {
+                    "nav" if is_nav => {
+                        in_toc = attribute(element, "epub:type").is_some_and(|kind| kind.contains("toc"));
+                    }
+                    "a" if is_nav && in_toc => {
+                        target = attribute(element, "href");
+                        label = Some(String::new());
+                        reading_label = true;
+                    }
+                    "navLabel" if !is_nav => {
+                        label = Some(String::new());
+                        reading_label = true;
+                    }
+                    "content" if !is_nav => add_title(attribute(element, "src"), label.take()),
+                    _ => {}
+                },
+                Event::End(element) => match element.local_name().as_ref() {
+                    "nav" if is_nav => in_toc = false,
+                    "a" if is_nav => {
+                        add_title(target.take(), label.take());
+                        reading_label = false;
+                    }
+                    "navLabel" => reading_label = false,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {
+                    if let (true, Some(label), Some(text)) = (reading_label, label.as_mut(), event_text(&event)) {
+                        label.push_str(&text);
+                    }
+                }
+            }
+        }
+
+        if !titles.is_empty() {
+            break;
+        }
+    }
+
+    titles
+}
+
+
+/// Strips an XHTML chapter to its text, one line per block element. Also returns the
+/// first heading and the document title to name the chapter.
+fn chapter_text(xhtml: &str) -> Result<ChapterText> {
+    let mut reader = Reader::from_str(xhtml);
+    reader.config_mut().check_end_names = false;
+
+    let mut chapter = ChapterText { text: String::new(), heading: None, title: None };
+    let mut skipped_depth = 0;
+    let mut heading: Option<String> = None;
+    let mut title: Option<String> = None;
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(element) => {
+                let name = element.local_name();
+                let name = name.as_ref();
+                if name == "title" && chapter.title.is_none() {
+                    title = Some(String::new());
+                } else if SKIPPED_ELEMENTS.contains(&name) {
+                    skipped_depth += 1;
+                } else if matches!(name, "h1" | "h2" | "h3") && chapter.heading.is_none() && skipped_depth == 0 {
+                    heading = Some(String::new());
+                }
+            }
+            Event::Empty(element) => {
+                if BLOCK_ELEMENTS.contains(&element.local_name().as_ref()) && skipped_depth == 0 {
+                    chapter.text.push('\n');
+                }
+            }
+            Event::End(element) => {
+                let name = element.local_name();
+                let name = name.as_ref();
+                if name == "title" {
+                    chapter.title = title.take().map(|title| title.split_whitespace().collect::<Vec<_>>().join(" "));
+                } else if SKIPPED_ELEMENTS.contains(&name) {
+                    skipped_depth = (skipped_depth - 1).max(0);
+                } else if skipped_depth == 0 && BLOCK_ELEMENTS.contains(&name) {
+                    if matches!(name, "h1" | "h2" | "h3") {
+                        if let Some(heading) = heading.take() {
+                            chapter.heading = Some(heading.split_whitespace().collect::<Vec<_>>().join(" "));
+                        }
+                    }
+                    chapter.text.push('\n');
+                }
+            }
+            Event::Eof => break,
+            _ => {
+                if let Some(text) = event_text(&event) {
+                    if let Some(title) = title.as_mut() {
+                        title.push_str(&text);
+                    } else if skipped_depth == 0 {
+                        chapter.text.push_str(&text);
+                        if let Some(heading) = heading.as_mut() {
+                            heading.push_str(&text);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    chapter.heading = chapter.heading.filter(|heading| !heading.is_empty());
+    chapter.title = chapter.title.filter(|title| !title.is_empty());
+    Ok(chapter)
+}
+
+
+/// Joins the lines of `text`, dropping blank ones and collapsing runs of whitespace.
+fn clean_text(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+
+/// Reads the EPUB at `path` in spine order, one document per chapter that has text. The
+/// book title, authors and language are set on every document.
+pub(crate) fn extract_epub(path: &Path) -> Result<ExtractedFile> {
+    let mut zip = open_package(path)?;
+    check_encryption(&mut zip)?;
+
+    let opf_path = package_path(&mut zip)?;
+    let opf = read_part(&mut zip, &opf_path)?.ok_or_else(|| anyhow!("{} is missing", opf_path))?;
+    let package = read_package(&opf_path, &opf).map_err(|error| anyhow!("Invalid {}: {}", opf_path, error))?;
+    let toc = read_toc(&package, &mut zip);
+
+    let author = (!package.authors.is_empty()).then(|| package.authors.join(", "));
+    let mut metadata = file_metadata(path, &package.title, &author);
+    metadata.language = package.language.clone();
+
+    let mut documents = Vec::new();
+    for id in &package.spine {
+        let Some(item) = package.manifest.get(id) else { continue };
+        if !matches!(item.media_type.as_str(), "application/xhtml+xml" | "text/html") {
+            continue;
+        }
+        let Some(xhtml) = read_part(&mut zip, &item.path)? else { continue };
+        let chapter = chapter_text(&xhtml).map_err(|error| anyhow!("Invalid {}: {}", item.path, error))?;
+
+        let text = clean_text(&chapter.text);
+        if text.is_empty() {
+            continue;
+        }
+        let chapter_title = toc
+            .get(&item.path)
+            .cloned()
+            .or(chapter.heading)
+            .or(chapter.title.filter(|title| Some(title) != package.title.as_ref()));
+
+        let index = documents.len();
+        documents.push(DocumentWithMetadata {
+            doc: Document { id: chunk_id(path, index), text },
+            metadata: DocumentMetadata {
+                chapter: chapter_title,
+                chapter_index: Some(index as u32 + 1),
+                ..metadata.clone()
+            },
+        });
+    }
+
+    Ok(ExtractedFile { path: metadata.path, title: package.title, author, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_hrefs_within_the_package() {
+        assert_eq!(resolve_href("OEBPS/content.opf", "text/chapter%201.xhtml#start"), "OEBPS/text/chapter 1.xhtml");
+        assert_eq!(resolve_href("OEBPS/text/toc.xhtml", "../images/./cover.jpg"), "OEBPS/images/cover.jpg");
+        assert_eq!(resolve_href("content.opf", "/chapter.xhtml"), "chapter.xhtml");
+        assert_eq!(percent_decode("caf%C3%A9 %zz%"), "café %zz%");
+    }
+
+    #[test]
+    fn reads_the_package_metadata_and_spine() {
+        let opf = r#"<package xmlns="http://www.idpf.org/2007/opf" xmlns:dc="http://purl.org/dc/elements/1.1/">
+            <metadata>
+                <dc:title>  Things Fall
+                    Apart </dc:title>
+                <dc:creator>Chinua Achebe</dc:creator>
+                <dc:language>en-GB</dc:language>
+            </metadata>
+            <manifest>
+                <item id="one" href="text/one.xhtml" media-type="application/xhtml+xml"/>
+                <item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
+            </manifest>
+            <spine toc="ncx"><itemref idref="one"/></spine>
+        </package>"#;
+
+        let package = read_package("OEBPS/content.opf", opf).unwrap();
+
+        assert_eq!(package.title.as_deref(), Some("Things Fall Apart"));
+        assert_eq!(package.authors, vec!["Chinua Achebe"]);
+        assert_eq!(package.language.as_deref(), Some("en"));
+        assert_eq!(package.spine, vec!["one"]);
+        assert_eq!(package.ncx_id.as_deref(), Some("ncx"));
+        assert_eq!(package.manifest["one"].path, "OEBPS/text/one.xhtml");
+    }
+
+    #[test]
+    fn strips_chapters_to_their_text() {
+        let xhtml = "<html><head><title>Part One</title><style>p { color: red }</style></head>\
+                     <body><h1>Chapter <em>1</em></h1><p>Okonkwo was well known.</p><br/><script>x()</script><p>Last</p></body></html>";
+
+        let chapter = chapter_text(xhtml).unwrap();
+
+        assert_eq!(chapter.title.as_deref(), Some("Part One"));
+        assert_eq!(chapter.heading.as_deref(), Some("Chapter 1"));
+        assert_eq!(clean_text(&chapter.text), "Chapter 1\nOkonkwo was well known.\nLast");
+    }
+}
@@ -0,0 +1,280 @@
+use anyhow::{anyhow, Result};
+use sha1::{Digest, Sha1};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::api::archive::extract_archive;
+use crate::api::audio::extract_audio;
+use crate::api::chat::{extract_sms_backup, is_sms_backup};
+use crate::api::email::{extract_eml, extract_mbox};
+use crate::api::ocr::extract_image;
+use crate::api::office::{extract_docx, extract_odt};
+use crate::api::pdf::extract_pdf_with;
+use crate::api::epub::extract_epub;
+use crate::api::sheet::{extract_csv, extract_xlsx};
+use crate::api::text::{extract_text_file, read_text_file, TextFormat};
+use crate::api::tantivy::{replace_file_documents, Document, DocumentMetadata, DocumentWithMetadata};
+
+
+// Documents made from files without pages are about the size of a PDF page.
+pub(crate) const CHUNK_CHARS: usize = 3000;
+// Extensions `extract_file` has an extractor for, archives aside.
+pub(crate) const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "pdf", "docx", "odt", "epub", "txt", "md", "markdown", "html", "htm", "csv", "tsv", "xlsx", "jpg", "jpeg", "png",
+    "opus", "ogg", "m4a", "wav", "xml", "eml", "mbox",
+];
+
+
+/// Text and properties read from a file that has no pages, before it is split into
+/// documents.
+pub(crate) struct ExtractedText {
+    pub(crate) title: Option<String>,
+    pub(crate) author: Option<String>,
+    pub(crate) text: String,
+}
+
+
+/// A file turned into documents ready to be indexed.
+#[derive(Debug, Clone)]
+pub struct ExtractedFile {
+    pub path: String,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// One document per PDF page or e-book chapter, per conversation window for chats, per
+    /// few rows for spreadsheets, per transcript segment for recordings, or per chunk of
+    /// paragraphs for other formats. Their metadata carries the file properties, but not its
+    /// modification time.
+    pub documents: Vec<DocumentWithMetadata>,
+}
+
+
+/// Id of the `index`th document of the file at `path`, "<SHA-1 of the path>-<index>". The
+/// whole path is hashed, virtual paths inside archives and emails included, so that files
+/// of the same name in different folders get different ids.
+pub(crate) fn chunk_id(path: &Path, index: usize) -> String {
+    let hash: String = Sha1::digest(path.to_string_lossy().as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}-{}", hash, index)
+}
+
+
+/// Groups the lines of `text` into chunks of at most `max_chars` characters, cutting only
+/// between paragraphs unless a single paragraph is longer than that.
+pub(crate) fn split_paragraphs(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    let mut chunk_chars = 0;
+
+    for paragraph in text.lines().map(str::trim).filter(|paragraph| !paragraph.is_empty()) {
+        let mut paragraph = paragraph;
+        let mut paragraph_chars = paragraph.chars().count();
+
+        if chunk_chars > 0 && chunk_chars + 1 + paragraph_chars > max_chars {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_chars = 0;
+        }
+
+        while paragraph_chars > max_chars {
+            // Cut at the last space before the limit, or at the limit if there is none.
+            let limit = paragraph.char_indices().nth(max_chars).map_or(paragraph.len(), |(index, _)| index);
+            let cut = paragraph[..limit].rfind(char::is_whitespace).filter(|&cut| cut > 0).unwrap_or(limit);
+            chunks.push(paragraph[..cut].trim_end().to_string());
+            paragraph = paragraph[cut..].trim_start();
+            paragraph_chars = paragraph.chars().count();
+        }
+
+        if chunk_chars > 0 {
+            chunk.push('\n');
+            chunk_chars += 1;
+        }
+        chunk.push_str(paragraph);
+        chunk_chars += paragraph_chars;
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+
+/// Metadata shared by every document of the file at `path`.
+pub(crate) fn file_metadata(path: &Path, title: &Option<String>, author: &Option<String>) -> DocumentMetadata {
+    DocumentMetadata {
+        path: path.to_string_lossy().to_string(),
+        title: title.clone(),
+        author: author.clone(),
+        ..DocumentMetadata::default()
+    }
+}
+
+
+fn from_text(path: &Path, extracted: ExtractedText) -> ExtractedFile {
+    let metadata = file_metadata(path, &extracted.title, &extracted.author);
+    let documents = split_paragraphs(&extracted.text, CHUNK_CHARS)
+        .into_iter()
+        .enumerate()
+        .map(|(index, text)| DocumentWithMetadata {
+            doc: Document { id: chunk_id(path, index), text },
+            metadata: metadata.clone(),
+        })
+        .collect();
+
+    ExtractedFile { path: metadata.path, title: extracted.title, author: extracted.author, documents }
+}
+
+
+fn extract_pdf_file(path: &Path) -> Result<ExtractedFile> {
+    let mut pages = Vec::new();
+    let info = extract_pdf_with(path, |page| {
+        if !page.text.is_empty() {
+            pages.push(Document { id: chunk_id(path, page.page_number as usize - 1), text: page.text });
+        }
+        true
+    })?;
+
+    let metadata = file_metadata(path, &info.title, &info.author);
+    let documents = pages
+        .into_iter()
+        .map(|doc| DocumentWithMetadata { doc, metadata: metadata.clone() })
+        .collect();
+
+    Ok(ExtractedFile { path: metadata.path, title: info.title, author: info.author, documents })
+}
+
+
+fn nfc(text: &str) -> String {
+    text.nfc().collect()
+}
+
+
+/// Brings every text of `file` to Unicode normalisation form C, so that the same accented
+/// letter typed with a combining diacritic or as a single character matches.
+fn normalize(mut file: ExtractedFile) -> ExtractedFile {
+    file.title = file.title.as_deref().map(nfc);
+    file.author = file.author.as_deref().map(nfc);
+    for DocumentWithMetadata { doc, metadata } in &mut file.documents {
+        doc.text = nfc(&doc.text);
+        let texts = [
+            &mut metadata.title,
+            &mut metadata.author,
+            &mut metadata.chapter,
+            &mut metadata.chat,
+            &mut metadata.sheet,
+            &mut metadata.subject,
+        ];
+        for value in texts.into_iter().flatten() {
+            *value = nfc(value);
+        }
+        for value in metadata.headings.iter_mut().chain(&mut metadata.senders).chain(&mut metadata.recipients) {
+            *value = nfc(value);
+        }
+    }
+    file
+}
+
+
+/// Extracts the text of the file at `path` with the extractor for its extension. XML files
+/// other than SMS backups give no documents.
+pub fn extract_file(path: String) -> Result<ExtractedFile> {
+    let path = Path::new(&path);
+    let extension = path.extension().map(|extension| extension.to_string_lossy().to_lowercase());
+
+    let extracted = match extension.as_deref() {
+        Some("pdf") => extract_pdf_file(path)?,
+        Some("docx") => from_text(path, extract_docx(path)?),
+        Some("odt") => from_text(path, extract_odt(path)?),
+        Some("epub") => extract_epub(path)?,
+        Some("txt") => extract_text_file(path, TextFormat::Plain)?,
+        Some("md" | "markdown") => extract_text_file(path, TextFormat::Markdown)?,
+        Some("html" | "htm") => extract_text_file(path, TextFormat::Html)?,
+        Some("csv" | "tsv") => extract_csv(path)?,
+        Some("xlsx") => extract_xlsx(path)?,
+        Some("jpg" | "jpeg" | "png") => extract_image(path)?,
+        Some("opus" | "ogg" | "m4a" | "wav") => extract_audio(path)?,
+        Some("xml") if is_sms_backup(path)? => extract_sms_backup(path, &read_text_file(path, TextFormat::Plain)?)?,
+        Some("xml") => {
+            let metadata = file_metadata(path, &None, &None);
+            ExtractedFile { path: metadata.path, title: None, author: None, documents: Vec::new() }
+        }
+        Some("eml") => extract_eml(path)?,
+        Some("mbox") => extract_mbox(path)?,
+        Some("zip") => extract_archive(path)?,
+        _ => return Err(anyhow!("Unsupported file type: {}", path.display())),
+    };
+
+    Ok(normalize(extracted))
+}
+
+
+/// Extracts the file at `path` and replaces whatever was indexed from it with the new
+/// documents, in a single commit. Returns the ids of the documents, to record with
+/// `record_indexed_files` and to compute their embeddings.
+pub fn index_file(path: String) -> Result<Vec<String>> {
+    let modified = std::fs::metadata(&path)?
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs() as i64);
+    let mut extracted = extract_file(path)?;
+
+    let ids = extracted.documents.iter().map(|document| document.doc.id.clone()).collect();
+    for document in &mut extracted.documents {
+        document.metadata.modified = modified;
+    }
+    replace_file_documents(extracted.path, extracted.documents)?;
+
+    Ok(ids)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_ids_differ_between_folders() {
+        let first = chunk_id(Path::new("/storage/2023/report.pdf"), 0);
+        let second = chunk_id(Path::new("/storage/2024/report.pdf"), 0);
+        let inside_archive = chunk_id(Path::new("/storage/reports.zip!/2024/report.pdf"), 0);
+
+        assert_ne!(first, second);
+        assert_ne!(second, inside_archive);
+        assert_eq!(first, chunk_id(Path::new("/storage/2023/report.pdf"), 0));
+        assert!(first.ends_with("-0"));
+    }
+
+    #[test]
+    fn splits_between_paragraphs() {
+        let chunks = split_paragraphs("first paragraph\n\n  second paragraph  \nthird", 34);
+
+        assert_eq!(chunks, vec!["first paragraph\nsecond paragraph", "third"]);
+    }
+
+    #[test]
+    fn cuts_long_paragraphs_at_spaces() {
+        let chunks = split_paragraphs("one two three four", 9);
+
+        assert_eq!(chunks, vec!["one two", "three", "four"]);
+    }
+
+    #[test]
+    fn cuts_long_words_at_the_limit_between_characters() {
+        let chunks = split_paragraphs("ẹẹẹẹẹ", 2);
+
+        assert_eq!(chunks, vec!["ẹẹ", "ẹẹ", "ẹ"]);
+    }
+
+    #[test]
+    fn other_xml_files_give_no_documents() {
+        let dir = std::env::temp_dir().join(format!("extract-xml-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("strings.xml");
+        std::fs::write(&path, "<resources><string name=\"app\">Search</string></resources>").unwrap();
+
+        let extracted = extract_file(path.to_string_lossy().to_string()).unwrap();
+
+        assert!(extracted.documents.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
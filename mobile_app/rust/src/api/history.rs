@@ -0,0 +1,351 @@
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::api::tantivy::index_terms_with_prefix;
+
+
+const HISTORY_FILE: &str = "search_history.json";
+// Written by the Dart `saveSearchHistory`, one query per line, oldest first.
+const LEGACY_HISTORY_FILE: &str = "search_history.txt";
+const MAX_HISTORY_ENTRIES: usize = 500;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SuggestionSource {
+    History,
+    Index,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub text: String,
+    pub source: SuggestionSource,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HistoryEntry {
+    query: String,
+    count: u32,
+    /// Seconds since the Unix epoch.
+    last_used: i64,
+}
+
+
+impl HistoryEntry {
+    /// Frequency weighted by recency, so that a query used a lot last month does not
+    /// outrank one used twice today.
+    fn frecency(&self, now: i64) -> f64 {
+        let age_days = (now - self.last_used).max(0) as f64 / SECONDS_PER_DAY;
+        self.count as f64 / (1.0 + age_days)
+    }
+}
+
+
+struct SearchHistory {
+    path: PathBuf,
+    entries: Vec<HistoryEntry>,
+}
+
+
+impl SearchHistory {
+    fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(HISTORY_FILE);
+        let entries = if path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            import_legacy_history(&dir.join(LEGACY_HISTORY_FILE))?
+        };
+
+        Ok(Self { path, entries })
+    }
+
+    fn record(&mut self, query: &str, now: i64) {
+        match self.entries.iter_mut().find(|entry| entry.query == query) {
+            Some(entry) => {
+                entry.count += 1;
+                entry.last_used = now;
+            }
+            None => self.entries.push(HistoryEntry {
+                query: query.to_string(),
+                count: 1,
+                last_used: now,
+            }),
+        }
+
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            self.entries.sort_by(|a, b| b.frecency(now).total_cmp(&a.frecency(now)));
+            self.entries.truncate(MAX_HISTORY_ENTRIES);
+        }
+    }
+
+    /// Past queries starting with `prefix`, ignoring case, best first.
+    fn matching(&self, prefix: &str, now: i64) -> Vec<&HistoryEntry> {
+        let lowercase_prefix = prefix.to_lowercase();
+        let mut matches: Vec<&HistoryEntry> = self.entries
+            .iter()
+            .filter(|entry| entry.query.to_lowercase().starts_with(&lowercase_prefix))
+            .collect();
+        matches.sort_by(|a, b| b.frecency(now).total_cmp(&a.frecency(now)));
+        matches
+    }
+
+    fn save(&self) -> Result<()> {
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string(&self.entries)?)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+
+static HISTORY: Lazy<Mutex<Option<SearchHistory>>> = Lazy::new(|| Mutex::new(None));
+
+
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+
+fn import_legacy_history(path: &Path) -> Result<Vec<HistoryEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    // The legacy file has no timestamps, keep its order by spacing entries a second apart.
+    let now = now();
+    let lines: Vec<String> = std::fs::read_to_string(path)?
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+    let total = lines.len() as i64;
+
+    Ok(lines
+        .into_iter()
+        .enumerate()
+        .map(|(position, query)| HistoryEntry {
+            query,
+            count: 1,
+            last_used: now - (total - position as i64),
+        })
+        .collect())
+}
+
+
+/// Loads the search history stored in `dir_path`, importing the Dart history file from
+/// the same directory the first time.
+#[flutter_rust_bridge::frb(sync)]
+pub fn init_search_history(dir_path: String) -> Result<()> {
+    let mut history_lock = HISTORY.lock().unwrap();
+    if history_lock.is_some() {
+        return Ok(());
+    }
+
+    let dir = PathBuf::from(dir_path);
+    std::fs::create_dir_all(&dir)?;
+    *history_lock = Some(SearchHistory::load(&dir)?);
+
+    Ok(())
+}
+
+
+pub fn record_search(query: String) -> Result<()> {
+    // In NFC, so that a query typed with precomposed or combining accents is one entry.
+    let query: String = query.nfc().collect();
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(());
+    }
+
+    let mut history_lock = HISTORY.lock().unwrap();
+    let history = history_lock.as_mut().ok_or_else(|| anyhow!("Search history not initialized"))?;
+
+    history.record(query, now());
+
+    history.save()
+}
+
+
+/// Most recent queries first.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_search_history(limit: usize) -> Result<Vec<String>> {
+    let history_lock = HISTORY.lock().unwrap();
+    let history = history_lock.as_ref().ok_or_else(|| anyhow!("Search history not initialized"))?;
+
+    let mut entries: Vec<&HistoryEntry> = history.entries.iter().collect();
+    entries.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+
+    Ok(entries
+        .into_iter()
+        .take(limit)
+        .map(|entry| entry.query.clone())
+        .collect())
+}
+
+
+pub fn clear_search_history() -> Result<()> {
+    let mut history_lock = HISTORY.lock().unwrap();
+    let history = history_lock.as_mut().ok_or_else(|| anyhow!("Search history not initialized"))?;
+
+    history.entries.clear();
+    history.save()
+}
+
+
+/// Completes `prefix` with past queries, ranked by recency and frequency, followed by
+/// completions of its last word from the index terms, ranked by document frequency.
+/// Cheap enough to be called on every keystroke.
+pub fn suggest(prefix: String, limit: usize) -> Result<Vec<Suggestion>> {
+    let prefix: String = prefix.nfc().collect();
+    let prefix = prefix.trim_start();
+    if prefix.is_empty() || limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut suggestions = Vec::new();
+    let mut seen = HashSet::new();
+
+    {
+        let history_lock = HISTORY.lock().unwrap();
+        if let Some(history) = history_lock.as_ref() {
+            for entry in history.matching(prefix, now()).into_iter().take(limit) {
+                if seen.insert(entry.query.to_lowercase()) {
+                    suggestions.push(Suggestion {
+                        text: entry.query.clone(),
+                        source: SuggestionSource::History,
+                    });
+                }
+            }
+        }
+    }
+
+    // Only the word being typed is completed, the words before it are kept as they are.
+    let (head, last_word) = match prefix.rfind(char::is_whitespace) {
+        Some(position) => prefix.split_at(position + 1),
+        None => ("", prefix),
+    };
+    if last_word.is_empty() || suggestions.len() >= limit {
+        return Ok(suggestions);
+    }
+
+    for (term, _) in index_terms_with_prefix(&last_word.to_lowercase(), limit)? {
+        if suggestions.len() >= limit {
+            break;
+        }
+
+        let text = format!("{}{}", head, term);
+        if seen.insert(text.to_lowercase()) {
+            suggestions.push(Suggestion {
+                text,
+                source: SuggestionSource::Index,
+            });
+        }
+    }
+
+    Ok(suggestions)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("history-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn empty_history() -> SearchHistory {
+        SearchHistory {
+            path: PathBuf::from(HISTORY_FILE),
+            entries: Vec::new(),
+        }
+    }
+
+    fn queries(entries: Vec<&HistoryEntry>) -> Vec<&str> {
+        entries.into_iter().map(|entry| entry.query.as_str()).collect()
+    }
+
+    #[test]
+    fn recording_a_query_again_counts_it_once() {
+        let mut history = empty_history();
+        history.record("weather", 100);
+        history.record("weather", 200);
+
+        assert_eq!(history.entries.len(), 1);
+        assert_eq!(history.entries[0].count, 2);
+        assert_eq!(history.entries[0].last_used, 200);
+    }
+
+    #[test]
+    fn recent_queries_outrank_old_frequent_ones() {
+        let now = 100 * SECONDS_PER_DAY as i64;
+        let mut history = empty_history();
+        for _ in 0..5 {
+            history.record("weekly report", now - 30 * SECONDS_PER_DAY as i64);
+        }
+        history.record("weather", now);
+        history.record("Week plan", now - 1);
+        history.record("notes", now);
+
+        assert_eq!(queries(history.matching("WE", now)), vec!["weather", "Week plan", "weekly report"]);
+    }
+
+    #[test]
+    fn least_useful_entries_are_dropped_when_full() {
+        let mut history = empty_history();
+        for position in 0..MAX_HISTORY_ENTRIES as i64 {
+            history.record(&format!("query {}", position), position);
+        }
+        history.record("latest", MAX_HISTORY_ENTRIES as i64);
+
+        assert_eq!(history.entries.len(), MAX_HISTORY_ENTRIES);
+        assert!(history.entries.iter().any(|entry| entry.query == "latest"));
+        assert!(!history.entries.iter().any(|entry| entry.query == "query 0"));
+    }
+
+    #[test]
+    fn legacy_history_is_imported_in_order_and_saved_as_json() {
+        let dir = temp_dir("legacy");
+        std::fs::write(dir.join(LEGACY_HISTORY_FILE), "first\n\n  second  \nthird\n").unwrap();
+
+        let history = SearchHistory::load(&dir).unwrap();
+        let mut entries: Vec<&HistoryEntry> = history.entries.iter().collect();
+        entries.sort_by_key(|entry| entry.last_used);
+        assert_eq!(queries(entries), vec!["first", "second", "third"]);
+
+        history.save().unwrap();
+        let reloaded = SearchHistory::load(&dir).unwrap();
+        assert_eq!(reloaded.entries.len(), 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn suggestions_come_from_history_without_an_index() {
+        let dir = temp_dir("suggest");
+        init_search_history(dir.to_string_lossy().to_string()).unwrap();
+        record_search("cafe\u{301} menu".to_string()).unwrap();
+
+        let suggestions = suggest("Caf\u{e9}".to_string(), 5).unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].text, "caf\u{e9} menu");
+        assert_eq!(suggestions[0].source, SuggestionSource::History);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::api::crawler::{crawl_with, CrawlConfig, CrawlEvent};
+use crate::api::history::now;
+use crate::api::recovery::{clear_rebuild_pending, rebuild_pending};
+use crate::api::tantivy::{delete_documents_batch, delete_files, index_dir};
+
+
+pub(crate) const FILE_MANIFEST_FILE: &str = "file_manifest.json";
+
+
+/// What is known about an indexed source file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub size: u64,
+    /// Seconds since the Unix epoch.
+    pub modified: Option<i64>,
+    /// SHA-1 of the file content, in hexadecimal.
+    pub hash: String,
+    /// Ids of the documents the file was indexed as.
+    pub chunk_ids: Vec<String>,
+    /// Seconds since the Unix epoch.
+    pub indexed_at: i64,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    New,
+    Changed,
+}
+
+
+/// A file to index, see `record_indexed_files`.
+#[derive(Debug, Clone)]
+pub struct FileToIndex {
+    pub path: String,
+    pub size: u64,
+    pub modified: Option<i64>,
+    pub hash: String,
+    pub kind: ChangeKind,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct IndexedFile {
+    pub file: FileToIndex,
+    pub chunk_ids: Vec<String>,
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct ReconcileReport {
+    pub to_index: Vec<FileToIndex>,
+    /// Files that disappeared since they were indexed. Their chunks have been deleted.
+    pub removed: Vec<String>,
+    /// Ids of the chunks deleted, to delete their embeddings as well.
+    pub deleted_chunk_ids: Vec<String>,
+    pub unchanged: u64,
+    /// Files and folders that could not be read. Files indexed from them are kept.
+    pub errors: Vec<String>,
+}
+
+
+// Serialises the read-modify-write cycles of the manifest file.
+static MANIFEST_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+
+fn manifest_path() -> Result<PathBuf> {
+    Ok(index_dir()?.join(FILE_MANIFEST_FILE))
+}
+
+
+fn load_manifest(path: &Path) -> Result<HashMap<String, ManifestEntry>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let entries: Vec<ManifestEntry> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    Ok(entries.into_iter().map(|entry| (entry.path.clone(), entry)).collect())
+}
+
+
+fn save_manifest(path: &Path, manifest: &HashMap<String, ManifestEntry>) -> Result<()> {
+    let mut entries: Vec<&ManifestEntry> = manifest.values().collect();
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, serde_json::to_string(&entries)?)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+
+pub(crate) fn hash_file(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha1::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+
+/// Compares the files found by crawling `config` with the manifest. Files that were
+/// removed have their chunks deleted right away, new and changed files are returned to be
+/// indexed. A file whose size or modification time changed but not its content is only
+/// updated in the manifest.
+///
+/// After an index recovery every file is indexed again, and the chunks of the old index are
+/// reported as deleted.
+pub fn reconcile_files(config: CrawlConfig) -> Result<ReconcileReport> {
+    let _manifest_lock = MANIFEST_LOCK.lock().unwrap();
+    let index_dir = index_dir()?;
+    let manifest_path = index_dir.join(FILE_MANIFEST_FILE);
+    let mut manifest = load_manifest(&manifest_path)?;
+
+    let index_dir = index_dir.to_string_lossy().to_string();
+    let mut report = ReconcileReport::default();
+    if rebuild_pending(index_dir.clone()) {
+        // The chunks of the old index are gone. Files indexed again from now on are recorded
+        // as usual, so an interrupted rebuild picks up where it stopped.
+        report.deleted_chunk_ids = manifest.drain().flat_map(|(_, entry)| entry.chunk_ids).collect();
+        save_manifest(&manifest_path, &manifest)?;
+        clear_rebuild_pending(index_dir)?;
+    }
+
+    let mut manifest_changed = compare_files(&config, &mut manifest, &mut report)?;
+
+    if !report.removed.is_empty() {
+        // Chunks indexed without metadata are only known by their id.
+        let chunk_ids: Vec<String> = report
+            .removed
+            .iter()
+            .filter_map(|path| manifest.remove(path))
+            .flat_map(|entry| entry.chunk_ids)
+            .collect();
+        let deleted_ids = delete_files(&report.removed, &chunk_ids)?.deleted_ids;
+        let deleted: HashSet<&String> = deleted_ids.iter().collect();
+        let stale_ids: Vec<String> = chunk_ids.iter().filter(|id| !deleted.contains(id)).cloned().collect();
+        report.deleted_chunk_ids.extend(deleted_ids);
+        report.deleted_chunk_ids.extend(stale_ids);
+        manifest_changed = true;
+    }
+
+    if manifest_changed {
+        save_manifest(&manifest_path, &manifest)?;
+    }
+
+    Ok(report)
+}
+
+
+/// Crawls `config` and compares the files found with `manifest`, filling the files to
+/// index, the unchanged and removed files and the errors of `report`. Entries of files
+/// whose content did not change are updated in place. Returns whether `manifest` changed.
+fn compare_files(config: &CrawlConfig, manifest: &mut HashMap<String, ManifestEntry>, report: &mut ReconcileReport) -> Result<bool> {
+    let mut seen = HashSet::new();
+    let mut unreadable_dirs = Vec::new();
+    let mut manifest_changed = false;
+
+    crawl_with(config, |event| {
+        match event {
+            CrawlEvent::File { path, size, modified } => {
+                seen.insert(path.clone());
+                let entry = manifest.get_mut(&path);
+                if entry.as_ref().is_some_and(|entry| entry.size == size && entry.modified == modified) {
+                    report.unchanged += 1;
+                    return true;
+                }
+
+                let hash = match hash_file(Path::new(&path)) {
+                    Ok(hash) => hash,
+                    Err(error) => {
+                        report.errors.push(format!("{}: {}", path, error));
+                        return true;
+                    }
+                };
+                match entry {
+                    Some(entry) if entry.hash == hash => {
+                        entry.size = size;
+                        entry.modified = modified;
+                        manifest_changed = true;
+                        report.unchanged += 1;
+                    }
+                    Some(_) => report.to_index.push(FileToIndex { path, size, modified, hash, kind: ChangeKind::Changed }),
+                    None => report.to_index.push(FileToIndex { path, size, modified, hash, kind: ChangeKind::New }),
+                }
+            }
+            CrawlEvent::Error { path, message } => {
+                report.errors.push(format!("{}: {}", path, message));
+                unreadable_dirs.push(path);
+            }
+            CrawlEvent::Skipped { .. } => {}
+        }
+        true
+    })?;
+
+    report.removed = manifest
+        .keys()
+        .filter(|path| !seen.contains(*path))
+        .filter(|path| {
+            // A file that still exists in a folder that could not be read is most likely
+            // still there.
+            !(Path::new(path).exists()
+                && unreadable_dirs.iter().any(|dir| Path::new(path).starts_with(dir)))
+        })
+        .cloned()
+        .collect();
+    report.removed.sort();
+
+    Ok(manifest_changed)
+}
+
+
+/// Records files indexed after `reconcile_files`, once their chunks have been committed.
+/// Chunks a changed file was previously indexed as, and that are not part of `chunk_ids`
+/// anymore, are deleted. Returns the ids of those deleted chunks.
+pub fn record_indexed_files(files: Vec<IndexedFile>) -> Result<Vec<String>> {
+    let _manifest_lock = MANIFEST_LOCK.lock().unwrap();
+    let manifest_path = manifest_path()?;
+    let mut manifest = load_manifest(&manifest_path)?;
+
+    let indexed_at = now();
+    let mut stale_ids = Vec::new();
+    for IndexedFile { file, chunk_ids } in files {
+        if let Some(previous) = manifest.get(&file.path) {
+            let current: HashSet<&String> = chunk_ids.iter().collect();
+            stale_ids.extend(previous.chunk_ids.iter().filter(|id| !current.contains(id)).cloned());
+        }
+        manifest.insert(file.path.clone(), ManifestEntry {
+            path: file.path,
+            size: file.size,
+            modified: file.modified,
+            hash: file.hash,
+            chunk_ids,
+            indexed_at,
+        });
+    }
+
+    if !stale_ids.is_empty() {
+        delete_documents_batch(stale_ids.clone())?;
+    }
+    save_manifest(&manifest_path, &manifest)?;
+
+    Ok(stale_ids)
+}
+
+
+pub fn get_manifest_entry(path: String) -> Result<Option<ManifestEntry>> {
+    let _manifest_lock = MANIFEST_LOCK.lock().unwrap();
+    Ok(load_manifest(&manifest_path()?)?.remove(&path))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("manifest-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config(dir: &Path) -> CrawlConfig {
+        CrawlConfig { roots: vec![dir.to_string_lossy().to_string()], ..CrawlConfig::default() }
+    }
+
+    fn entry(path: &Path, hash: &str) -> ManifestEntry {
+        ManifestEntry {
+            path: path.to_string_lossy().to_string(),
+            size: 0,
+            modified: None,
+            hash: hash.to_string(),
+            chunk_ids: vec![],
+            indexed_at: 0,
+        }
+    }
+
+    #[test]
+    fn new_files_are_to_be_indexed() {
+        let dir = test_dir("new");
+        std::fs::write(dir.join("a.pdf"), "a").unwrap();
+        std::fs::write(dir.join("b.txt"), "b").unwrap();
+
+        let mut manifest = HashMap::new();
+        let mut report = ReconcileReport::default();
+        let manifest_changed = compare_files(&config(&dir), &mut manifest, &mut report).unwrap();
+
+        assert!(!manifest_changed);
+        assert_eq!(report.to_index.len(), 1);
+        assert_eq!(report.to_index[0].path, dir.join("a.pdf").to_string_lossy());
+        assert_eq!(report.to_index[0].kind, ChangeKind::New);
+        assert_eq!(report.to_index[0].hash, hash_file(&dir.join("a.pdf")).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn changed_files_are_told_apart_by_their_content() {
+        let dir = test_dir("changed");
+        let changed = dir.join("changed.pdf");
+        let touched = dir.join("touched.pdf");
+        std::fs::write(&changed, "new content").unwrap();
+        std::fs::write(&touched, "same content").unwrap();
+
+        let mut manifest = HashMap::new();
+        manifest.insert(changed.to_string_lossy().to_string(), entry(&changed, "old hash"));
+        manifest.insert(touched.to_string_lossy().to_string(), entry(&touched, &hash_file(&touched).unwrap()));
+        let mut report = ReconcileReport::default();
+        let manifest_changed = compare_files(&config(&dir), &mut manifest, &mut report).unwrap();
+
+        assert_eq!(report.to_index.len(), 1);
+        assert_eq!(report.to_index[0].path, changed.to_string_lossy());
+        assert_eq!(report.to_index[0].kind, ChangeKind::Changed);
+        // Only the size and modification time of the touched file are updated.
+        assert!(manifest_changed);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(manifest[&*touched.to_string_lossy()].size, "same content".len() as u64);
+        assert_eq!(manifest[&*changed.to_string_lossy()].hash, "old hash");
+
+        let mut report = ReconcileReport::default();
+        assert!(!compare_files(&config(&dir), &mut manifest, &mut report).unwrap());
+        assert_eq!(report.unchanged, 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn removed_files_are_reported() {
+        let dir = test_dir("removed");
+        let kept = dir.join("kept.pdf");
+        let removed = dir.join("removed.pdf");
+        std::fs::write(&kept, "kept").unwrap();
+
+        let mut manifest = HashMap::new();
+        manifest.insert(kept.to_string_lossy().to_string(), entry(&kept, &hash_file(&kept).unwrap()));
+        manifest.insert(removed.to_string_lossy().to_string(), entry(&removed, "hash"));
+        let mut report = ReconcileReport::default();
+        compare_files(&config(&dir), &mut manifest, &mut report).unwrap();
+
+        assert!(report.to_index.is_empty());
+        assert_eq!(report.removed, vec![removed.to_string_lossy().to_string()]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn manifest_round_trips_through_its_file() {
+        let dir = test_dir("save");
+        let path = dir.join(FILE_MANIFEST_FILE);
+        let mut manifest = HashMap::new();
+        manifest.insert("/a.pdf".to_string(), ManifestEntry { chunk_ids: vec!["a-0".to_string()], ..entry(Path::new("/a.pdf"), "hash") });
+
+        save_manifest(&path, &manifest).unwrap();
+        let loaded = load_manifest(&path).unwrap();
+
+        assert_eq!(loaded["/a.pdf"].chunk_ids, vec!["a-0"]);
+        assert!(load_manifest(&dir.join("missing.json")).unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,415 @@
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tantivy::schema::{Facet, Schema, Value};
+use tantivy::{Index, TantivyDocument, Term};
+
+use crate::api::manifest::FILE_MANIFEST_FILE;
+use crate::api::recovery::{quarantine, RecoveryReason, REBUILD_PENDING_FILE};
+use crate::api::tantivy::{build_schema, Document, DocumentMetadata, IndexFields, SCHEMA_VERSION};
+
+
+pub(crate) const SCHEMA_VERSION_FILE: &str = "schema_version";
+// Files the app keeps in the index directory, carried over when the index is replaced.
+const APP_FILES: &[&str] = &[FILE_MANIFEST_FILE, REBUILD_PENDING_FILE];
+// Number of documents copied between two progress updates.
+const PROGRESS_INTERVAL: u64 = 500;
+
+
+#[derive(Debug, Clone)]
+pub struct IndexStatus {
+    /// Schema version of the index on disk, `None` when there is no index yet.
+    pub version: Option<u32>,
+    pub current_version: u32,
+    pub needs_migration: bool,
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct MigrationProgress {
+    pub running: bool,
+    pub processed: u64,
+    pub total: u64,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub documents_migrated: u64,
+    /// The old index could not be converted. It was quarantined and replaced by an empty
+    /// one, so every source file has to be indexed again.
+    pub rebuild_required: bool,
+    /// Why the old index could not be converted.
+    pub error: Option<String>,
+}
+
+
+static PROGRESS: Lazy<Mutex<MigrationProgress>> = Lazy::new(|| Mutex::new(MigrationProgress::default()));
+
+
+fn set_progress(running: bool, processed: u64, total: u64) {
+    *PROGRESS.lock().unwrap() = MigrationProgress { running, processed, total };
+}
+
+
+pub(crate) fn sibling_dir(index_dir: &Path, suffix: &str) -> PathBuf {
+    let mut name = index_dir.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    index_dir.with_file_name(name)
+}
+
+
+pub(crate) fn write_schema_version(index_dir: &Path, version: u32) -> Result<()> {
+    std::fs::write(index_dir.join(SCHEMA_VERSION_FILE), version.to_string())?;
+    Ok(())
+}
+
+
+/// Reads the schema version of the index in `index_dir`. Indexes created before the
+/// version was stored are recognised by their fields: version 1 only had `id` and `text`.
+pub(crate) fn stored_schema_version(index_dir: &Path) -> Result<Option<u32>> {
+    if !index_dir.join("meta.json").exists() {
+        return Ok(None);
+    }
+
+    let version_path = index_dir.join(SCHEMA_VERSION_FILE);
+    if version_path.exists() {
+        let version = std::fs::read_to_string(&version_path)?;
+        let version = version.trim().parse().map_err(|_| anyhow!("Invalid schema version '{}'", version.trim()))?;
+        return Ok(Some(version));
+    }
+
+    let schema = Index::open_in_dir(index_dir)?.schema();
+    if IndexFields::resolve(&schema).is_ok() {
+        Ok(Some(SCHEMA_VERSION))
+    } else if schema.get_field("id").is_ok() && schema.get_field("text").is_ok() {
+        Ok(Some(1))
+    } else {
+        Ok(Some(0))
+    }
+}
+
+
+/// Puts back the index if a previous migration was interrupted between moving the old
+/// index away and moving the new one in place, and removes half-written copies.
+fn recover_interrupted_migration(index_dir: &Path) -> Result<()> {
+    let migrating_dir = sibling_dir(index_dir, ".migrating");
+    let previous_dir = sibling_dir(index_dir, ".previous");
+
+    if previous_dir.exists() {
+        if index_dir.join("meta.json").exists() {
+            std::fs::remove_dir_all(&previous_dir)?;
+        } else {
+            if index_dir.exists() {
+                std::fs::remove_dir_all(index_dir)?;
+            }
+            std::fs::rename(&previous_dir, index_dir)?;
+        }
+    }
+    if migrating_dir.exists() {
+        std::fs::remove_dir_all(&migrating_dir)?;
+    }
+
+    Ok(())
+}
+
+
+/// Copies the files the app keeps next to the index, such as the file manifest, from the
+/// index in `from_dir` to the one in `to_dir`.
+pub(crate) fn copy_app_files(from_dir: &Path, to_dir: &Path) -> Result<()> {
+    for name in APP_FILES {
+        let path = from_dir.join(name);
+        if path.exists() {
+            std::fs::copy(&path, to_dir.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+
+/// Replaces `dir` with `new_dir`. The old content is kept in a `.previous` sibling until the
+/// new one is in place, see `recover_interrupted_migration`.
+pub(crate) fn replace_dir(dir: &Path, new_dir: &Path) -> Result<()> {
+    let previous_dir = sibling_dir(dir, ".previous");
+    if previous_dir.exists() {
+        std::fs::remove_dir_all(&previous_dir)?;
+    }
+
+    if dir.exists() {
+        std::fs::rename(dir, &previous_dir)?;
+    }
+    std::fs::rename(new_dir, dir)?;
+    if previous_dir.exists() {
+        std::fs::remove_dir_all(&previous_dir)?;
+    }
+
+    Ok(())
+}
+
+
+/// Copies every live document of `old_index` into a new index with the current schema,
+/// passing stored paths through `map_path`. Metadata fields are carried over when the old
+/// schema has them.
+pub(crate) fn copy_documents(old_index: &Index, new_dir: &Path, map_path: impl Fn(&str) -> String) -> Result<u64> {
+    let old_schema = old_index.schema();
+    let old_field = |name: &str| old_schema.get_field(name).ok();
+    let (old_id, old_text) = match (old_field("id"), old_field("text")) {
+        (Some(id), Some(text)) => (id, text),
+        _ => return Err(anyhow!("The index has no 'id' or 'text' field")),
+    };
+    let (old_path, old_modified, old_language) = (old_field("path"), old_field("modified"), old_field("language"));
+    let (old_title, old_author, old_chapter) = (old_field("title"), old_field("author"), old_field("chapter"));
+    let (old_chapter_index, old_headings) = (old_field("chapter_index"), old_field("headings"));
+    let (old_chat, old_sender, old_timestamp) = (old_field("chat"), old_field("sender"), old_field("timestamp"));
+    let (old_sheet, old_row) = (old_field("sheet"), old_field("row"));
+    let (old_start_ms, old_end_ms) = (old_field("start_ms"), old_field("end_ms"));
+    let (old_subject, old_recipient) = (old_field("subject"), old_field("recipient"));
+
+    let schema: Schema = build_schema();
+    let fields = IndexFields::resolve(&schema)?;
+    let new_index = Index::create_in_dir(new_dir, schema)?;
+    let mut writer = new_index.writer(50_000_000)?;
+
+    let searcher = old_index.reader()?.searcher();
+    let total = searcher.num_docs();
+    let mut processed = 0;
+    set_progress(true, processed, total);
+
+    for segment_reader in searcher.segment_readers() {
+        let store_reader = segment_reader.get_store_reader(1)?;
+        for doc_id in segment_reader.doc_ids_alive() {
+            let old_doc: TantivyDocument = store_reader.get(doc_id)?;
+            let text_value = |field: Option<tantivy::schema::Field>| {
+                field
+                    .and_then(|field| old_doc.get_first(field))
+                    .and_then(|value| value.as_str())
+                    .map(str::to_string)
+            };
+            let text_values = |field: Option<tantivy::schema::Field>| -> Vec<String> {
+                field
+                    .map(|field| old_doc.get_all(field).filter_map(|value| value.as_str()).map(str::to_string).collect())
+                    .unwrap_or_default()
+            };
+
+            let u64_value = |field: Option<tantivy::schema::Field>| {
+                field.and_then(|field| old_doc.get_first(field)).and_then(|value| value.as_u64())
+            };
+
+            let doc = Document {
+                id: text_value(Some(old_id)).unwrap_or_default(),
+                text: text_value(Some(old_text)).unwrap_or_default(),
+            };
+            let metadata = DocumentMetadata {
+                path: text_value(old_path).map(|path| map_path(&path)).unwrap_or_default(),
+                modified: old_modified
+                    .and_then(|field| old_doc.get_first(field))
+                    .and_then(|value| value.as_datetime())
+                    .map(|modified| modified.into_timestamp_secs()),
+                language: old_language
+                    .and_then(|field| old_doc.get_first(field))
+                    .and_then(|value| value.as_facet())
+                    .and_then(|encoded| Facet::from_encoded(encoded.as_bytes().to_vec()).ok())
+                    .and_then(|facet| facet.to_path().last().map(|language| language.to_string())),
+                title: text_value(old_title),
+                author: text_value(old_author),
+                chapter: text_value(old_chapter),
+                chapter_index: old_chapter_index
+                    .and_then(|field| old_doc.get_first(field))
+                    .and_then(|value| value.as_u64())
+                    .map(|chapter_index| chapter_index as u32),
+                headings: text_values(old_headings),
+                chat: text_value(old_chat),
+                senders: text_values(old_sender),
+                timestamp: old_timestamp
+                    .and_then(|field| old_doc.get_first(field))
+                    .and_then(|value| value.as_datetime())
+                    .map(|timestamp| timestamp.into_timestamp_secs()),
+                sheet: text_value(old_sheet),
+                row: old_row
+                    .and_then(|field| old_doc.get_first(field))
+                    .and_then(|value| value.as_u64())
+                    .map(|row| row as u32),
+                start_ms: u64_value(old_start_ms),
+                end_ms: u64_value(old_end_ms),
+                subject: text_value(old_subject),
+                recipients: text_values(old_recipient),
+            };
+
+            writer.delete_term(Term::from_field_text(fields.id, &doc.id));
+            writer.add_document(fields.build_document(&doc, &metadata))?;
+
+            processed += 1;
+            if processed % PROGRESS_INTERVAL == 0 {
+                set_progress(true, processed, total);
+            }
+        }
+    }
+
+    writer.commit()?;
+    writer.wait_merging_threads()?;
+    write_schema_version(new_dir, SCHEMA_VERSION)?;
+
+    Ok(processed)
+}
+
+
+/// Brings the index in `index_dir` to the current schema version. Documents are copied into
+/// a new index next to the old one, which is swapped in once complete, so an interruption
+/// leaves the old index usable. Fails, leaving the index untouched, when it was written by
+/// a newer version of the app or cannot be converted.
+pub(crate) fn upgrade_index(index_dir: &Path) -> Result<MigrationReport> {
+    recover_interrupted_migration(index_dir)?;
+
+    let from_version = stored_schema_version(index_dir)?.unwrap_or(SCHEMA_VERSION);
+    let mut report = MigrationReport {
+        from_version,
+        to_version: SCHEMA_VERSION,
+        documents_migrated: 0,
+        rebuild_required: false,
+        error: None,
+    };
+    if from_version > SCHEMA_VERSION {
+        return Err(anyhow!("Schema version {} was written by a newer version of the app than {}", from_version, SCHEMA_VERSION));
+    }
+    if from_version == SCHEMA_VERSION {
+        if index_dir.join("meta.json").exists() && !index_dir.join(SCHEMA_VERSION_FILE).exists() {
+            write_schema_version(index_dir, SCHEMA_VERSION)?;
+        }
+        return Ok(report);
+    }
+
+    let migrating_dir = sibling_dir(index_dir, ".migrating");
+    std::fs::create_dir_all(&migrating_dir)?;
+
+    // Versions too old to hold documents cannot be copied.
+    let copied = Index::open_in_dir(index_dir)
+        .map_err(anyhow::Error::from)
+        .and_then(|old_index| copy_documents(&old_index, &migrating_dir, str::to_string));
+
+    match copied {
+        Ok(documents_migrated) => report.documents_migrated = documents_migrated,
+        Err(error) => {
+            std::fs::remove_dir_all(&migrating_dir)?;
+            return Err(anyhow!("Schema version {} cannot be migrated to {}: {}", from_version, SCHEMA_VERSION, error));
+        }
+    }
+
+    copy_app_files(index_dir, &migrating_dir)?;
+    replace_dir(index_dir, &migrating_dir)?;
+    Ok(report)
+}
+
+
+/// Same as `upgrade_index`, but an older index that cannot be converted is quarantined,
+/// replaced by an empty index and reported with `rebuild_required`.
+pub(crate) fn migrate(index_dir: &Path) -> Result<MigrationReport> {
+    let error = match upgrade_index(index_dir) {
+        Ok(report) => return Ok(report),
+        Err(error) => error,
+    };
+    let from_version = match stored_schema_version(index_dir) {
+        Ok(Some(version)) if version < SCHEMA_VERSION => version,
+        _ => return Err(error),
+    };
+
+    quarantine(index_dir, RecoveryReason::IncompatibleSchema, vec![error.to_string()])?;
+    Ok(MigrationReport {
+        from_version,
+        to_version: SCHEMA_VERSION,
+        documents_migrated: 0,
+        rebuild_required: true,
+        error: Some(error.to_string()),
+    })
+}
+
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn index_status(dir_path: String) -> Result<IndexStatus> {
+    let version = stored_schema_version(Path::new(&dir_path))?;
+
+    Ok(IndexStatus {
+        version,
+        current_version: SCHEMA_VERSION,
+        needs_migration: version.is_some_and(|version| version != SCHEMA_VERSION),
+    })
+}
+
+
+/// Migrates the index in `dir_path` to the current schema. Must be called before
+/// `init_tantivy`, which otherwise migrates without reporting progress. Poll
+/// `migration_progress` to follow it.
+pub fn migrate_index(dir_path: String) -> Result<MigrationReport> {
+    let result = migrate(Path::new(&dir_path));
+    set_progress(false, 0, 0);
+    result
+}
+
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn migration_progress() -> MigrationProgress {
+    PROGRESS.lock().unwrap().clone()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tantivy::schema::{STORED, TEXT};
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("migration-{}-{}", name, std::process::id()));
+        for dir in [dir.clone(), sibling_dir(&dir, ".corrupt")] {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn newer_index_is_left_untouched() {
+        let dir = test_dir("newer");
+        Index::create_in_dir(&dir, build_schema()).unwrap();
+        write_schema_version(&dir, SCHEMA_VERSION + 1).unwrap();
+
+        assert!(migrate(&dir).is_err());
+        assert_eq!(stored_schema_version(&dir).unwrap(), Some(SCHEMA_VERSION + 1));
+        assert!(dir.join("meta.json").exists());
+        assert!(!sibling_dir(&dir, ".corrupt").exists());
+    }
+
+    #[test]
+    fn migration_keeps_the_file_manifest() {
+        let dir = test_dir("manifest");
+        let mut schema_builder = Schema::builder();
+        schema_builder.add_text_field("id", TEXT | STORED);
+        schema_builder.add_text_field("text", TEXT | STORED);
+        Index::create_in_dir(&dir, schema_builder.build()).unwrap();
+        std::fs::write(dir.join(FILE_MANIFEST_FILE), "[]").unwrap();
+
+        let report = migrate(&dir).unwrap();
+
+        assert_eq!(report.from_version, 1);
+        assert!(!report.rebuild_required);
+        assert!(dir.join(FILE_MANIFEST_FILE).exists());
+    }
+
+    #[test]
+    fn unconvertible_index_is_quarantined() {
+        let dir = test_dir("unconvertible");
+        let mut schema_builder = Schema::builder();
+        schema_builder.add_text_field("body", TEXT | STORED);
+        Index::create_in_dir(&dir, schema_builder.build()).unwrap();
+        std::fs::write(dir.join(FILE_MANIFEST_FILE), "[]").unwrap();
+
+        let report = migrate(&dir).unwrap();
+
+        assert!(report.rebuild_required);
+        assert!(report.error.unwrap().contains("no 'id' or 'text' field"));
+        assert!(sibling_dir(&dir, ".corrupt").join("meta.json").exists());
+        assert_eq!(stored_schema_version(&dir).unwrap(), Some(SCHEMA_VERSION));
+        assert!(dir.join(FILE_MANIFEST_FILE).exists());
+    }
+}
@@ -1,3 +1,22 @@
 pub mod simple;
 pub mod acho;
 pub mod tantivy;
+pub mod history;
+pub mod query;
+pub mod migration;
+pub mod snapshot;
+pub mod recovery;
+pub mod crawler;
+pub mod manifest;
+pub mod pdf;
+pub mod office;
+pub mod epub;
+pub mod text;
+pub mod chat;
+pub mod sheet;
+pub mod ocr;
+pub mod audio;
+pub mod archive;
+pub mod email;
+pub mod extract;
+pub mod date;
@@ -0,0 +1,378 @@
+use anyhow::{anyhow, Result};
+use image::imageops::{self, FilterType};
+use image::{ImageReader, Limits, RgbImage};
+use ndarray::Array4;
+use once_cell::sync::Lazy;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::api::extract::{chunk_id, file_metadata, split_paragraphs, ExtractedFile, CHUNK_CHARS};
+use crate::api::tantivy::{Document, DocumentWithMetadata};
+
+
+// Longest side of the image given to the detection model; larger images are scaled down.
+const DETECTION_MAX_SIDE: u32 = 960;
+// Probability above which a pixel of the detection map is taken for text.
+const DETECTION_THRESHOLD: f32 = 0.3;
+// Mean probability below which a detected region is dropped.
+const BOX_THRESHOLD: f32 = 0.6;
+// How much detected regions are grown, as the detection model outputs their core only.
+const UNCLIP_RATIO: f32 = 1.5;
+// Height of the line images given to the recognition model, and the widest they can be.
+const RECOGNITION_HEIGHT: u32 = 48;
+const RECOGNITION_MAX_WIDTH: u32 = 1920;
+// Mean confidence below which a recognised line is dropped.
+const LINE_THRESHOLD: f32 = 0.5;
+// Bound on the pixels of images to recognise, against images that would not fit in memory.
+pub(crate) const MAX_OCR_PIXELS: u64 = 40_000_000;
+// Normalisation of the detection model input. Paddle models are trained on BGR images.
+const DETECTION_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+const DETECTION_STD: [f32; 3] = [0.229, 0.224, 0.225];
+
+
+struct OcrModels {
+    detection: Session,
+    recognition: Session,
+    /// Characters of the recognition model, by class index minus one: class 0 is the CTC
+    /// blank.
+    characters: Vec<String>,
+}
+
+
+static OCR: Lazy<Mutex<Option<OcrModels>>> = Lazy::new(|| Mutex::new(None));
+
+
+/// A region of text found by the detection model, in pixels of the original image.
+#[derive(Debug, Clone, Copy)]
+struct TextBox {
+    left: u32,
+    top: u32,
+    right: u32,
+    bottom: u32,
+}
+
+
+fn load_session(model_path: &str) -> Result<Session> {
+    Session::builder()?
+        .with_optimization_level(GraphOptimizationLevel::Level1)?
+        .with_intra_threads(4)?
+        .commit_from_file(model_path)
+        .map_err(|error| anyhow!("Cannot load the OCR model {}: {}", model_path, error))
+}
+
+
+/// Loads PaddleOCR detection and recognition models exported to ONNX, and the character
+/// dictionary of the recognition model, one character per line. Until this is called,
+/// pages and images without a text layer are not indexed.
+pub fn init_ocr(detection_model_path: String, recognition_model_path: String, dictionary_path: String) -> Result<()> {
+    let dictionary = std::fs::read_to_string(&dictionary_path)
+        .map_err(|error| anyhow!("Cannot read {}: {}", dictionary_path, error))?;
+    let mut characters: Vec<String> = dictionary.lines().map(|line| line.trim_end_matches('\r').to_string()).collect();
+    // The models recognise spaces as an extra class after the dictionary.
+    characters.push(" ".to_string());
+
+    let models = OcrModels {
+        detection: load_session(&detection_model_path)?,
+        recognition: load_session(&recognition_model_path)?,
+        characters,
+    };
+    *OCR.lock().unwrap() = Some(models);
+
+    Ok(())
+}
+
+
+/// Unloads the OCR models.
+#[flutter_rust_bridge::frb(sync)]
+pub fn close_ocr() {
+    OCR.lock().unwrap().take();
+}
+
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_ocr_enabled() -> bool {
+    OCR.lock().unwrap().is_some()
+}
+
+
+/// Scales `image` so that its sides are multiples of 32 and the longest is at most
+/// `DETECTION_MAX_SIDE`, as the detection model requires.
+fn detection_input(image: &RgbImage) -> Array4<f32> {
+    let (width, height) = image.dimensions();
+    let scale = (DETECTION_MAX_SIDE as f32 / width.max(height) as f32).min(1.0);
+    let round = |side: u32| (((side as f32 * scale) / 32.0).round() as u32).max(1) * 32;
+    let resized = imageops::resize(image, round(width), round(height), FilterType::Triangle);
+
+    let (width, height) = resized.dimensions();
+    let mut input = Array4::zeros((1, 3, height as usize, width as usize));
+    for (x, y, pixel) in resized.enumerate_pixels() {
+        for channel in 0..3 {
+            let value = pixel[2 - channel] as f32 / 255.0;
+            input[[0, channel, y as usize, x as usize]] = (value - DETECTION_MEAN[channel]) / DETECTION_STD[channel];
+        }
+    }
+    input
+}
+
+
+/// Finds the text regions of a detection probability map of `map_width` by `map_height`
+/// pixels, as the bounding boxes of the connected areas above `DETECTION_THRESHOLD`,
+/// grown by `UNCLIP_RATIO` and scaled to an image of `width` by `height` pixels.
+fn find_boxes(map: &[f32], map_width: usize, map_height: usize, width: u32, height: u32) -> Vec<TextBox> {
+    let mut visited = vec![false; map.len()];
+    let mut boxes = Vec::new();
+    let mut stack = Vec::new();
+    let (scale_x, scale_y) = (width as f32 / map_width as f32, height as f32 / map_height as f32);
+
+    for start in 0..map.len() {
+        if visited[start] || map[start] <= DETECTION_THRESHOLD {
+            continue;
+        }
+        visited[start] = true;
+        stack.push(start);
+        let (mut left, mut top, mut right, mut bottom) = (usize::MAX, usize::MAX, 0, 0);
+        let (mut sum, mut count) = (0.0, 0);
+
+        while let Some(index) = stack.pop() {
+            let (x, y) = (index % map_width, index / map_width);
+            (left, top, right, bottom) = (left.min(x), top.min(y), right.max(x), bottom.max(y));
+            sum += map[index];
+            count += 1;
+
+            let neighbours = [
+                (x > 0).then(|| index - 1),
+                (x + 1 < map_width).then(|| index + 1),
+                (y > 0).then(|| index - map_width),
+                (y + 1 < map_height).then(|| index + map_width),
+            ];
+            for neighbour in neighbours.into_iter().flatten() {
+                if !visited[neighbour] && map[neighbour] > DETECTION_THRESHOLD {
+                    visited[neighbour] = true;
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        let (box_width, box_height) = ((right - left + 1) as f32, (bottom - top + 1) as f32);
+        if box_width.min(box_height) < 3.0 || sum / (count as f32) < BOX_THRESHOLD {
+            continue;
+        }
+        let grow = box_width * box_height * UNCLIP_RATIO / (2.0 * (box_width + box_height));
+        let scale = |value: f32, scale: f32, limit: u32| ((value * scale).max(0.0) as u32).min(limit);
+        boxes.push(TextBox {
+            left: scale(left as f32 - grow, scale_x, width - 1),
+            top: scale(top as f32 - grow, scale_y, height - 1),
+            right: scale(right as f32 + 1.0 + grow, scale_x, width),
+            bottom: scale(bottom as f32 + 1.0 + grow, scale_y, height),
+        });
+    }
+
+    boxes
+}
+
+
+/// Scales a line of text to `RECOGNITION_HEIGHT` pixels high, keeping its proportions.
+fn recognition_input(line: &RgbImage) -> Array4<f32> {
+    let (width, height) = line.dimensions();
+    let resized_width = ((RECOGNITION_HEIGHT as f32 * width as f32 / height as f32).ceil() as u32).clamp(8, RECOGNITION_MAX_WIDTH);
+    let resized = imageops::resize(line, resized_width, RECOGNITION_HEIGHT, FilterType::Triangle);
+
+    let mut input = Array4::zeros((1, 3, RECOGNITION_HEIGHT as usize, resized_width as usize));
+    for (x, y, pixel) in resized.enumerate_pixels() {
+        for channel in 0..3 {
+            input[[0, channel, y as usize, x as usize]] = pixel[2 - channel] as f32 / 127.5 - 1.0;
+        }
+    }
+    input
+}
+
+
+/// Reads the most likely text out of recognition model probabilities, `classes` per time
+/// step: the best class of each step, without repeats and CTC blanks. Returns the text and
+/// its mean confidence.
+fn ctc_decode(probabilities: &[f32], classes: usize, characters: &[String]) -> (String, f32) {
+    let mut text = String::new();
+    let (mut confidence, mut count) = (0.0, 0);
+    let mut previous = 0;
+
+    for step in probabilities.chunks_exact(classes) {
+        let (class, probability) = step
+            .iter()
+            .enumerate()
+            .fold((0, f32::MIN), |best, (class, &probability)| if probability > best.1 { (class, probability) } else { best });
+        if class != 0 && class != previous {
+            if let Some(character) = characters.get(class - 1) {
+                text.push_str(character);
+                confidence += probability;
+                count += 1;
+            }
+        }
+        previous = class;
+    }
+
+    let confidence = if count == 0 { 0.0 } else { confidence / count as f32 };
+    (text.trim().to_string(), confidence)
+}
+
+
+/// Puts `boxes` in reading order and groups them into lines: a box whose vertical middle is
+/// within the first box of the current line belongs to that line.
+fn reading_order(mut boxes: Vec<(TextBox, String)>) -> String {
+    boxes.sort_by_key(|(text_box, _)| (text_box.top, text_box.left));
+    let mut lines: Vec<(TextBox, Vec<(TextBox, String)>)> = Vec::new();
+    for (text_box, text) in boxes {
+        let middle = (text_box.top + text_box.bottom) / 2;
+        match lines.last_mut() {
+            Some((first, line)) if (first.top..first.bottom).contains(&middle) => line.push((text_box, text)),
+            _ => lines.push((text_box, vec![(text_box, text)])),
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|(_, mut line)| {
+            line.sort_by_key(|(text_box, _)| text_box.left);
+            line.into_iter().map(|(_, text)| text).collect::<Vec<_>>().join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+
+fn recognize_with(models: &mut OcrModels, image: &RgbImage) -> Result<String> {
+    let (width, height) = image.dimensions();
+    if width < 8 || height < 8 {
+        return Ok(String::new());
+    }
+
+    let input = ort::value::Tensor::from_array(detection_input(image))?;
+    let outputs = models.detection.run(ort::inputs![input])?;
+    let map = outputs[0].try_extract_array::<f32>()?;
+    let (map_height, map_width) = match map.shape() {
+        [.., map_height, map_width] => (*map_height, *map_width),
+        shape => return Err(anyhow!("Unexpected detection output shape {:?}", shape)),
+    };
+    let map: Vec<f32> = map.iter().copied().collect();
+    drop(outputs);
+    let boxes = find_boxes(&map, map_width, map_height, width, height);
+
+    let mut lines = Vec::new();
+    for text_box in boxes {
+        let (box_width, box_height) = (text_box.right - text_box.left, text_box.bottom - text_box.top);
+        if box_width < 2 || box_height < 2 {
+            continue;
+        }
+        let line = imageops::crop_imm(image, text_box.left, text_box.top, box_width, box_height).to_image();
+        let input = ort::value::Tensor::from_array(recognition_input(&line))?;
+        let outputs = models.recognition.run(ort::inputs![input])?;
+        let probabilities = outputs[0].try_extract_array::<f32>()?;
+        // `ctc_decode` cannot step through a dimension of 0.
+        let classes = probabilities.shape().last().copied().filter(|&classes| classes > 0).ok_or_else(|| anyhow!("Empty recognition output"))?;
+        let probabilities: Vec<f32> = probabilities.iter().copied().collect();
+
+        let (text, confidence) = ctc_decode(&probabilities, classes, &models.characters);
+        if !text.is_empty() && confidence >= LINE_THRESHOLD {
+            lines.push((text_box, text));
+        }
+    }
+
+    Ok(reading_order(lines))
+}
+
+
+/// Recognises the text of `image`, `None` when no OCR models are loaded.
+pub(crate) fn recognize(image: &RgbImage) -> Result<Option<String>> {
+    let mut ocr_lock = OCR.lock().unwrap();
+    match ocr_lock.as_mut() {
+        Some(models) => Ok(Some(recognize_with(models, image)?)),
+        None => Ok(None),
+    }
+}
+
+
+fn load_image(path: &Path) -> Result<RgbImage> {
+    let mut reader = ImageReader::open(path)
+        .map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?
+        .with_guessed_format()?;
+    let mut limits = Limits::default();
+    limits.max_alloc = Some(MAX_OCR_PIXELS * 4);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|error| anyhow!("Cannot decode {}: {}", path.display(), error))?;
+    Ok(image.to_rgb8())
+}
+
+
+/// Recognises the text of the image file at `path`, e.g. a photo of a document.
+pub fn recognize_image(path: String) -> Result<String> {
+    let image = load_image(Path::new(&path))?;
+    recognize(&image)?.ok_or_else(|| anyhow!("OCR not initialized"))
+}
+
+
+/// Reads the text of a photo or scan with OCR, into documents of a few paragraphs each.
+pub(crate) fn extract_image(path: &Path) -> Result<ExtractedFile> {
+    let image = load_image(path)?;
+    let text = recognize(&image)?.ok_or_else(|| anyhow!("OCR not initialized"))?;
+
+    let metadata = file_metadata(path, &None, &None);
+    let documents = split_paragraphs(&text, CHUNK_CHARS)
+        .into_iter()
+        .enumerate()
+        .map(|(index, text)| DocumentWithMetadata {
+            doc: Document { id: chunk_id(path, index), text },
+            metadata: metadata.clone(),
+        })
+        .collect();
+
+    Ok(ExtractedFile { path: metadata.path, title: None, author: None, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn characters() -> Vec<String> {
+        ["a", "b", "é"].iter().map(|character| character.to_string()).collect()
+    }
+
+    #[test]
+    fn decodes_without_repeats_and_blanks() {
+        // Classes: blank, "a", "b", "é".
+        let probabilities = [
+            0.1, 0.7, 0.1, 0.1, // a
+            0.1, 0.7, 0.1, 0.1, // a, repeated
+            0.9, 0.0, 0.1, 0.0, // blank
+            0.1, 0.5, 0.2, 0.2, // a, after a blank
+            0.0, 0.1, 0.1, 0.8, // é
+        ];
+
+        let (text, confidence) = ctc_decode(&probabilities, 4, &characters());
+
+        assert_eq!(text, "aaé");
+        assert!((confidence - (0.7 + 0.5 + 0.8) / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn decodes_blank_output_to_nothing() {
+        let (text, confidence) = ctc_decode(&[0.9, 0.1, 0.0, 0.0], 4, &characters());
+
+        assert_eq!(text, "");
+        assert_eq!(confidence, 0.0);
+    }
+
+    #[test]
+    fn orders_boxes_in_lines() {
+        let text_box = |left, top| TextBox { left, top, right: left + 50, bottom: top + 20 };
+        let boxes = vec![
+            (text_box(60, 42), "world".to_string()),
+            (text_box(0, 0), "Hello".to_string()),
+            (text_box(0, 40), "Second".to_string()),
+            (text_box(60, 4), "there".to_string()),
+        ];
+
+        assert_eq!(reading_order(boxes), "Hello there\nSecond world");
+    }
+}
@@ -0,0 +1,210 @@
+use anyhow::{anyhow, Result};
+use quick_xml::escape::{resolve_html5_entity, unescape};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+use crate::api::extract::ExtractedText;
+
+
+// Bound on the uncompressed size of a single XML part, against zip bombs.
+const MAX_PART_SIZE: u64 = 64 * 1024 * 1024;
+
+
+pub(crate) fn open_package(path: &Path) -> Result<ZipArchive<File>> {
+    let file = File::open(path).map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?;
+    ZipArchive::new(file).map_err(|error| anyhow!("{} is not a valid document package: {}", path.display(), error))
+}
+
+
+/// Reads the part `name` of a zip package as text, `None` when the package has no such part.
+pub(crate) fn read_part(package: &mut ZipArchive<File>, name: &str) -> Result<Option<String>> {
+    let part = match package.by_name(name) {
+        Ok(part) => part,
+        Err(ZipError::FileNotFound) => return Ok(None),
+        Err(error) => return Err(anyhow!("Cannot read {}: {}", name, error)),
+    };
+
+    let mut content = Vec::new();
+    part.take(MAX_PART_SIZE + 1).read_to_end(&mut content)?;
+    if content.len() as u64 > MAX_PART_SIZE {
+        return Err(anyhow!("{} is larger than {} bytes", name, MAX_PART_SIZE));
+    }
+    Ok(Some(String::from_utf8(content).map_err(|_| anyhow!("{} is not valid UTF-8", name))?))
+}
+
+
+/// The text carried by a text, CDATA or entity reference event. Named references are
+/// resolved with the HTML entities, which XHTML content commonly uses.
+pub(crate) fn event_text<'a>(event: &'a Event) -> Option<Cow<'a, str>> {
+    match event {
+        Event::Text(text) => Some(text.xml10_content()),
+        Event::CData(data) => Some(data.xml10_content()),
+        Event::GeneralRef(reference) => match reference.resolve_char_ref() {
+            Ok(Some(character)) => Some(Cow::Owned(character.to_string())),
+            _ => resolve_html5_entity(&reference.xml10_content()).map(Cow::Borrowed),
+        },
+        _ => None,
+    }
+}
+
+
+/// Value of the attribute `name` of `element`, with references resolved.
+pub(crate) fn attribute(element: &BytesStart, name: &str) -> Option<String> {
+    let attribute = element.try_get_attribute(name).ok()??;
+    Some(unescape(&attribute.value).map_or_else(|_| attribute.value.to_string(), |value| value.to_string()))
+}
+
+
+/// Reads document properties such as `docProps/core.xml` or `meta.xml`, keyed by the
+/// local name of their element, e.g. "title" or "creator".
+pub(crate) fn read_properties(xml: &str) -> Result<HashMap<String, String>> {
+    let mut reader = Reader::from_str(xml);
+    let mut properties = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(start) => {
+                current = Some((start.local_name().as_ref().to_string(), String::new()));
+            }
+            Event::End(_) => {
+                if let Some((name, value)) = current.take() {
+                    let value = value.trim();
+                    if !value.is_empty() {
+                        properties.entry(name).or_insert_with(|| value.to_string());
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {
+                if let (Some((_, value)), Some(text)) = (current.as_mut(), event_text(&event)) {
+                    value.push_str(&text);
+                }
+            }
+        }
+    }
+
+    Ok(properties)
+}
+
+
+/// Text of `word/document.xml`: runs of `w:t`, with tabs and line breaks, one line per
+/// paragraph. Deleted revisions and field codes are left out.
+fn docx_body_text(xml: &str) -> Result<String> {
+    let mut reader = Reader::from_str(xml);
+    let mut text = String::new();
+    let mut in_text = false;
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(start) => in_text = start.local_name().as_ref() == "t",
+            Event::Empty(empty) => match empty.local_name().as_ref() {
+                "tab" => text.push('\t'),
+                "br" | "cr" => text.push('\n'),
+                _ => {}
+            },
+            Event::End(end) => {
+                in_text = false;
+                if end.local_name().as_ref() == "p" {
+                    text.push('\n');
+                }
+            }
+            Event::Eof => break,
+            _ => {
+                if let (true, Some(content)) = (in_text, event_text(&event)) {
+                    text.push_str(&content);
+                }
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+
+/// Text of the `office:text` body of an ODF `content.xml`, one line per paragraph and
+/// heading.
+fn odt_body_text(xml: &str) -> Result<String> {
+    let mut reader = Reader::from_str(xml);
+    let mut text = String::new();
+    let mut paragraph_depth = 0;
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(start) if matches!(start.local_name().as_ref(), "p" | "h") => paragraph_depth += 1,
+            Event::End(end) if matches!(end.local_name().as_ref(), "p" | "h") => {
+                paragraph_depth -= 1;
+                text.push('\n');
+            }
+            Event::Empty(empty) if paragraph_depth > 0 => match empty.local_name().as_ref() {
+                "s" => {
+                    let count = empty
+                        .try_get_attribute("text:c")?
+                        .and_then(|count| count.value.trim().parse::<usize>().ok())
+                        .unwrap_or(1);
+                    text.extend(std::iter::repeat_n(' ', count.min(1000)));
+                }
+                "tab" => text.push('\t'),
+                "line-break" => text.push('\n'),
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {
+                if let (true, Some(content)) = (paragraph_depth > 0, event_text(&event)) {
+                    text.push_str(&content);
+                }
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+
+pub(crate) fn extract_docx(path: &Path) -> Result<ExtractedText> {
+    let mut package = open_package(path)?;
+    let body = read_part(&mut package, "word/document.xml")?
+        .ok_or_else(|| anyhow!("{} has no word/document.xml", path.display()))?;
+    let text = docx_body_text(&body).map_err(|error| anyhow!("Invalid word/document.xml: {}", error))?;
+
+    // Broken properties should not prevent indexing the text.
+    let mut properties = read_part(&mut package, "docProps/core.xml")
+        .ok()
+        .flatten()
+        .and_then(|xml| read_properties(&xml).ok())
+        .unwrap_or_default();
+
+    Ok(ExtractedText { title: properties.remove("title"), author: properties.remove("creator"), text })
+}
+
+
+pub(crate) fn extract_odt(path: &Path) -> Result<ExtractedText> {
+    let mut package = open_package(path)?;
+    let manifest = read_part(&mut package, "META-INF/manifest.xml")?.unwrap_or_default();
+    if manifest.contains("encryption-data") {
+        return Err(anyhow!("The document {} is password protected", path.display()));
+    }
+    let content = read_part(&mut package, "content.xml")?
+        .ok_or_else(|| anyhow!("{} has no content.xml", path.display()))?;
+    let text = odt_body_text(&content).map_err(|error| anyhow!("Invalid content.xml: {}", error))?;
+
+    let mut properties = read_part(&mut package, "meta.xml")
+        .ok()
+        .flatten()
+        .and_then(|xml| read_properties(&xml).ok())
+        .unwrap_or_default();
+    // `dc:creator` is whoever saved the file last.
+    let author = properties.remove("initial-creator").or_else(|| properties.remove("creator"));
+
+    Ok(ExtractedText { title: properties.remove("title"), author, text })
+}
@@ -0,0 +1,275 @@
+use anyhow::{anyhow, Result};
+use image::{ImageFormat, ImageReader, Rgb, RgbImage};
+use lopdf::xobject::PdfImage;
+use lopdf::{Dictionary, LoadOptions, Object, ObjectId};
+use std::io::Cursor;
+use std::path::Path;
+
+use crate::api::date::days_from_civil;
+use crate::api::ocr::{is_ocr_enabled, recognize, MAX_OCR_PIXELS};
+use crate::frb_generated::StreamSink;
+
+
+// Bound on what a single stream or page may decompress to, so that a small crafted file
+// cannot exhaust the memory of the phone.
+const MAX_DECOMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
+
+#[derive(Debug, Clone, Default)]
+pub struct PdfInfo {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub creation_date: Option<i64>,
+    pub page_count: u32,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct PdfPage {
+    /// Starts at 1.
+    pub page_number: u32,
+    /// Text recognised with OCR when the page has no text layer and OCR is enabled.
+    pub text: String,
+    /// Why the text of this page could not be extracted, e.g. an unsupported font encoding.
+    /// The other pages are still extracted.
+    pub error: Option<String>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct PdfText {
+    pub info: PdfInfo,
+    pub pages: Vec<PdfPage>,
+}
+
+
+fn load_pdf(path: &Path) -> Result<lopdf::Document> {
+    // lopdf does not say what went wrong with the file itself.
+    std::fs::metadata(path).map_err(|error| anyhow!("Cannot read PDF {}: {}", path.display(), error))?;
+    let options = LoadOptions { max_decompressed_size: Some(MAX_DECOMPRESSED_SIZE), ..LoadOptions::default() };
+    let document = lopdf::Document::load_with_options(path, options)
+        .map_err(|error| anyhow!("Cannot read PDF {}: {}", path.display(), error))?;
+
+    // Files encrypted with an empty user password are decrypted while loading, only those
+    // that need a password keep their encryption dictionary.
+    if document.trailer.has(b"Encrypt") {
+        return Err(anyhow!("The PDF {} is password protected", path.display()));
+    }
+    Ok(document)
+}
+
+
+fn info_dictionary(document: &lopdf::Document) -> Option<&Dictionary> {
+    match document.trailer.get(b"Info").ok()? {
+        Object::Reference(id) => document.get_dictionary(*id).ok(),
+        Object::Dictionary(dictionary) => Some(dictionary),
+        _ => None,
+    }
+}
+
+
+fn info_text(info: &Dictionary, key: &[u8]) -> Option<String> {
+    let value = lopdf::decode_text_string(info.get(key).ok()?).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+
+/// Parses a PDF date, "D:YYYYMMDDHHmmSSOHH'mm'" where everything after the year is
+/// optional, into seconds since the Unix epoch.
+fn parse_pdf_date(date: &str) -> Option<i64> {
+    let date = date.trim();
+    let date = date.strip_prefix("D:").unwrap_or(date);
+    let digits = date.bytes().take_while(u8::is_ascii_digit).count();
+    if digits < 4 {
+        return None;
+    }
+    let (timestamp, zone) = date.split_at(digits);
+
+    let part = |start: usize, default: i64| {
+        timestamp.get(start..start + 2).and_then(|part| part.parse::<i64>().ok()).unwrap_or(default)
+    };
+    let year = timestamp[..4].parse::<i64>().ok()?;
+    let (month, day) = (part(4, 1), part(6, 1));
+    let (hour, minute, second) = (part(8, 0), part(10, 0), part(12, 0));
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut offset = 0;
+    let mut zone_chars = zone.chars();
+    if let Some(sign @ ('+' | '-')) = zone_chars.next() {
+        let zone_digits: String = zone_chars.filter(char::is_ascii_digit).collect();
+        let hours = zone_digits.get(0..2).and_then(|hours| hours.parse::<i64>().ok()).unwrap_or(0);
+        let minutes = zone_digits.get(2..4).and_then(|minutes| minutes.parse::<i64>().ok()).unwrap_or(0);
+        offset = (hours * 3600 + minutes * 60) * if sign == '-' { -1 } else { 1 };
+    }
+
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+
+fn read_info(document: &lopdf::Document) -> PdfInfo {
+    let info = info_dictionary(document);
+    let text = |key: &[u8]| info.and_then(|info| info_text(info, key));
+
+    PdfInfo {
+        title: text(b"Title"),
+        author: text(b"Author"),
+        creation_date: text(b"CreationDate").as_deref().and_then(parse_pdf_date),
+        page_count: document.get_pages().len() as u32,
+    }
+}
+
+
+/// Decodes the pixels of an image of a PDF. JPEG images and uncompressed or deflated
+/// samples are supported, other encodings such as CCITT fax, JBIG2 or JPEG 2000 are not.
+fn decode_image(document: &lopdf::Document, image: &PdfImage) -> Option<RgbImage> {
+    let (width, height) = (u32::try_from(image.width).ok()?, u32::try_from(image.height).ok()?);
+    let pixels = width as usize * height as usize;
+    if pixels == 0 || pixels as u64 > MAX_OCR_PIXELS || image.color_space.as_deref() == Some("Indexed") {
+        return None;
+    }
+
+    let filters = image.filters.as_deref().unwrap_or_default();
+    if filters.iter().any(|filter| filter == "DCTDecode") {
+        if filters.len() > 1 {
+            return None;
+        }
+        let reader = ImageReader::with_format(Cursor::new(image.content), ImageFormat::Jpeg);
+        return reader.decode().ok().map(|image| image.to_rgb8());
+    }
+
+    let stream = document.get_object(image.id).ok()?.as_stream().ok()?;
+    let samples = stream.decompressed_content_with_limit(pixels * 4).ok()?;
+    match image.bits_per_component {
+        Some(8) => {
+            let rgb = match samples.len() / pixels {
+                1 => samples[..pixels].iter().flat_map(|&gray| [gray; 3]).collect(),
+                3 => samples[..pixels * 3].to_vec(),
+                4 => samples[..pixels * 4]
+                    .chunks_exact(4)
+                    .flat_map(|cmyk| {
+                        let black = 255 - cmyk[3] as u32;
+                        [cmyk[0], cmyk[1], cmyk[2]].map(|ink| ((255 - ink as u32) * black / 255) as u8)
+                    })
+                    .collect(),
+                _ => return None,
+            };
+            RgbImage::from_raw(width, height, rgb)
+        }
+        // Black and white scans and image masks, where a 0 bit is black unless the decode
+        // array says otherwise.
+        Some(1) | None => {
+            let row_bytes = (width as usize).div_ceil(8);
+            if samples.len() < row_bytes * height as usize {
+                return None;
+            }
+            let inverted = match stream.dict.get(b"Decode") {
+                Ok(Object::Array(decode)) => decode.first().and_then(|value| value.as_float().ok()) == Some(1.0),
+                _ => false,
+            };
+            Some(RgbImage::from_fn(width, height, |x, y| {
+                let bit = samples[y as usize * row_bytes + x as usize / 8] >> (7 - x % 8) & 1;
+                Rgb([if (bit == 1) != inverted { 255 } else { 0 }; 3])
+            }))
+        }
+        _ => None,
+    }
+}
+
+
+/// Recognises the text of a page without a text layer, taking the largest image drawn on it
+/// for a scan of the page. `None` when OCR is not enabled or the page has no such image.
+fn recognize_page(document: &lopdf::Document, page_id: ObjectId) -> Result<Option<String>> {
+    if !is_ocr_enabled() {
+        return Ok(None);
+    }
+    let images = document.get_page_images(page_id).unwrap_or_default();
+    let largest = images.iter().max_by_key(|image| image.width.saturating_mul(image.height));
+    match largest.and_then(|image| decode_image(document, image)) {
+        Some(image) => recognize(&image),
+        None => Ok(None),
+    }
+}
+
+
+/// Extracts the text of the PDF at `path` one page at a time, in page order, passing each
+/// page to `on_page`. Stops early when `on_page` returns false. Files that cannot be parsed
+/// or need a password are reported as errors. Pages without text go through OCR when it is
+/// enabled.
+pub(crate) fn extract_pdf_with(path: &Path, mut on_page: impl FnMut(PdfPage) -> bool) -> Result<PdfInfo> {
+    let document = load_pdf(path)?;
+    let info = read_info(&document);
+
+    for (page_number, page_id) in document.get_pages() {
+        let mut page = match document.extract_text_with_limit(&[page_number], MAX_DECOMPRESSED_SIZE) {
+            Ok(text) => PdfPage { page_number, text: text.trim().to_string(), error: None },
+            Err(error) => PdfPage { page_number, text: String::new(), error: Some(error.to_string()) },
+        };
+        if page.text.is_empty() {
+            match recognize_page(&document, page_id) {
+                Ok(text) => page.text = text.unwrap_or_default().trim().to_string(),
+                Err(error) => page.error = Some(format!("OCR failed: {}", error)),
+            }
+        }
+        if !on_page(page) {
+            break;
+        }
+    }
+
+    Ok(info)
+}
+
+
+/// Reads the title, author, creation date and page count of the PDF at `path`.
+pub fn read_pdf_info(path: String) -> Result<PdfInfo> {
+    Ok(read_info(&load_pdf(Path::new(&path))?))
+}
+
+
+pub fn extract_pdf(path: String) -> Result<PdfText> {
+    let mut pages = Vec::new();
+    let info = extract_pdf_with(Path::new(&path), |page| {
+        pages.push(page);
+        true
+    })?;
+
+    Ok(PdfText { info, pages })
+}
+
+
+/// Streams the text of the PDF at `path` page by page and returns its metadata once every
+/// page has been sent. Closing the stream on the Dart side stops the extraction.
+pub fn stream_pdf_pages(path: String, sink: StreamSink<PdfPage>) -> Result<PdfInfo> {
+    extract_pdf_with(Path::new(&path), |page| sink.add(page).is_ok())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_pdf_dates_with_their_offset() {
+        assert_eq!(parse_pdf_date("D:20240315143000+01'00'"), Some(1_710_509_400));
+        assert_eq!(parse_pdf_date("D:19981231182959-05'30'"), Some(915_148_799));
+        assert_eq!(parse_pdf_date("20240315133000Z"), Some(1_710_509_400));
+    }
+
+    #[test]
+    fn defaults_missing_parts_of_pdf_dates() {
+        assert_eq!(parse_pdf_date("D:2024"), Some(1_704_067_200));
+        assert_eq!(parse_pdf_date(" D:202401 "), Some(1_704_067_200));
+        assert_eq!(parse_pdf_date("D:2024é"), Some(1_704_067_200));
+    }
+
+    #[test]
+    fn rejects_invalid_pdf_dates() {
+        assert_eq!(parse_pdf_date("D:20241301"), None);
+        assert_eq!(parse_pdf_date("D:202403152500"), None);
+        assert_eq!(parse_pdf_date("D:24"), None);
+        assert_eq!(parse_pdf_date("yesterday"), None);
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::{anyhow, Result};
+use tantivy::query::{AllQuery, BooleanQuery, EmptyQuery, FuzzyTermQuery, Occur, PhraseQuery, Query as TantivyQuery, TermQuery};
+use tantivy::schema::{Field, FieldType, IndexRecordOption};
+use tantivy::tokenizer::TokenStream;
+use tantivy::{Index, Term};
+use unicode_normalization::UnicodeNormalization;
+
+
+/// A query built from Dart without going through the query string syntax.
+#[derive(Debug, Clone)]
+pub enum Query {
+    /// Matches documents containing every word of `text`.
+    Term { text: String },
+    /// Matches the words of `text` in order, allowing `slop` other words in between.
+    Phrase { text: String, slop: u32 },
+    Boolean {
+        must: Vec<Query>,
+        should: Vec<Query>,
+        must_not: Vec<Query>,
+    },
+    /// Runs `query` against `field` instead of the document text. Only tokenized text
+    /// fields can be scoped to, fields matched whole like `path` cannot.
+    Field { field: String, query: Box<Query> },
+}
+
+
+impl Query {
+    /// Words the query looks for, used to build spelling suggestions.
+    pub(crate) fn text(&self) -> String {
+        match self {
+            Query::Term { text } | Query::Phrase { text, .. } => text.clone(),
+            Query::Boolean { must, should, .. } => must
+                .iter()
+                .chain(should)
+                .map(Query::text)
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+            Query::Field { query, .. } => query.text(),
+        }
+    }
+
+    /// The same query with its texts in Unicode normalisation form C.
+    pub(crate) fn nfc(&self) -> Query {
+        let nfc = |queries: &Vec<Query>| queries.iter().map(Query::nfc).collect();
+        match self {
+            Query::Term { text } => Query::Term { text: text.nfc().collect() },
+            Query::Phrase { text, slop } => Query::Phrase { text: text.nfc().collect(), slop: *slop },
+            Query::Boolean { must, should, must_not } => Query::Boolean {
+                must: nfc(must),
+                should: nfc(should),
+                must_not: nfc(must_not),
+            },
+            Query::Field { field, query } => Query::Field { field: field.clone(), query: Box::new(query.nfc()) },
+        }
+    }
+}
+
+
+/// Converts `query` into a tantivy query. Unscoped terms are searched in `default_field`,
+/// and expanded to fuzzy terms when `fuzzy_distance` is not 0.
+pub(crate) fn to_tantivy_query(query: &Query, index: &Index, default_field: Field, fuzzy_distance: u8) -> Result<Box<dyn TantivyQuery>> {
+    match query {
+        Query::Term { text } => {
+            let terms = tokenize(index, default_field, text)?;
+            let clauses: Vec<(Occur, Box<dyn TantivyQuery>)> = terms
+                .into_iter()
+                .map(|(_, term)| {
+                    let query: Box<dyn TantivyQuery> = if fuzzy_distance > 0 {
+                        Box::new(FuzzyTermQuery::new(term, fuzzy_distance, true))
+                    } else {
+                        Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs))
+                    };
+                    (Occur::Must, query)
+                })
+                .collect();
+            Ok(collapse(clauses))
+        }
+        Query::Phrase { text, slop } => {
+            let terms = tokenize(index, default_field, text)?;
+            if terms.len() < 2 {
+                return to_tantivy_query(&Query::Term { text: text.clone() }, index, default_field, fuzzy_distance);
+            }
+            let mut phrase_query = PhraseQuery::new_with_offset(terms);
+            phrase_query.set_slop(*slop);
+            Ok(Box::new(phrase_query))
+        }
+        Query::Boolean { must, should, must_not } => {
+            let mut clauses = Vec::new();
+            for (occur, queries) in [(Occur::Must, must), (Occur::Should, should), (Occur::MustNot, must_not)] {
+                for query in queries {
+                    clauses.push((occur, to_tantivy_query(query, index, default_field, fuzzy_distance)?));
+                }
+            }
+            // A boolean query made of exclusions only would not match anything.
+            if must.is_empty() && should.is_empty() && !must_not.is_empty() {
+                clauses.push((Occur::Must, Box::new(AllQuery)));
+            }
+            Ok(Box::new(BooleanQuery::new(clauses)))
+        }
+        Query::Field { field, query } => {
+            let schema = index.schema();
+            let scoped_field = schema
+                .get_field(field)
+                .map_err(|_| anyhow!("Unknown field '{}'", field))?;
+            match schema.get_field_entry(scoped_field).field_type() {
+                // Phrases need positions, which fields indexed whole as one term do not have.
+                FieldType::Str(options) if options.get_indexing_options().is_some_and(|indexing| indexing.index_option().has_positions()) => {
+                    to_tantivy_query(query, index, scoped_field, fuzzy_distance)
+                }
+                _ => Err(anyhow!("Field '{}' is not a searchable text field", field)),
+            }
+        }
+    }
+}
+
+
+fn tokenize(index: &Index, field: Field, text: &str) -> Result<Vec<(usize, Term)>> {
+    let mut tokenizer = index.tokenizer_for_field(field)?;
+    let mut token_stream = tokenizer.token_stream(text);
+    let mut terms = Vec::new();
+    while token_stream.advance() {
+        let token = token_stream.token();
+        terms.push((token.position, Term::from_field_text(field, &token.text)));
+    }
+    Ok(terms)
+}
+
+
+fn collapse(mut clauses: Vec<(Occur, Box<dyn TantivyQuery>)>) -> Box<dyn TantivyQuery> {
+    match clauses.len() {
+        0 => Box::new(EmptyQuery),
+        1 => clauses.pop().unwrap().1,
+        _ => Box::new(BooleanQuery::new(clauses)),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tantivy::schema::{Schema, STRING, TEXT};
+
+    #[test]
+    fn normalizes_every_text_of_a_query() {
+        let query = Query::Boolean {
+            must: vec![Query::Term { text: "cafe\u{301}".to_string() }],
+            should: vec![],
+            must_not: vec![Query::Field {
+                field: "title".to_string(),
+                query: Box::new(Query::Phrase { text: "re\u{301}sume\u{301}".to_string(), slop: 0 }),
+            }],
+        };
+
+        let Query::Boolean { must, must_not, .. } = query.nfc() else { panic!("not a boolean query") };
+        assert_eq!(must[0].text(), "café");
+        assert_eq!(must_not[0].text(), "résumé");
+    }
+
+    fn scoped(field: &str) -> Result<Box<dyn TantivyQuery>> {
+        let mut schema_builder = Schema::builder();
+        schema_builder.add_text_field("id", STRING);
+        let text_field = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+
+        let query = Query::Field {
+            field: field.to_string(),
+            query: Box::new(Query::Phrase { text: "annual report".to_string(), slop: 0 }),
+        };
+        to_tantivy_query(&query, &index, text_field, 0)
+    }
+
+    #[test]
+    fn scopes_only_to_tokenized_text_fields() {
+        assert!(scoped("text").is_ok());
+        assert!(scoped("id").unwrap_err().to_string().contains("not a searchable text field"));
+        assert!(scoped("missing").unwrap_err().to_string().contains("Unknown field"));
+    }
+}
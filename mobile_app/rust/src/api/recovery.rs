@@ -0,0 +1,257 @@
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use std::path::Path;
+use std::sync::Mutex;
+use tantivy::Index;
+
+use crate::api::history::now;
+use crate::api::migration::{copy_app_files, migrate, sibling_dir, stored_schema_version, write_schema_version};
+use crate::api::tantivy::{build_schema, IndexFields, SCHEMA_VERSION};
+
+
+// Marker file left in the index directory until the app has indexed its files again.
+pub(crate) const REBUILD_PENDING_FILE: &str = "rebuild_pending";
+const QUARANTINE_SUFFIX: &str = ".corrupt";
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryReason {
+    /// The index files were damaged, e.g. by the app being killed during a commit or the
+    /// storage filling up.
+    Corrupted,
+    /// The index was written by an unknown version of the app and could not be migrated.
+    IncompatibleSchema,
+}
+
+
+/// What happened when the index could not be opened as it was. The app was given a fresh,
+/// empty index and has to index its files again, see `rebuild_pending`.
+#[derive(Debug, Clone)]
+pub struct RecoveryReport {
+    pub reason: RecoveryReason,
+    pub problems: Vec<String>,
+    /// Where the damaged index was moved, kept until the next recovery to help diagnose
+    /// the problem.
+    pub quarantine_dir: Option<String>,
+    /// Seconds since the Unix epoch.
+    pub occurred_at: i64,
+}
+
+
+static LAST_RECOVERY: Lazy<Mutex<Option<RecoveryReport>>> = Lazy::new(|| Mutex::new(None));
+
+
+pub(crate) fn queue_rebuild(index_dir: &Path) -> Result<()> {
+    std::fs::write(index_dir.join(REBUILD_PENDING_FILE), now().to_string())?;
+    Ok(())
+}
+
+
+/// Lists what is wrong with the index in `index_dir` without reading all of it: `meta.json`
+/// must parse, every segment file it references must be present, every segment must open
+/// and the schema must have the expected fields.
+///
+/// Only the tantivy index is checked. Checking an embedding store is out of scope: this
+/// crate keeps none, embeddings are computed on demand and not stored.
+pub(crate) fn check_index(index_dir: &Path) -> Vec<String> {
+    let index = match Index::open_in_dir(index_dir) {
+        Ok(index) => index,
+        Err(error) => return vec![format!("Cannot open index: {}", error)],
+    };
+
+    let mut problems = Vec::new();
+    match index.searchable_segment_metas() {
+        Ok(segment_metas) => {
+            for segment_meta in segment_metas {
+                let mut files: Vec<_> = segment_meta.list_files().into_iter().collect();
+                files.sort();
+                for file in files {
+                    let is_delete_file = file.extension().is_some_and(|extension| extension == "del");
+                    if (!is_delete_file || segment_meta.has_deletes()) && !index_dir.join(&file).exists() {
+                        problems.push(format!("Missing segment file {}", file.display()));
+                    }
+                }
+            }
+        }
+        Err(error) => problems.push(format!("Cannot read segment list: {}", error)),
+    }
+    if !problems.is_empty() {
+        return problems;
+    }
+
+    if let Err(error) = index.reader() {
+        problems.push(format!("Cannot open segments: {}", error));
+    }
+    if let Err(error) = IndexFields::resolve(&index.schema()) {
+        problems.push(format!("Unexpected schema: {}", error));
+    }
+
+    problems
+}
+
+
+/// Moves the index in `index_dir` aside, replacing a previously quarantined one, and queues
+/// a rebuild in the fresh index created in its place. The file manifest is kept, so that the
+/// rebuild can report the chunks that were lost.
+pub(crate) fn quarantine(index_dir: &Path, reason: RecoveryReason, problems: Vec<String>) -> Result<Index> {
+    let quarantine_dir = sibling_dir(index_dir, QUARANTINE_SUFFIX);
+    if quarantine_dir.exists() {
+        std::fs::remove_dir_all(&quarantine_dir)?;
+    }
+    std::fs::rename(index_dir, &quarantine_dir)?;
+
+    std::fs::create_dir_all(index_dir)?;
+    let index = Index::create_in_dir(index_dir, build_schema())?;
+    write_schema_version(index_dir, SCHEMA_VERSION)?;
+    copy_app_files(&quarantine_dir, index_dir)?;
+    queue_rebuild(index_dir)?;
+
+    *LAST_RECOVERY.lock().unwrap() = Some(RecoveryReport {
+        reason,
+        problems,
+        quarantine_dir: Some(quarantine_dir.to_string_lossy().to_string()),
+        occurred_at: now(),
+    });
+
+    Ok(index)
+}
+
+
+/// Opens the index in `index_dir`, migrating it to the current schema and checking it
+/// first. A damaged index is quarantined and replaced by an empty one rather than failing,
+/// so that the app never gets stuck on startup. An index written by a newer version of the
+/// app is an error, it is left for that version to open.
+pub(crate) fn open_index(index_dir: &Path) -> Result<Index> {
+    if let Err(error) = migrate(index_dir) {
+        let newer = stored_schema_version(index_dir).ok().flatten().is_some_and(|version| version > SCHEMA_VERSION);
+        if newer {
+            return Err(error);
+        }
+        return quarantine(index_dir, RecoveryReason::Corrupted, vec![format!("Cannot open index: {}", error)]);
+    }
+
+    if !index_dir.join("meta.json").exists() {
+        let index = Index::create_in_dir(index_dir, build_schema())?;
+        write_schema_version(index_dir, SCHEMA_VERSION)?;
+        return Ok(index);
+    }
+
+    let problems = check_index(index_dir);
+    if !problems.is_empty() {
+        return quarantine(index_dir, RecoveryReason::Corrupted, problems);
+    }
+
+    Ok(Index::open_in_dir(index_dir)?)
+}
+
+
+/// Returns, once, what happened the last time the index had to be recovered, so that the
+/// UI can tell the user why their files are being indexed again.
+#[flutter_rust_bridge::frb(sync)]
+pub fn take_recovery_report() -> Option<RecoveryReport> {
+    LAST_RECOVERY.lock().unwrap().take()
+}
+
+
+/// Whether the index in `dir_path` was reset and every file has to be indexed again.
+/// `reconcile_files` takes care of it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rebuild_pending(dir_path: String) -> bool {
+    Path::new(&dir_path).join(REBUILD_PENDING_FILE).exists()
+}
+
+
+/// To be called once every file has been scheduled to be indexed again after a recovery.
+pub fn clear_rebuild_pending(dir_path: String) -> Result<()> {
+    let path = Path::new(&dir_path).join(REBUILD_PENDING_FILE);
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::tantivy::{Document, DocumentMetadata};
+    use tantivy::schema::{Schema, STORED, STRING, TEXT};
+    use tantivy::TantivyDocument;
+
+    fn test_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("recovery-{}-{}", name, std::process::id()));
+        for dir in [dir.clone(), sibling_dir(&dir, QUARANTINE_SUFFIX)] {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn index_with_documents(dir: &Path) {
+        let index = Index::create_in_dir(dir, build_schema()).unwrap();
+        write_schema_version(dir, SCHEMA_VERSION).unwrap();
+        let fields = IndexFields::resolve(&index.schema()).unwrap();
+        let mut writer = index.writer::<TantivyDocument>(15_000_000).unwrap();
+        let doc = Document { id: "report".to_string(), text: "Quarterly report".to_string() };
+        writer.add_document(fields.build_document(&doc, &DocumentMetadata::default())).unwrap();
+        writer.commit().unwrap();
+    }
+
+    /// Opens `dir` and checks that it was quarantined and replaced by an empty index.
+    fn assert_recovered(dir: &Path) {
+        let index = open_index(dir).unwrap();
+
+        assert_eq!(index.reader().unwrap().searcher().num_docs(), 0);
+        assert!(sibling_dir(dir, QUARANTINE_SUFFIX).exists());
+        assert!(rebuild_pending(dir.to_string_lossy().to_string()));
+        assert!(check_index(dir).is_empty());
+    }
+
+    #[test]
+    fn sound_index_opens_as_it_is() {
+        let dir = test_dir("sound");
+        index_with_documents(&dir);
+
+        assert!(check_index(&dir).is_empty());
+        assert_eq!(open_index(&dir).unwrap().reader().unwrap().searcher().num_docs(), 1);
+        assert!(!sibling_dir(&dir, QUARANTINE_SUFFIX).exists());
+    }
+
+    #[test]
+    fn missing_segment_file_is_recovered() {
+        let dir = test_dir("missing-segment");
+        index_with_documents(&dir);
+        let postings = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().is_some_and(|extension| extension == "idx"))
+            .unwrap();
+        std::fs::remove_file(postings).unwrap();
+
+        assert!(check_index(&dir)[0].starts_with("Missing segment file"));
+        assert_recovered(&dir);
+    }
+
+    #[test]
+    fn corrupt_meta_json_is_recovered() {
+        let dir = test_dir("corrupt-meta");
+        index_with_documents(&dir);
+        std::fs::write(dir.join("meta.json"), "{ not json").unwrap();
+
+        assert!(check_index(&dir)[0].starts_with("Cannot open index"));
+        assert_recovered(&dir);
+    }
+
+    #[test]
+    fn schema_mismatch_is_recovered() {
+        let dir = test_dir("schema-mismatch");
+        let mut schema_builder = Schema::builder();
+        schema_builder.add_text_field("id", STRING | STORED);
+        schema_builder.add_text_field("text", TEXT | STORED);
+        Index::create_in_dir(&dir, schema_builder.build()).unwrap();
+        write_schema_version(&dir, SCHEMA_VERSION).unwrap();
+
+        assert!(check_index(&dir)[0].starts_with("Unexpected schema"));
+        assert_recovered(&dir);
+    }
+}
@@ -0,0 +1,451 @@
+use anyhow::{anyhow, Result};
+use csv::ReaderBuilder;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::api::date::civil_from_days;
+use crate::api::extract::{chunk_id, file_metadata, ExtractedFile};
+use crate::api::office::{attribute, event_text, open_package, read_part, read_properties};
+use crate::api::tantivy::{Document, DocumentMetadata, DocumentWithMetadata};
+use crate::api::text::{read_text_file, TextFormat};
+
+
+// Rows are grouped into documents of about this many characters, so that a search result
+// shows the matching row with a few of its neighbours.
+const ROW_GROUP_CHARS: usize = 500;
+// Delimiters recognised in CSV files, the first being the default.
+const CSV_DELIMITERS: &[u8] = b",;\t|";
+// Built-in XLSX number formats that show dates or times.
+const DATE_FORMAT_IDS: &[u32] = &[14, 15, 16, 17, 18, 19, 20, 21, 22, 45, 46, 47];
+// Days between the epoch of XLSX dates and 1970-01-01, for workbooks using 1900 and 1904
+// dates. The 1900 epoch is 1899-12-30 because of a leap day 1900 never had.
+const EPOCH_1900_DAYS: i64 = 25_569;
+const EPOCH_1904_DAYS: i64 = 24_107;
+
+
+/// Name of a column as shown by spreadsheet apps: "A" for 0, "AA" for 26.
+fn column_name(index: usize) -> String {
+    let mut name = Vec::new();
+    let mut index = index + 1;
+    while index > 0 {
+        name.push(b'A' + ((index - 1) % 26) as u8);
+        index = (index - 1) / 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+
+/// Index of the column of a cell reference such as "C7".
+fn column_index(reference: &str) -> Option<usize> {
+    let mut letters = reference.bytes().take_while(u8::is_ascii_alphabetic);
+    let number = letters.try_fold(0usize, |number, letter| {
+        number.checked_mul(26)?.checked_add((letter.to_ascii_uppercase() - b'A') as usize + 1)
+    })?;
+    number.checked_sub(1)
+}
+
+
+/// Turns the rows of a sheet into documents holding a few rows each, every row written as
+/// "<column>: <value>" lines. The first row is taken for headers when it holds only text,
+/// otherwise columns are named by their letter.
+fn row_documents(
+    path: &Path,
+    sheet: Option<&str>,
+    rows: Vec<(u32, Vec<String>)>,
+    metadata: &DocumentMetadata,
+    documents: &mut Vec<DocumentWithMetadata>,
+) {
+    let mut rows = rows.into_iter().filter(|(_, cells)| cells.iter().any(|cell| !cell.trim().is_empty())).peekable();
+    let is_header = rows.peek().is_some_and(|(_, cells)| {
+        cells.iter().map(|cell| cell.trim()).all(|cell| cell.is_empty() || cell.parse::<f64>().is_err())
+    });
+    let headers: Vec<String> = match is_header {
+        true => rows.next().map(|(_, cells)| cells.iter().map(|cell| cell.trim().to_string()).collect()).unwrap_or_default(),
+        false => Vec::new(),
+    };
+
+    let mut group: Option<(u32, String)> = None;
+    for (number, cells) in rows {
+        let text = cells
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !value.trim().is_empty())
+            .map(|(index, value)| {
+                let header = headers.get(index).filter(|header| !header.is_empty()).cloned();
+                format!("{}: {}", header.unwrap_or_else(|| column_name(index)), value.trim())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match group.as_mut() {
+            Some((_, group_text)) if group_text.chars().count() + 2 + text.chars().count() <= ROW_GROUP_CHARS => {
+                group_text.push_str("\n\n");
+                group_text.push_str(&text);
+            }
+            _ => {
+                if let Some((first_row, text)) = group.replace((number, text)) {
+                    documents.push(row_document(path, sheet, first_row, text, documents.len(), metadata));
+                }
+            }
+        }
+    }
+    if let Some((first_row, text)) = group {
+        documents.push(row_document(path, sheet, first_row, text, documents.len(), metadata));
+    }
+}
+
+
+fn row_document(
+    path: &Path,
+    sheet: Option<&str>,
+    first_row: u32,
+    text: String,
+    index: usize,
+    metadata: &DocumentMetadata,
+) -> DocumentWithMetadata {
+    DocumentWithMetadata {
+        doc: Document { id: chunk_id(path, index), text },
+        metadata: DocumentMetadata { sheet: sheet.map(str::to_string), row: Some(first_row), ..metadata.clone() },
+    }
+}
+
+
+/// The delimiter used by the CSV `text`: the one of `CSV_DELIMITERS` found most often in
+/// its first line.
+fn csv_delimiter(text: &str) -> u8 {
+    let first_line = text.lines().next().unwrap_or_default();
+    let count = |delimiter: u8| first_line.bytes().filter(|&byte| byte == delimiter).count();
+    let best = CSV_DELIMITERS.iter().copied().max_by_key(|&delimiter| count(delimiter));
+    best.filter(|&delimiter| count(delimiter) > 0).unwrap_or(CSV_DELIMITERS[0])
+}
+
+
+/// Reads a CSV or TSV file into documents of a few rows each. Rows are numbered from 1 like
+/// spreadsheet apps do when opening the file.
+pub(crate) fn extract_csv(path: &Path) -> Result<ExtractedFile> {
+    let text = read_text_file(path, TextFormat::Plain)?;
+    let is_tsv = path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("tsv"));
+    let delimiter = if is_tsv { b'\t' } else { csv_delimiter(&text) };
+
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .from_reader(text.as_bytes());
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|error| anyhow!("Invalid CSV in {}: {}", path.display(), error))?;
+        rows.push((index as u32 + 1, record.iter().map(str::to_string).collect()));
+    }
+
+    let metadata = file_metadata(path, &None, &None);
+    let mut documents = Vec::new();
+    row_documents(path, None, rows, &metadata, &mut documents);
+    Ok(ExtractedFile { path: metadata.path, title: None, author: None, documents })
+}
+
+
+/// The strings of `xl/sharedStrings.xml`, which text cells refer to by index. Phonetic
+/// readings are left out.
+fn read_shared_strings(xml: &str) -> Result<Vec<String>> {
+    let mut reader = Reader::from_str(xml);
+    let mut strings = Vec::new();
+    let (mut in_text, mut in_phonetic) = (false, false);
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(element) => match element.local_name().as_ref() {
+                "si" => strings.push(String::new()),
+                "t" => in_text = true,
+                "rPh" => in_phonetic = true,
+                _ => {}
+            },
+            Event::Empty(element) if element.local_name().as_ref() == "si" => strings.push(String::new()),
+            Event::End(element) => match element.local_name().as_ref() {
+                "t" => in_text = false,
+                "rPh" => in_phonetic = false,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {
+                if let (true, false, Some(string), Some(text)) = (in_text, in_phonetic, strings.last_mut(), event_text(&event)) {
+                    string.push_str(&text);
+                }
+            }
+        }
+    }
+
+    Ok(strings)
+}
+
+
+/// Whether a custom number format such as "dd/mm/yyyy" or "h:mm" shows a date or time.
+/// Quoted text and bracketed colours or conditions are not looked at.
+fn is_date_format(format: &str) -> bool {
+    let mut plain = String::new();
+    let (mut quoted, mut bracketed) = (false, false);
+    for character in format.chars() {
+        match character {
+            '"' => quoted = !quoted,
+            '[' if !quoted => bracketed = true,
+            ']' if !quoted => bracketed = false,
+            _ if !quoted && !bracketed => plain.push(character.to_ascii_lowercase()),
+            _ => {}
+        }
+    }
+    plain.contains(['d', 'y']) || plain.contains('h') && plain.contains(':')
+}
+
+
+/// Indexes of the cell styles of `xl/styles.xml` that show numbers as dates or times.
+fn read_date_styles(xml: &str) -> Result<HashSet<usize>> {
+    let mut reader = Reader::from_str(xml);
+    let mut custom_dates = HashSet::new();
+    let mut date_styles = HashSet::new();
+    let mut in_cell_styles = false;
+    let mut style_index = 0;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) | Event::Empty(element) => match element.local_name().as_ref() {
+                "numFmt" => {
+                    let id = attribute(&element, "numFmtId").and_then(|id| id.parse::<u32>().ok());
+                    if let (Some(id), Some(format)) = (id, attribute(&element, "formatCode")) {
+                        if is_date_format(&format) {
+                            custom_dates.insert(id);
+                        }
+                    }
+                }
+                "cellXfs" => in_cell_styles = true,
+                "xf" if in_cell_styles => {
+                    let id = attribute(&element, "numFmtId").and_then(|id| id.parse::<u32>().ok()).unwrap_or(0);
+                    if DATE_FORMAT_IDS.contains(&id) || custom_dates.contains(&id) {
+                        date_styles.insert(style_index);
+                    }
+                    style_index += 1;
+                }
+                _ => {}
+            },
+            Event::End(element) if element.local_name().as_ref() == "cellXfs" => in_cell_styles = false,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(date_styles)
+}
+
+
+/// Writes the serial date `value`, counted in days from `epoch_days` before 1970-01-01, as
+/// "2024-03-31", "2024-03-31 14:05" or "14:05".
+fn format_date(value: f64, epoch_days: i64) -> Option<String> {
+    if !value.is_finite() || !(0.0..3_000_000.0).contains(&value) {
+        return None;
+    }
+    let days = value.floor() as i64;
+    let minutes = ((value - value.floor()) * 1440.0).round() as i64;
+    let (days, minutes) = if minutes == 1440 { (days + 1, 0) } else { (days, minutes) };
+    let time = format!("{:02}:{:02}", minutes / 60, minutes % 60);
+    if days == 0 {
+        return Some(time);
+    }
+
+    let (year, month, day) = civil_from_days(days - epoch_days);
+    let date = format!("{:04}-{:02}-{:02}", year, month, day);
+    Some(if minutes == 0 { date } else { format!("{} {}", date, time) })
+}
+
+
+/// A sheet listed in `xl/workbook.xml`, with the part holding its cells.
+struct Sheet {
+    name: String,
+    part: String,
+}
+
+
+/// The visible sheets of the workbook in their order, and whether its dates count from 1904.
+fn read_workbook(package: &mut ZipArchive<File>, path: &Path) -> Result<(Vec<Sheet>, bool)> {
+    let workbook = read_part(package, "xl/workbook.xml")?
+        .ok_or_else(|| anyhow!("{} has no xl/workbook.xml", path.display()))?;
+    let relationships = read_part(package, "xl/_rels/workbook.xml.rels")?.unwrap_or_default();
+
+    let mut targets = HashMap::new();
+    let mut reader = Reader::from_str(&relationships);
+    loop {
+        match reader.read_event().map_err(|error| anyhow!("Invalid workbook relationships: {}", error))? {
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "Relationship" => {
+                if let (Some(id), Some(target)) = (attribute(&element, "Id"), attribute(&element, "Target")) {
+                    let part = match target.strip_prefix('/') {
+                        Some(absolute) => absolute.to_string(),
+                        None => format!("xl/{}", target),
+                    };
+                    targets.insert(id, part);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let mut sheets = Vec::new();
+    let mut date_1904 = false;
+    let mut reader = Reader::from_str(&workbook);
+    loop {
+        match reader.read_event().map_err(|error| anyhow!("Invalid xl/workbook.xml: {}", error))? {
+            Event::Start(element) | Event::Empty(element) => match element.local_name().as_ref() {
+                "workbookPr" => {
+                    date_1904 = matches!(attribute(&element, "date1904").as_deref(), Some("1" | "true"));
+                }
+                "sheet" => {
+                    // Hidden sheets mostly hold lookup tables rather than data people look for.
+                    if attribute(&element, "state").is_some_and(|state| state != "visible") {
+                        continue;
+                    }
+                    let part = attribute(&element, "r:id").and_then(|id| targets.get(&id).cloned());
+                    if let (Some(name), Some(part)) = (attribute(&element, "name"), part) {
+                        sheets.push(Sheet { name, part });
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok((sheets, date_1904))
+}
+
+
+/// Reads the rows of a worksheet part, numbered as in the sheet, with the value of each cell
+/// as shown by spreadsheet apps except for number formatting other than dates.
+fn read_sheet_rows(xml: &str, shared_strings: &[String], date_styles: &HashSet<usize>, epoch_days: i64) -> Result<Vec<(u32, Vec<String>)>> {
+    let mut reader = Reader::from_str(xml);
+    let mut rows: Vec<(u32, Vec<String>)> = Vec::new();
+    // Column, type, style and raw value of the cell being read.
+    let mut cell: Option<(usize, String, Option<usize>, String)> = None;
+    let mut in_value = false;
+
+    loop {
+        let event = reader.read_event()?;
+        match &event {
+            Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == "row" => {
+                let previous = rows.last().map_or(0, |(number, _)| *number);
+                let number = attribute(element, "r").and_then(|number| number.parse().ok()).unwrap_or(previous + 1);
+                rows.push((number, Vec::new()));
+            }
+            Event::Start(element) if element.local_name().as_ref() == "c" => {
+                let previous = rows.last().map_or(0, |(_, cells)| cells.len());
+                let column = attribute(element, "r").and_then(|reference| column_index(&reference)).unwrap_or(previous);
+                let kind = attribute(element, "t").unwrap_or_default();
+                let style = attribute(element, "s").and_then(|style| style.parse().ok());
+                cell = Some((column, kind, style, String::new()));
+            }
+            Event::Start(element) if matches!(element.local_name().as_ref(), "v" | "t") => in_value = true,
+            Event::End(element) if matches!(element.local_name().as_ref(), "v" | "t") => in_value = false,
+            Event::End(element) if element.local_name().as_ref() == "c" => {
+                let (Some((column, kind, style, value)), Some((_, cells))) = (cell.take(), rows.last_mut()) else { continue };
+                let value = match kind.as_str() {
+                    "s" => value.trim().parse::<usize>().ok().and_then(|index| shared_strings.get(index).cloned()),
+                    "b" => Some(if value.trim() == "1" { "TRUE" } else { "FALSE" }.to_string()),
+                    "" | "n" if style.is_some_and(|style| date_styles.contains(&style)) => {
+                        value.trim().parse().ok().and_then(|value| format_date(value, epoch_days)).or(Some(value))
+                    }
+                    _ => Some(value),
+                };
+                if let Some(value) = value.filter(|value| !value.trim().is_empty()) {
+                    if cells.len() <= column {
+                        cells.resize(column + 1, String::new());
+                    }
+                    cells[column] = value;
+                }
+            }
+            Event::Eof => break,
+            _ => {
+                if let (true, Some((_, _, _, value)), Some(text)) = (in_value, cell.as_mut(), event_text(&event)) {
+                    value.push_str(&text);
+                }
+            }
+        }
+    }
+
+    Ok(rows)
+}
+
+
+/// Reads an XLSX workbook into documents of a few rows each, sheet by sheet. Formulas are
+/// indexed by the value last computed for them.
+pub(crate) fn extract_xlsx(path: &Path) -> Result<ExtractedFile> {
+    let mut package = open_package(path)?;
+    let (sheets, date_1904) = read_workbook(&mut package, path)?;
+    let epoch_days = if date_1904 { EPOCH_1904_DAYS } else { EPOCH_1900_DAYS };
+
+    let shared_strings = match read_part(&mut package, "xl/sharedStrings.xml")? {
+        Some(xml) => read_shared_strings(&xml).map_err(|error| anyhow!("Invalid xl/sharedStrings.xml: {}", error))?,
+        None => Vec::new(),
+    };
+    // Without styles, dates show as numbers, which is no reason not to index the rest.
+    let date_styles = read_part(&mut package, "xl/styles.xml")
+        .ok()
+        .flatten()
+        .and_then(|xml| read_date_styles(&xml).ok())
+        .unwrap_or_default();
+    let mut properties = read_part(&mut package, "docProps/core.xml")
+        .ok()
+        .flatten()
+        .and_then(|xml| read_properties(&xml).ok())
+        .unwrap_or_default();
+
+    let (title, author) = (properties.remove("title"), properties.remove("creator"));
+    let metadata = file_metadata(path, &title, &author);
+    let mut documents = Vec::new();
+    for sheet in sheets {
+        let Some(xml) = read_part(&mut package, &sheet.part)? else { continue };
+        let rows = read_sheet_rows(&xml, &shared_strings, &date_styles, epoch_days)
+            .map_err(|error| anyhow!("Invalid sheet {}: {}", sheet.name, error))?;
+        row_documents(path, Some(&sheet.name), rows, &metadata, &mut documents);
+    }
+
+    Ok(ExtractedFile { path: metadata.path, title, author, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_column_references() {
+        assert_eq!(column_index("A1"), Some(0));
+        assert_eq!(column_index("c7"), Some(2));
+        assert_eq!(column_index("AA10"), Some(26));
+        assert_eq!(column_index("XFD1048576"), Some(16_383));
+        assert_eq!(column_index("7"), None);
+        assert_eq!(column_index(&"Z".repeat(40)), None);
+        assert_eq!(column_name(column_index("AZ3").unwrap()), "AZ");
+    }
+
+    #[test]
+    fn recognises_date_formats() {
+        assert!(is_date_format("dd/mm/yyyy"));
+        assert!(is_date_format("[$-409]h:mm AM/PM"));
+        assert!(!is_date_format("0.00"));
+        assert!(!is_date_format("\"days\" 0"));
+        assert!(!is_date_format("[Red]0"));
+    }
+
+    #[test]
+    fn formats_serial_dates() {
+        assert_eq!(format_date(45_382.0, EPOCH_1900_DAYS).as_deref(), Some("2024-03-31"));
+        assert_eq!(format_date(45_382.5, EPOCH_1900_DAYS).as_deref(), Some("2024-03-31 12:00"));
+        assert_eq!(format_date(0.25, EPOCH_1900_DAYS).as_deref(), Some("06:00"));
+        assert_eq!(format_date(43_920.0, EPOCH_1904_DAYS).as_deref(), Some("2024-03-31"));
+        assert_eq!(format_date(f64::NAN, EPOCH_1900_DAYS), None);
+    }
+}
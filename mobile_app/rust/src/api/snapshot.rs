@@ -0,0 +1,544 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use tantivy::Index;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::api::history::now;
+use crate::api::manifest::FILE_MANIFEST_FILE;
+use crate::api::migration::{copy_documents, replace_dir, sibling_dir, stored_schema_version, upgrade_index, SCHEMA_VERSION_FILE};
+use crate::api::tantivy::{close_tantivy, SCHEMA_VERSION};
+
+
+const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+const MANIFEST_ENTRY: &str = "manifest.json";
+const INDEX_PREFIX: &str = "index/";
+const EMBEDDINGS_PREFIX: &str = "embeddings/";
+const FILE_MANIFEST_ENTRY: &str = "files.json";
+const SETTINGS_ENTRY: &str = "settings.json";
+// A merge finishing while the index files are copied removes some of them, in which
+// case the copy starts over from the new commit.
+const MAX_EXPORT_ATTEMPTS: usize = 3;
+
+
+/// Locations of the data a snapshot is made of. Optional parts are left out of the
+/// snapshot, or not restored, when `None`.
+#[derive(Debug, Clone)]
+pub struct SnapshotPaths {
+    pub index_dir: String,
+    pub embedding_store_dir: Option<String>,
+    /// JSON file listing the indexed source files, `file_manifest.json` in the index
+    /// directory.
+    pub file_manifest_path: Option<String>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct SnapshotInfo {
+    pub schema_version: u32,
+    /// Seconds since the Unix epoch.
+    pub created_at: i64,
+    /// Root of the shared storage on the device the snapshot was taken on, e.g.
+    /// "/storage/emulated/0".
+    pub storage_root: String,
+    pub document_count: u64,
+    pub has_embeddings: bool,
+    pub has_file_manifest: bool,
+    /// App settings saved with the snapshot. On import, paths under the old storage root
+    /// are rewritten to the new one.
+    pub settings_json: Option<String>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SnapshotManifest {
+    format_version: u32,
+    schema_version: u32,
+    created_at: i64,
+    storage_root: String,
+    document_count: u64,
+    entries: Vec<SnapshotEntry>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SnapshotEntry {
+    name: String,
+    size: u64,
+}
+
+
+/// Rewrites `path` when it is `from` or inside it so that it points under `to` instead.
+fn remap_path(path: &str, from: &str, to: &str) -> String {
+    let from = from.trim_end_matches('/');
+    let to = to.trim_end_matches('/');
+    if from.is_empty() || from == to {
+        return path.to_string();
+    }
+
+    match path.strip_prefix(from) {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => format!("{}{}", to, rest),
+        _ => path.to_string(),
+    }
+}
+
+
+fn remap_json(value: &mut serde_json::Value, from: &str, to: &str) {
+    match value {
+        serde_json::Value::String(text) => *text = remap_path(text, from, to),
+        serde_json::Value::Array(values) => values.iter_mut().for_each(|value| remap_json(value, from, to)),
+        serde_json::Value::Object(map) => map.values_mut().for_each(|value| remap_json(value, from, to)),
+        _ => {}
+    }
+}
+
+
+fn remap_json_text(json: &str, from: &str, to: &str) -> Result<String> {
+    let mut value: serde_json::Value = serde_json::from_str(json)?;
+    remap_json(&mut value, from, to);
+    Ok(serde_json::to_string(&value)?)
+}
+
+
+fn file_options(size: u64, method: CompressionMethod) -> SimpleFileOptions {
+    SimpleFileOptions::default()
+        .compression_method(method)
+        .large_file(size >= u32::MAX as u64)
+}
+
+
+struct SnapshotWriter {
+    zip: ZipWriter<BufWriter<File>>,
+    entries: Vec<SnapshotEntry>,
+}
+
+
+impl SnapshotWriter {
+    fn add_bytes(&mut self, name: &str, bytes: &[u8]) -> Result<()> {
+        self.zip.start_file(name, file_options(bytes.len() as u64, CompressionMethod::Deflated))?;
+        self.zip.write_all(bytes)?;
+        self.entries.push(SnapshotEntry { name: name.to_string(), size: bytes.len() as u64 });
+        Ok(())
+    }
+
+    /// Index and embedding files are already compact, they are stored uncompressed to keep
+    /// exports fast on slow devices.
+    fn add_file(&mut self, name: &str, path: &Path) -> Result<()> {
+        let mut file = File::open(path)?;
+        let size = file.metadata()?.len();
+        self.zip.start_file(name, file_options(size, CompressionMethod::Stored))?;
+        std::io::copy(&mut file, &mut self.zip)?;
+        self.entries.push(SnapshotEntry { name: name.to_string(), size });
+        Ok(())
+    }
+
+    fn add_dir(&mut self, prefix: &str, dir: &Path) -> Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+            if entry.file_type()?.is_dir() {
+                self.add_dir(&format!("{}/", name), &entry.path())?;
+            } else {
+                self.add_file(&name, &entry.path())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+fn is_not_found(error: &anyhow::Error) -> bool {
+    error
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|error| error.kind() == ErrorKind::NotFound)
+}
+
+
+/// Writes the files of the last commit of the index, with a `meta.json` matching exactly
+/// the segments copied. Returns the schema version and the number of documents in that
+/// commit.
+fn add_index(writer: &mut SnapshotWriter, index_dir: &Path) -> Result<(u32, u64)> {
+    let schema_version = stored_schema_version(index_dir)?.ok_or_else(|| anyhow!("There is no index to export"))?;
+    let index = Index::open_in_dir(index_dir)?;
+    let metas = index.load_metas()?;
+
+    let mut document_count = 0;
+    for segment_meta in &metas.segments {
+        document_count += segment_meta.num_docs() as u64;
+        let mut files: Vec<PathBuf> = segment_meta.list_files().into_iter().collect();
+        files.sort();
+        for file in files {
+            let path = index_dir.join(&file);
+            // Segments without deletes have no delete file.
+            if file.extension().is_some_and(|extension| extension == "del") && !path.exists() {
+                continue;
+            }
+            writer.add_file(&format!("{}{}", INDEX_PREFIX, file.to_string_lossy()), &path)?;
+        }
+    }
+    writer.add_bytes(&format!("{}meta.json", INDEX_PREFIX), serde_json::to_string_pretty(&metas)?.as_bytes())?;
+    writer.add_bytes(&format!("{}{}", INDEX_PREFIX, SCHEMA_VERSION_FILE), schema_version.to_string().as_bytes())?;
+
+    Ok((schema_version, document_count))
+}
+
+
+fn write_snapshot(archive_path: &Path, paths: &SnapshotPaths, settings_json: Option<&str>, storage_root: &str) -> Result<SnapshotInfo> {
+    let mut writer = SnapshotWriter {
+        zip: ZipWriter::new(BufWriter::new(File::create(archive_path)?)),
+        entries: Vec::new(),
+    };
+
+    let (schema_version, document_count) = add_index(&mut writer, Path::new(&paths.index_dir))?;
+    let embedding_store_dir = paths.embedding_store_dir.as_ref().map(Path::new).filter(|dir| dir.exists());
+    if let Some(dir) = embedding_store_dir {
+        writer.add_dir(EMBEDDINGS_PREFIX, dir)?;
+    }
+    let file_manifest_path = paths.file_manifest_path.as_ref().map(Path::new).filter(|path| path.exists());
+    if let Some(path) = file_manifest_path {
+        writer.add_file(FILE_MANIFEST_ENTRY, path)?;
+    }
+    if let Some(settings_json) = settings_json {
+        serde_json::from_str::<serde_json::Value>(settings_json).map_err(|error| anyhow!("Invalid settings: {}", error))?;
+        writer.add_bytes(SETTINGS_ENTRY, settings_json.as_bytes())?;
+    }
+
+    let manifest = SnapshotManifest {
+        format_version: SNAPSHOT_FORMAT_VERSION,
+        schema_version,
+        created_at: now(),
+        storage_root: storage_root.to_string(),
+        document_count,
+        entries: std::mem::take(&mut writer.entries),
+    };
+    writer.add_bytes(MANIFEST_ENTRY, serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+    writer.zip.finish()?.flush()?;
+
+    Ok(SnapshotInfo {
+        schema_version: manifest.schema_version,
+        created_at: manifest.created_at,
+        storage_root: manifest.storage_root,
+        document_count,
+        has_embeddings: embedding_store_dir.is_some(),
+        has_file_manifest: file_manifest_path.is_some(),
+        settings_json: settings_json.map(str::to_string),
+    })
+}
+
+
+/// Writes the last commit of the index, the embedding store, the file manifest and the
+/// settings to a single archive at `archive_path`. Changes not committed yet are not part
+/// of the snapshot. Indexing can go on while the snapshot is written.
+pub fn export_snapshot(paths: SnapshotPaths, settings_json: Option<String>, storage_root: String, archive_path: String) -> Result<SnapshotInfo> {
+    let archive_path = PathBuf::from(archive_path);
+    let tmp_path = archive_path.with_extension("tmp");
+
+    let mut attempt = 1;
+    let info = loop {
+        match write_snapshot(&tmp_path, &paths, settings_json.as_deref(), &storage_root) {
+            Err(error) if is_not_found(&error) && attempt < MAX_EXPORT_ATTEMPTS => attempt += 1,
+            result => break result,
+        }
+    };
+    let info = info.inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp_path);
+    })?;
+    std::fs::rename(&tmp_path, &archive_path)?;
+
+    Ok(info)
+}
+
+
+fn open_archive(archive_path: &Path) -> Result<(ZipArchive<BufReader<File>>, SnapshotManifest)> {
+    let mut archive = ZipArchive::new(BufReader::new(File::open(archive_path)?))
+        .map_err(|error| anyhow!("Not a snapshot archive: {}", error))?;
+
+    let manifest: SnapshotManifest = {
+        let mut manifest_file = archive
+            .by_name(MANIFEST_ENTRY)
+            .map_err(|_| anyhow!("Not a snapshot archive: {} is missing", MANIFEST_ENTRY))?;
+        let mut manifest_json = String::new();
+        manifest_file.read_to_string(&mut manifest_json)?;
+        serde_json::from_str(&manifest_json)?
+    };
+
+    if manifest.format_version != SNAPSHOT_FORMAT_VERSION {
+        return Err(anyhow!("Unsupported snapshot format version {}", manifest.format_version));
+    }
+    if manifest.schema_version > SCHEMA_VERSION {
+        return Err(anyhow!("The snapshot was made by a newer version of the app (schema version {})", manifest.schema_version));
+    }
+    for entry in &manifest.entries {
+        let file = archive
+            .by_name(&entry.name)
+            .map_err(|_| anyhow!("Snapshot is incomplete: {} is missing", entry.name))?;
+        if file.size() != entry.size || file.enclosed_name().is_none() {
+            return Err(anyhow!("Snapshot is corrupted: unexpected entry {}", entry.name));
+        }
+    }
+
+    Ok((archive, manifest))
+}
+
+
+fn has_entry(manifest: &SnapshotManifest, name: &str) -> bool {
+    manifest.entries.iter().any(|entry| entry.name == name)
+}
+
+
+fn read_entry(archive: &mut ZipArchive<BufReader<File>>, name: &str) -> Result<String> {
+    let mut text = String::new();
+    archive.by_name(name)?.read_to_string(&mut text)?;
+    Ok(text)
+}
+
+
+/// Extracts the entries under `prefix` into `dir`. Reading an entry to its end checks its
+/// checksum, so a damaged archive fails here.
+fn extract_prefix(archive: &mut ZipArchive<BufReader<File>>, manifest: &SnapshotManifest, prefix: &str, dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for entry in manifest.entries.iter().filter(|entry| entry.name.starts_with(prefix)) {
+        let mut file = archive.by_name(&entry.name)?;
+        let relative_path = file
+            .enclosed_name()
+            .and_then(|path| path.strip_prefix(prefix).ok().map(Path::to_path_buf))
+            .ok_or_else(|| anyhow!("Snapshot is corrupted: unexpected entry {}", entry.name))?;
+
+        let path = dir.join(relative_path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::io::copy(&mut file, &mut BufWriter::new(File::create(&path)?))?;
+    }
+    Ok(())
+}
+
+
+fn has_embeddings(manifest: &SnapshotManifest) -> bool {
+    manifest.entries.iter().any(|entry| entry.name.starts_with(EMBEDDINGS_PREFIX))
+}
+
+
+fn snapshot_info(manifest: &SnapshotManifest, settings_json: Option<String>) -> SnapshotInfo {
+    SnapshotInfo {
+        schema_version: manifest.schema_version,
+        created_at: manifest.created_at,
+        storage_root: manifest.storage_root.clone(),
+        document_count: manifest.document_count,
+        has_embeddings: has_embeddings(manifest),
+        has_file_manifest: has_entry(manifest, FILE_MANIFEST_ENTRY),
+        settings_json,
+    }
+}
+
+
+/// Checks that `archive_path` is a complete snapshot this version of the app can import,
+/// without extracting it.
+pub fn read_snapshot_info(archive_path: String) -> Result<SnapshotInfo> {
+    let (mut archive, manifest) = open_archive(Path::new(&archive_path))?;
+    let settings_json = if has_entry(&manifest, SETTINGS_ENTRY) {
+        Some(read_entry(&mut archive, SETTINGS_ENTRY)?)
+    } else {
+        None
+    };
+
+    Ok(snapshot_info(&manifest, settings_json))
+}
+
+
+fn restore_index(archive: &mut ZipArchive<BufReader<File>>, manifest: &SnapshotManifest, index_dir: &Path, storage_root: &str) -> Result<()> {
+    let importing_dir = sibling_dir(index_dir, ".importing");
+    let remapping_dir = sibling_dir(index_dir, ".remapping");
+    for dir in [&importing_dir, &remapping_dir] {
+        if dir.exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+    }
+
+    extract_prefix(archive, manifest, INDEX_PREFIX, &importing_dir)?;
+    upgrade_index(&importing_dir)?;
+
+    let index = Index::open_in_dir(&importing_dir)?;
+    let document_count = index.reader()?.searcher().num_docs();
+    if document_count != manifest.document_count {
+        return Err(anyhow!("Snapshot is corrupted: expected {} documents, found {}", manifest.document_count, document_count));
+    }
+
+    if manifest.storage_root.trim_end_matches('/') != storage_root.trim_end_matches('/') {
+        std::fs::create_dir_all(&remapping_dir)?;
+        copy_documents(&index, &remapping_dir, |path| remap_path(path, &manifest.storage_root, storage_root))?;
+        drop(index);
+        std::fs::remove_dir_all(&importing_dir)?;
+        std::fs::rename(&remapping_dir, &importing_dir)?;
+    }
+
+    Ok(())
+}
+
+
+/// Restores a snapshot made by `export_snapshot`, replacing the data at `paths`. Stored
+/// paths under the storage root of the original device are rewritten to be under
+/// `storage_root`. The index is closed first and must be opened again with `init_tantivy`.
+/// Nothing is replaced when the archive fails validation.
+pub fn import_snapshot(archive_path: String, paths: SnapshotPaths, storage_root: String) -> Result<SnapshotInfo> {
+    let (mut archive, manifest) = open_archive(Path::new(&archive_path))?;
+    let index_dir = PathBuf::from(&paths.index_dir);
+    let importing_dir = sibling_dir(&index_dir, ".importing");
+
+    let restored = restore_index(&mut archive, &manifest, &index_dir, &storage_root);
+    if let Err(error) = restored {
+        let _ = std::fs::remove_dir_all(&importing_dir);
+        return Err(error);
+    }
+
+    let embedding_store_dir = paths.embedding_store_dir.as_ref().map(PathBuf::from).filter(|_| has_embeddings(&manifest));
+    let embeddings_importing_dir = embedding_store_dir.as_ref().map(|dir| sibling_dir(dir, ".importing"));
+    if let Some(dir) = &embeddings_importing_dir {
+        if dir.exists() {
+            std::fs::remove_dir_all(dir)?;
+        }
+        extract_prefix(&mut archive, &manifest, EMBEDDINGS_PREFIX, dir)?;
+    }
+
+    let file_manifest = match &paths.file_manifest_path {
+        Some(_) if has_entry(&manifest, FILE_MANIFEST_ENTRY) => {
+            let file_manifest = read_entry(&mut archive, FILE_MANIFEST_ENTRY)?;
+            Some(remap_json_text(&file_manifest, &manifest.storage_root, &storage_root)?)
+        }
+        _ => None,
+    };
+    let settings_json = if has_entry(&manifest, SETTINGS_ENTRY) {
+        let settings_json = read_entry(&mut archive, SETTINGS_ENTRY)?;
+        Some(remap_json_text(&settings_json, &manifest.storage_root, &storage_root)?)
+    } else {
+        None
+    };
+
+    // Everything has been validated and extracted, swap it in.
+    close_tantivy()?;
+    if paths.file_manifest_path.is_none() {
+        // The app keeps its file manifest in the index directory, see `FILE_MANIFEST_FILE`.
+        let file_manifest_path = index_dir.join(FILE_MANIFEST_FILE);
+        if file_manifest_path.exists() {
+            std::fs::copy(&file_manifest_path, importing_dir.join(FILE_MANIFEST_FILE))?;
+        }
+    }
+    replace_dir(&index_dir, &importing_dir)?;
+    if let (Some(dir), Some(importing_dir)) = (&embedding_store_dir, &embeddings_importing_dir) {
+        replace_dir(dir, importing_dir)?;
+    }
+    if let (Some(path), Some(file_manifest)) = (&paths.file_manifest_path, file_manifest) {
+        let tmp_path = PathBuf::from(format!("{}.tmp", path));
+        std::fs::write(&tmp_path, file_manifest)?;
+        std::fs::rename(&tmp_path, path)?;
+    }
+
+    Ok(snapshot_info(&manifest, settings_json))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::migration::write_schema_version;
+    use crate::api::tantivy::{build_schema, Document, DocumentMetadata, IndexFields};
+    use tantivy::schema::Value;
+    use tantivy::TantivyDocument;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("snapshot-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn stored_paths(index_dir: &Path) -> Vec<String> {
+        let index = Index::open_in_dir(index_dir).unwrap();
+        let path_field = index.schema().get_field("path").unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let mut paths = Vec::new();
+        for segment_reader in searcher.segment_readers() {
+            let store_reader = segment_reader.get_store_reader(1).unwrap();
+            for doc in store_reader.iter::<TantivyDocument>(segment_reader.alive_bitset()) {
+                let doc = doc.unwrap();
+                paths.push(doc.get_first(path_field).and_then(|value| value.as_str()).unwrap_or_default().to_string());
+            }
+        }
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn remaps_paths_under_the_old_root_only() {
+        let from = "/storage/emulated/0/";
+        let to = "/storage/emulated/10";
+
+        assert_eq!(remap_path("/storage/emulated/0/Download/a.pdf", from, to), "/storage/emulated/10/Download/a.pdf");
+        assert_eq!(remap_path("/storage/emulated/0", from, to), "/storage/emulated/10");
+        assert_eq!(remap_path("/storage/emulated/01/a.pdf", from, to), "/storage/emulated/01/a.pdf");
+        assert_eq!(remap_path("/sdcard/a.pdf", from, to), "/sdcard/a.pdf");
+        assert_eq!(remap_path("/storage/emulated/0/a.pdf", "", to), "/storage/emulated/0/a.pdf");
+    }
+
+    #[test]
+    fn export_and_import_round_trip() {
+        let dir = test_dir("round-trip");
+        let index_dir = dir.join("index");
+        std::fs::create_dir_all(&index_dir).unwrap();
+        let index = Index::create_in_dir(&index_dir, build_schema()).unwrap();
+        write_schema_version(&index_dir, SCHEMA_VERSION).unwrap();
+        let fields = IndexFields::resolve(&index.schema()).unwrap();
+        let mut writer = index.writer::<TantivyDocument>(15_000_000).unwrap();
+        for (id, path) in [("a", "/storage/emulated/0/Documents/a.pdf"), ("b", "/storage/emulated/0/b.txt")] {
+            let doc = Document { id: id.to_string(), text: format!("document {}", id) };
+            let metadata = DocumentMetadata { path: path.to_string(), ..DocumentMetadata::default() };
+            writer.add_document(fields.build_document(&doc, &metadata)).unwrap();
+        }
+        writer.commit().unwrap();
+        drop(writer);
+
+        let file_manifest_path = dir.join("files.json");
+        std::fs::write(&file_manifest_path, r#"[{"path":"/storage/emulated/0/b.txt"}]"#).unwrap();
+        let export_paths = SnapshotPaths {
+            index_dir: index_dir.to_string_lossy().to_string(),
+            embedding_store_dir: None,
+            file_manifest_path: Some(file_manifest_path.to_string_lossy().to_string()),
+        };
+        let archive_path = dir.join("snapshot.zip").to_string_lossy().to_string();
+        let settings = r#"{"folders":["/storage/emulated/0/Documents"]}"#;
+        let exported = export_snapshot(export_paths, Some(settings.to_string()), "/storage/emulated/0".to_string(), archive_path.clone()).unwrap();
+        assert_eq!(exported.document_count, 2);
+        assert!(exported.has_file_manifest);
+
+        let import_dir = dir.join("imported");
+        let import_paths = SnapshotPaths {
+            index_dir: import_dir.to_string_lossy().to_string(),
+            embedding_store_dir: None,
+            file_manifest_path: Some(dir.join("imported.json").to_string_lossy().to_string()),
+        };
+        let imported = import_snapshot(archive_path, import_paths, "/storage/emulated/10".to_string()).unwrap();
+
+        assert_eq!(imported.document_count, 2);
+        assert_eq!(imported.settings_json.as_deref(), Some(r#"{"folders":["/storage/emulated/10/Documents"]}"#));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("imported.json")).unwrap(),
+            r#"[{"path":"/storage/emulated/10/b.txt"}]"#,
+        );
+        assert_eq!(stored_paths(&import_dir), vec!["/storage/emulated/10/Documents/a.pdf", "/storage/emulated/10/b.txt"]);
+
+        // Without a file manifest path, the manifest kept in the index directory stays.
+        std::fs::write(import_dir.join(FILE_MANIFEST_FILE), "[]").unwrap();
+        let import_paths = SnapshotPaths {
+            index_dir: import_dir.to_string_lossy().to_string(),
+            embedding_store_dir: None,
+            file_manifest_path: None,
+        };
+        import_snapshot(dir.join("snapshot.zip").to_string_lossy().to_string(), import_paths, "/storage/emulated/10".to_string()).unwrap();
+        assert_eq!(std::fs::read_to_string(import_dir.join(FILE_MANIFEST_FILE)).unwrap(), "[]");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,11 +1,25 @@
 use anyhow::{anyhow, Result};
+use levenshtein_automata::{Distance, LevenshteinAutomatonBuilder, DFA};
 use once_cell::sync::Lazy;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use tantivy::collector::TopDocs;
-use tantivy::query::QueryParser;
+use tantivy::collector::{Count, DocSetCollector, FacetCollector, FacetCounts, TopDocs};
+use tantivy::indexer::{LogMergePolicy, NoMergePolicy};
+use tantivy::query::{AllQuery, BooleanQuery, Occur, Query as TantivyQuery, QueryParser, RangeQuery, RegexQuery, TermQuery};
 use tantivy::schema::*;
-use tantivy::{ Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+use tantivy::snippet::SnippetGenerator;
+use tantivy_fst::Automaton;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::api::archive::ARCHIVE_SEPARATOR;
+use crate::api::recovery::{open_index, quarantine, take_recovery_report, RecoveryReason, RecoveryReport};
+use crate::api::history::now;
+use crate::api::query::{to_tantivy_query, Query};
+use tantivy::tokenizer::TokenStream;
+use tantivy::{ DateTime, DocId, DocSet, Index, IndexReader, IndexWriter, ReloadPolicy, Score, Searcher, SegmentReader, TantivyDocument, Term, TERMINATED};
 
 
 #[derive(Debug, Clone)]
@@ -19,16 +33,1128 @@ pub struct Document {
 pub struct SearchResult {
     pub score: f32,
     pub doc: Document,
+    pub metadata: DocumentMetadata,
+}
+
+
+/// Where a document comes from. The extension and folder facets are derived from `path`.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentMetadata {
+    pub path: String,
+    /// Last modification time of the source file, in seconds since the Unix epoch.
+    pub modified: Option<i64>,
+    /// ISO 639 code of the document language, e.g. "yo" or "ha".
+    pub language: Option<String>,
+    /// Title of the book or file the document is part of.
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// Title of the chapter the document holds, for e-books.
+    pub chapter: Option<String>,
+    /// Position of the chapter in the book, starting at 1.
+    pub chapter_index: Option<u32>,
+    /// Headings of the section the document starts in and of those it contains.
+    pub headings: Vec<String>,
+    /// Name of the conversation a chat document comes from.
+    pub chat: Option<String>,
+    /// Authors of the messages of a chat document, or sender of an email.
+    pub senders: Vec<String>,
+    /// Time of the first message of a chat document or date of an email, in seconds since
+    /// the Unix epoch.
+    pub timestamp: Option<i64>,
+    /// Name of the sheet a spreadsheet document comes from.
+    pub sheet: Option<String>,
+    /// Number of the first row of a spreadsheet document, as shown by spreadsheet apps.
+    pub row: Option<u32>,
+    /// Start and end of a transcript segment in its recording, in milliseconds.
+    pub start_ms: Option<u64>,
+    pub end_ms: Option<u64>,
+    /// Subject of the email a document comes from.
+    pub subject: Option<String>,
+    /// Addresses an email was sent to, copies included.
+    pub recipients: Vec<String>,
+}
+
+
+/// A document and where it comes from, see `replace_file_documents` and
+/// `get_document_by_id`.
+#[derive(Debug, Clone)]
+pub struct DocumentWithMetadata {
+    pub doc: Document,
+    pub metadata: DocumentMetadata,
+}
+
+
+/// Restricts search results. Empty lists and `None` values do not filter anything.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    /// File extensions without the leading dot, e.g. "pdf". Matches any of them.
+    pub extensions: Vec<String>,
+    /// Only match documents located in this folder or one of its subfolders.
+    pub folder_prefix: Option<String>,
+    /// Inclusive lower bound on the modification time, in seconds since the Unix epoch.
+    pub modified_after: Option<i64>,
+    /// Exclusive upper bound on the modification time, in seconds since the Unix epoch.
+    pub modified_before: Option<i64>,
+    /// Language codes. Matches any of them.
+    pub languages: Vec<String>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: u64,
+}
+
+
+/// Number of matching documents per filter value, used to render filter chips.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFacets {
+    pub extensions: Vec<FacetCount>,
+    /// Full paths of the direct subfolders of the `folder_prefix` filter, or of the root
+    /// when it is unset.
+    pub folders: Vec<FacetCount>,
+    /// Years of the modification time.
+    pub years: Vec<FacetCount>,
+    pub languages: Vec<FacetCount>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// Number of hits per page. Only the total and the facets are returned when it is 0.
+    pub top_k: usize,
+    /// Number of hits to skip, a multiple of `top_k` when paging.
+    pub offset: usize,
+    pub filter: SearchFilter,
+    /// Maximum number of snippets returned per hit.
+    pub max_snippets: usize,
+    /// Maximum length of a snippet, in characters.
+    pub snippet_max_chars: usize,
+    /// Commit generation the results must reflect, see `commit_generation`.
+    pub min_generation: u64,
+    /// Maximum edit distance used to expand query terms, at most 2. Fuzzy matching is
+    /// disabled when it is 0.
+    pub fuzzy_distance: u8,
+    /// Spelling suggestions are returned when there are fewer hits than this.
+    pub suggest_below_hits: usize,
+}
+
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            top_k: 10,
+            offset: 0,
+            filter: SearchFilter::default(),
+            max_snippets: 1,
+            snippet_max_chars: 150,
+            min_generation: 0,
+            fuzzy_distance: 0,
+            suggest_below_hits: 3,
+        }
+    }
+}
+
+
+/// Range of a matched term within a snippet, in UTF-16 code units so that it can be
+/// used directly with Dart `String` indices.
+#[derive(Debug, Clone)]
+pub struct HighlightRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    pub fragment: String,
+    /// Offset of the fragment within the document text, in UTF-16 code units.
+    pub offset: usize,
+    pub highlights: Vec<HighlightRange>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub score: f32,
+    pub doc: Document,
+    pub metadata: DocumentMetadata,
+    pub snippets: Vec<Snippet>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct SearchResponse {
+    pub hits: Vec<SearchHit>,
+    /// Number of documents matching the query and filters, across all pages.
+    pub total_hits: usize,
+    pub facets: SearchFacets,
+    /// "Did you mean" queries built from terms present in the index, best first.
+    pub suggestions: Vec<String>,
+    /// Parts of a query string that could not be parsed and were ignored.
+    pub query_warnings: Vec<String>,
+}
+
+
+/// Settings of the merge policy used when committing. Fewer, larger segments make searches
+/// faster at the cost of more background merging.
+#[derive(Debug, Clone)]
+pub struct MergePolicyOptions {
+    /// Segments are not merged at all when disabled, e.g. while on battery.
+    pub enabled: bool,
+    /// Number of segments of similar size that triggers a merge.
+    pub min_num_segments: usize,
+    /// Segments holding more documents than this are never merged.
+    pub max_docs_before_merge: usize,
+    /// Ratio of deleted documents above which a segment is merged on its own to reclaim
+    /// space, between 0 and 1.
+    pub deleted_ratio_before_merge: f32,
+}
+
+
+impl Default for MergePolicyOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_num_segments: 8,
+            max_docs_before_merge: 10_000_000,
+            deleted_ratio_before_merge: 1.0,
+        }
+    }
+}
+
+
+/// State of the index files, used to decide when maintenance is worth running.
+#[derive(Debug, Clone)]
+pub struct IndexHealth {
+    pub segment_count: usize,
+    pub num_docs: u64,
+    pub num_deleted_docs: u64,
+    /// Deleted documents over all stored documents, between 0 and 1.
+    pub deleted_ratio: f64,
+    pub disk_bytes: u64,
+}
+
+
+/// Overview of the index shown on the settings screen.
+#[derive(Debug, Clone)]
+pub struct IndexStats {
+    /// Indexed documents, each one a chunk of a source file such as a PDF page.
+    pub chunk_count: u64,
+    pub file_count: u64,
+    /// Chunks per file extension.
+    pub extensions: Vec<FacetCount>,
+    /// Chunks per language.
+    pub languages: Vec<FacetCount>,
+    pub segment_count: usize,
+    pub deleted_count: u64,
+    pub index_bytes: u64,
+    pub embedding_store_bytes: u64,
+    /// Time of the last commit in seconds since the Unix epoch, `None` before the first one.
+    pub last_commit: Option<i64>,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct DeleteReport {
+    pub deleted_count: u64,
+    /// Ids of the deleted chunks. This crate keeps no embedding store, so nothing else is
+    /// deleted here: the app deletes the embeddings it keeps for these ids.
+    pub deleted_ids: Vec<String>,
+    /// False when the delete waits for the next `commit`, see `delete_by_query`.
+    pub committed: bool,
+}
+
+
+/// Fields of the current schema, see `build_schema`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IndexFields {
+    pub(crate) id: Field,
+    pub(crate) text: Field,
+    pub(crate) path: Field,
+    pub(crate) folder: Field,
+    pub(crate) extension: Field,
+    pub(crate) language: Field,
+    pub(crate) modified: Field,
+    // Modification time as a "/<year>/<month>" facet.
+    pub(crate) date: Field,
+    pub(crate) title: Field,
+    pub(crate) author: Field,
+    pub(crate) chapter: Field,
+    pub(crate) chapter_index: Field,
+    pub(crate) headings: Field,
+    pub(crate) chat: Field,
+    pub(crate) sender: Field,
+    pub(crate) timestamp: Field,
+    pub(crate) sheet: Field,
+    pub(crate) row: Field,
+    pub(crate) start_ms: Field,
+    pub(crate) end_ms: Field,
+    pub(crate) subject: Field,
+    pub(crate) recipient: Field,
+}
+
+
+impl IndexFields {
+    pub(crate) fn resolve(schema: &Schema) -> Result<Self> {
+        let get_field = |name: &str| {
+            schema.get_field(name).map_err(|_| anyhow!("'{}' field not found", name))
+        };
+
+        Ok(Self {
+            id: get_field("id")?,
+            text: get_field("text")?,
+            path: get_field("path")?,
+            folder: get_field("folder")?,
+            extension: get_field("extension")?,
+            language: get_field("language")?,
+            modified: get_field("modified")?,
+            date: get_field("date")?,
+            title: get_field("title")?,
+            author: get_field("author")?,
+            chapter: get_field("chapter")?,
+            chapter_index: get_field("chapter_index")?,
+            headings: get_field("headings")?,
+            chat: get_field("chat")?,
+            sender: get_field("sender")?,
+            timestamp: get_field("timestamp")?,
+            sheet: get_field("sheet")?,
+            row: get_field("row")?,
+            start_ms: get_field("start_ms")?,
+            end_ms: get_field("end_ms")?,
+            subject: get_field("subject")?,
+            recipient: get_field("recipient")?,
+        })
+    }
+
+    pub(crate) fn build_document(&self, doc: &Document, metadata: &DocumentMetadata) -> TantivyDocument {
+        let mut tantivy_doc = TantivyDocument::new();
+        tantivy_doc.add_text(self.id, &doc.id);
+        tantivy_doc.add_text(self.text, &doc.text);
+
+        if !metadata.path.is_empty() {
+            let path = Path::new(&metadata.path);
+            tantivy_doc.add_text(self.path, &metadata.path);
+            if let Some(folder) = path.parent() {
+                tantivy_doc.add_facet(self.folder, folder_facet(&folder.to_string_lossy()));
+            }
+            if let Some(extension) = path.extension() {
+                let extension = extension.to_string_lossy().to_lowercase();
+                tantivy_doc.add_facet(self.extension, Facet::from_path([extension]));
+            }
+        }
+
+        if let Some(language) = &metadata.language {
+            tantivy_doc.add_facet(self.language, Facet::from_path([language.to_lowercase()]));
+        }
+
+        if let Some(modified) = metadata.modified {
+            let modified = DateTime::from_timestamp_secs(modified);
+            let utc = modified.into_utc();
+            tantivy_doc.add_date(self.modified, modified);
+            tantivy_doc.add_facet(
+                self.date,
+                Facet::from_path([utc.year().to_string(), format!("{:02}", utc.month() as u8)]),
+            );
+        }
+
+        let texts = [
+            (self.title, &metadata.title),
+            (self.author, &metadata.author),
+            (self.chapter, &metadata.chapter),
+            (self.chat, &metadata.chat),
+            (self.sheet, &metadata.sheet),
+            (self.subject, &metadata.subject),
+        ];
+        for (field, value) in texts {
+            if let Some(value) = value {
+                tantivy_doc.add_text(field, value);
+            }
+        }
+        if let Some(chapter_index) = metadata.chapter_index {
+            tantivy_doc.add_u64(self.chapter_index, chapter_index as u64);
+        }
+        for heading in &metadata.headings {
+            tantivy_doc.add_text(self.headings, heading);
+        }
+        for sender in &metadata.senders {
+            tantivy_doc.add_text(self.sender, sender);
+        }
+        for recipient in &metadata.recipients {
+            tantivy_doc.add_text(self.recipient, recipient);
+        }
+        if let Some(timestamp) = metadata.timestamp {
+            tantivy_doc.add_date(self.timestamp, DateTime::from_timestamp_secs(timestamp));
+        }
+        if let Some(row) = metadata.row {
+            tantivy_doc.add_u64(self.row, row as u64);
+        }
+        for (field, value) in [(self.start_ms, metadata.start_ms), (self.end_ms, metadata.end_ms)] {
+            if let Some(value) = value {
+                tantivy_doc.add_u64(field, value);
+            }
+        }
+
+        tantivy_doc
+    }
+
+    /// Reads the metadata stored with a document, see `build_document`.
+    fn read_metadata(&self, doc: &TantivyDocument) -> DocumentMetadata {
+        let text_value = |field: Field| doc.get_first(field).and_then(|value| value.as_str()).map(str::to_string);
+
+        DocumentMetadata {
+            path: text_value(self.path).unwrap_or_default(),
+            modified: doc
+                .get_first(self.modified)
+                .and_then(|value| value.as_datetime())
+                .map(|modified| modified.into_timestamp_secs()),
+            language: doc
+                .get_first(self.language)
+                .and_then(|value| value.as_facet())
+                .and_then(|encoded| Facet::from_encoded(encoded.as_bytes().to_vec()).ok())
+                .and_then(|facet| facet.to_path().last().map(|language| language.to_string())),
+            title: text_value(self.title),
+            author: text_value(self.author),
+            chapter: text_value(self.chapter),
+            chapter_index: doc
+                .get_first(self.chapter_index)
+                .and_then(|value| value.as_u64())
+                .map(|chapter_index| chapter_index as u32),
+            headings: doc
+                .get_all(self.headings)
+                .filter_map(|value| value.as_str())
+                .map(str::to_string)
+                .collect(),
+            chat: text_value(self.chat),
+            senders: doc
+                .get_all(self.sender)
+                .filter_map(|value| value.as_str())
+                .map(str::to_string)
+                .collect(),
+            timestamp: doc
+                .get_first(self.timestamp)
+                .and_then(|value| value.as_datetime())
+                .map(|timestamp| timestamp.into_timestamp_secs()),
+            sheet: text_value(self.sheet),
+            row: doc
+                .get_first(self.row)
+                .and_then(|value| value.as_u64())
+                .map(|row| row as u32),
+            start_ms: doc.get_first(self.start_ms).and_then(|value| value.as_u64()),
+            end_ms: doc.get_first(self.end_ms).and_then(|value| value.as_u64()),
+            subject: text_value(self.subject),
+            recipients: doc
+                .get_all(self.recipient)
+                .filter_map(|value| value.as_str())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
 }
 
 
 struct TantivyApi {
+    dir: PathBuf,
     index: Index,
     writer: Mutex<IndexWriter>,
     reader: IndexReader,
-    schema: Schema,
-    id_field: Field,
-    text_field: Field,
+    fields: IndexFields,
+    // Number of commits made since the index was opened.
+    commit_generation: AtomicU64,
+    // Whether writes made by the `_no_commit` functions wait for `commit`.
+    pending_writes: AtomicBool,
+    // Source file count and the commit generation it was counted at, see `source_file_count`.
+    file_count: Mutex<Option<(u64, u64)>>,
+}
+
+
+impl TantivyApi {
+    fn new(dir: PathBuf, index: Index) -> Result<Self> {
+        let fields = IndexFields::resolve(&index.schema())?;
+
+        let writer = index.writer(50_000_000)?;
+        let reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()?;
+
+        Ok(Self {
+            dir,
+            index,
+            writer: Mutex::new(writer),
+            reader,
+            fields,
+            commit_generation: AtomicU64::new(0),
+            pending_writes: AtomicBool::new(false),
+            file_count: Mutex::new(None),
+        })
+    }
+
+    /// Combines the user query with one sub-query per active filter.
+    fn filtered_query(&self, query: Box<dyn TantivyQuery>, filter: &SearchFilter) -> Box<dyn TantivyQuery> {
+        let mut clauses: Vec<(Occur, Box<dyn TantivyQuery>)> = vec![(Occur::Must, query)];
+
+        if !filter.extensions.is_empty() {
+            let facets = filter.extensions
+                .iter()
+                .map(|extension| Facet::from_path([extension.trim_start_matches('.').to_lowercase()]));
+            clauses.push((Occur::Must, any_facet_query(self.fields.extension, facets)));
+        }
+
+        if !filter.languages.is_empty() {
+            let facets = filter.languages
+                .iter()
+                .map(|language| Facet::from_path([language.to_lowercase()]));
+            clauses.push((Occur::Must, any_facet_query(self.fields.language, facets)));
+        }
+
+        if let Some(folder) = &filter.folder_prefix {
+            let term = Term::from_facet(self.fields.folder, &folder_facet(folder));
+            clauses.push((Occur::Must, Box::new(TermQuery::new(term, IndexRecordOption::Basic))));
+        }
+
+        if filter.modified_after.is_some() || filter.modified_before.is_some() {
+            let bound = |secs: i64| Term::from_field_date_for_search(self.fields.modified, DateTime::from_timestamp_secs(secs));
+            let lower = filter.modified_after.map_or(Bound::Unbounded, |secs| Bound::Included(bound(secs)));
+            let upper = filter.modified_before.map_or(Bound::Unbounded, |secs| Bound::Excluded(bound(secs)));
+            clauses.push((Occur::Must, Box::new(RangeQuery::new(lower, upper))));
+        }
+
+        if clauses.len() == 1 {
+            return clauses.pop().unwrap().1;
+        }
+        Box::new(BooleanQuery::new(clauses))
+    }
+
+    fn facet_collector(&self, field: Field, parent: &Facet) -> FacetCollector {
+        let mut collector = FacetCollector::for_field(self.index.schema().get_field_name(field));
+        collector.add_facet(parent.clone());
+        collector
+    }
+
+    /// Parses `query` with the tantivy query syntax and runs it, see `search`.
+    fn search_text(&self, query: &str, options: &SearchOptions) -> Result<SearchResponse> {
+        let fuzzy_distance = options.fuzzy_distance.min(MAX_EDIT_DISTANCE);
+        let mut query_parser = QueryParser::for_index(&self.index, vec![self.fields.text]);
+        if fuzzy_distance > 0 {
+            query_parser.set_field_fuzzy(self.fields.text, false, fuzzy_distance, true);
+        }
+        let (mut parsed_query, errors) = query_parser.parse_query_lenient(query);
+        let query_warnings = errors.iter().map(|error| error.to_string()).collect();
+
+        // What tantivy salvages from a malformed query, e.g. an unterminated phrase, rarely
+        // matches what was meant. Pasted text is better served by searching its plain words.
+        let mut parsed_text = query.to_string();
+        if !errors.is_empty() {
+            parsed_text = strip_query_syntax(query);
+            parsed_query = query_parser.parse_query_lenient(&parsed_text).0;
+        }
+
+        let highlight_query = if fuzzy_distance > 0 {
+            QueryParser::for_index(&self.index, vec![self.fields.text]).parse_query_lenient(&parsed_text).0
+        } else {
+            parsed_query.box_clone()
+        };
+        self.execute_search(parsed_query, &*highlight_query, query, query_warnings, options)
+    }
+
+    /// Runs `parsed_query` with the filters, facets, snippets and suggestions requested by
+    /// `options`. Snippets highlight the terms of `highlight_query`, the same query without
+    /// fuzzy matching. `query_text` is what the user typed, used for spelling suggestions.
+    fn execute_search(
+        &self,
+        parsed_query: Box<dyn TantivyQuery>,
+        highlight_query: &dyn TantivyQuery,
+        query_text: &str,
+        query_warnings: Vec<String>,
+        options: &SearchOptions,
+    ) -> Result<SearchResponse> {
+        let searcher = self.searcher(options.min_generation)?;
+        let parsed_query = self.filtered_query(parsed_query, &options.filter);
+
+        let folder_root = options.filter.folder_prefix
+            .as_deref()
+            .map_or_else(Facet::root, folder_facet);
+        // `TopDocs` panics on a limit of 0, asked for when only the counts are wanted.
+        let top_docs_collector = (options.top_k > 0).then(|| TopDocs::with_limit(options.top_k).and_offset(options.offset));
+        let collectors = (
+            top_docs_collector,
+            Count,
+            (
+                self.facet_collector(self.fields.extension, &Facet::root()),
+                self.facet_collector(self.fields.folder, &folder_root),
+                self.facet_collector(self.fields.date, &Facet::root()),
+                self.facet_collector(self.fields.language, &Facet::root()),
+            ),
+        );
+        let (top_docs, total_hits, (extension_counts, folder_counts, date_counts, language_counts)) = searcher.search(&parsed_query, &collectors)?;
+
+        let facets = SearchFacets {
+            extensions: facet_counts(&extension_counts, &Facet::root(), false),
+            folders: facet_counts(&folder_counts, &folder_root, true),
+            years: facet_counts(&date_counts, &Facet::root(), false),
+            languages: facet_counts(&language_counts, &Facet::root(), false),
+        };
+
+        // Building the generator looks the query terms up, wasted when no snippet is wanted.
+        let snippet_generator = if options.max_snippets > 0 {
+            let fuzzy_distance = options.fuzzy_distance.min(MAX_EDIT_DISTANCE);
+            Some(self.snippet_generator(&searcher, highlight_query, fuzzy_distance, options.snippet_max_chars)?)
+        } else {
+            None
+        };
+
+        let mut hits = Vec::new();
+        for (score, doc_address) in top_docs.unwrap_or_default() {
+            let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
+            let id = retrieved_doc.get_first(self.fields.id)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let text = retrieved_doc.get_first(self.fields.text)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+
+            let snippets = snippet_generator
+                .as_ref()
+                .map(|generator| generate_snippets(generator, &text, options.max_snippets))
+                .unwrap_or_default();
+
+            hits.push(SearchHit {
+                score,
+                doc: Document { id, text },
+                metadata: self.fields.read_metadata(&retrieved_doc),
+                snippets,
+            });
+        }
+
+        let suggestions = if total_hits < options.suggest_below_hits {
+            self.spelling_suggestions(&searcher, query_text)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(SearchResponse { hits, total_hits, facets, suggestions, query_warnings })
+    }
+
+    /// Builds "did you mean" queries by replacing every query term that is not in the index
+    /// with close terms from the term dictionary, preferring the most frequent ones.
+    fn spelling_suggestions(&self, searcher: &Searcher, query: &str) -> Result<Vec<String>> {
+        let mut tokenizer = self.index.tokenizer_for_field(self.fields.text)?;
+        let mut token_stream = tokenizer.token_stream(query);
+        let mut tokens = Vec::new();
+        while token_stream.advance() {
+            tokens.push(token_stream.token().text.clone());
+        }
+
+        let mut corrected = false;
+        let mut candidates_per_token = Vec::new();
+        for token in &tokens {
+            let term = Term::from_field_text(self.fields.text, token);
+            if searcher.doc_freq(&term)? > 0 {
+                candidates_per_token.push(vec![token.clone()]);
+                continue;
+            }
+
+            let candidates = self.similar_terms(searcher, token)?;
+            if candidates.is_empty() {
+                candidates_per_token.push(vec![token.clone()]);
+            } else {
+                corrected = true;
+                candidates_per_token.push(candidates);
+            }
+        }
+
+        if !corrected {
+            return Ok(Vec::new());
+        }
+
+        // The best candidate for every term first, then one alternative term at a time.
+        let best: Vec<&str> = candidates_per_token.iter().map(|candidates| candidates[0].as_str()).collect();
+        let mut suggestions = vec![best.join(" ")];
+        for (position, candidates) in candidates_per_token.iter().enumerate() {
+            for candidate in candidates.iter().skip(1) {
+                if suggestions.len() >= MAX_SUGGESTIONS {
+                    return Ok(suggestions);
+                }
+                let mut words = best.clone();
+                words[position] = candidate;
+                suggestions.push(words.join(" "));
+            }
+        }
+
+        Ok(suggestions)
+    }
+
+    /// Terms of the text field within `MAX_EDIT_DISTANCE` of `token`, closest and most
+    /// frequent first.
+    fn similar_terms(&self, searcher: &Searcher, token: &str) -> Result<Vec<String>> {
+        // Short words have too many neighbours at distance 2 to be useful.
+        let distance = if token.chars().count() <= 4 { 1 } else { MAX_EDIT_DISTANCE };
+        let mut candidates = self.terms_within(searcher, token, distance)?;
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)).then_with(|| a.2.cmp(&b.2)));
+
+        Ok(candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, _, term)| term)
+            .collect())
+    }
+
+    /// Terms of the text field at most `distance` edits away from `token`, as (edit distance,
+    /// document frequency, term).
+    fn terms_within(&self, searcher: &Searcher, token: &str, distance: u8) -> Result<Vec<(u8, u64, String)>> {
+        let dfa = LEVENSHTEIN_BUILDERS[distance as usize - 1].build_dfa(token);
+
+        let mut doc_freqs: HashMap<String, u64> = HashMap::new();
+        for segment_reader in searcher.segment_readers() {
+            let inverted_index = segment_reader.inverted_index(self.fields.text)?;
+            let mut stream = inverted_index.terms().search(DfaWrapper(&dfa)).into_stream()?;
+            while stream.advance() {
+                if let Ok(term) = std::str::from_utf8(stream.key()) {
+                    *doc_freqs.entry(term.to_string()).or_default() += stream.value().doc_freq as u64;
+                }
+            }
+        }
+
+        Ok(doc_freqs
+            .into_iter()
+            .map(|(term, doc_freq)| (dfa_distance(&dfa, &term), doc_freq, term))
+            .collect())
+    }
+
+    /// Highlights the terms of `query` and, when matching fuzzily, the indexed terms within
+    /// `fuzzy_distance` edits of them. `SnippetGenerator::create` cannot be used for fuzzy
+    /// queries, which report no terms.
+    fn snippet_generator(
+        &self,
+        searcher: &Searcher,
+        query: &dyn TantivyQuery,
+        fuzzy_distance: u8,
+        max_chars: usize,
+    ) -> Result<SnippetGenerator> {
+        let mut tokens = BTreeSet::new();
+        query.query_terms(&mut |term, _| {
+            if let Some(token) = term.value().as_str().filter(|_| term.field() == self.fields.text) {
+                tokens.insert(token.to_string());
+            }
+        });
+
+        let mut terms_text = BTreeMap::new();
+        for token in tokens {
+            let matches = if fuzzy_distance > 0 {
+                self.terms_within(searcher, &token, fuzzy_distance)?
+            } else {
+                let doc_freq = searcher.doc_freq(&Term::from_field_text(self.fields.text, &token))?;
+                vec![(0, doc_freq, token)]
+            };
+            // Rare terms make the best snippets, as with `SnippetGenerator::create`.
+            for (_, doc_freq, term) in matches.into_iter().filter(|(_, doc_freq, _)| *doc_freq > 0) {
+                terms_text.insert(term, 1.0 / (1.0 + doc_freq as Score));
+            }
+        }
+
+        let tokenizer = self.index.tokenizer_for_field(self.fields.text)?;
+        Ok(SnippetGenerator::new(terms_text, tokenizer, self.fields.text, max_chars))
+    }
+
+    /// Commits pending writes and reloads the reader, so that searches see them as soon as
+    /// this returns. Returns the new commit generation.
+    fn commit(&self, writer: &mut IndexWriter) -> Result<u64> {
+        // The commit time is kept in the commit payload, see `IndexStats::last_commit`.
+        let mut prepared_commit = writer.prepare_commit()?;
+        prepared_commit.set_payload(&now().to_string());
+        prepared_commit.commit()?;
+        self.pending_writes.store(false, Ordering::SeqCst);
+        self.reader.reload()?;
+        Ok(self.commit_generation.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    /// Returns a searcher, failing when `min_generation` has not been committed yet. The
+    /// reader is reloaded by `commit`, so the searcher reflects every commit made.
+    fn searcher(&self, min_generation: u64) -> Result<Searcher> {
+        let committed = self.commit_generation.load(Ordering::SeqCst);
+        if min_generation > committed {
+            return Err(anyhow!("Commit generation {} has not been reached yet (current: {})", min_generation, committed));
+        }
+
+        Ok(self.reader.searcher())
+    }
+
+    /// Deletes the documents matching `query`. The delete is committed right away, unless
+    /// writes made by the `_no_commit` functions are pending: it is then left for their
+    /// `commit`, so that their batch is not committed halfway.
+    fn delete_matching(&self, query: Box<dyn TantivyQuery>) -> Result<DeleteReport> {
+        let mut writer = self.writer.lock().unwrap();
+
+        // Matching chunks added but not committed yet are deleted too, but cannot be listed.
+        let searcher = self.searcher(0)?;
+        let mut doc_addresses: Vec<_> = searcher.search(&query, &DocSetCollector)?.into_iter().collect();
+        doc_addresses.sort();
+        let mut deleted_ids = Vec::with_capacity(doc_addresses.len());
+        for doc_address in doc_addresses {
+            let doc: TantivyDocument = searcher.doc(doc_address)?;
+            if let Some(id) = doc.get_first(self.fields.id).and_then(|value| value.as_str()) {
+                deleted_ids.push(id.to_string());
+            }
+        }
+
+        writer.delete_query(query)?;
+        let committed = !self.pending_writes.load(Ordering::SeqCst);
+        if committed {
+            self.commit(&mut writer)?;
+        }
+
+        Ok(DeleteReport {
+            deleted_count: deleted_ids.len() as u64,
+            deleted_ids,
+            committed,
+        })
+    }
+
+    /// See `delete_by_path`.
+    fn delete_path(&self, path: &str) -> Result<DeleteReport> {
+        self.delete_matching(self.path_query(path)?)
+    }
+
+    /// Deletes the chunks of every file in `paths`, as `delete_path` does, and the chunks
+    /// with an id in `ids`, in a single commit.
+    fn delete_files(&self, paths: &[String], ids: &[String]) -> Result<DeleteReport> {
+        if paths.is_empty() && ids.is_empty() {
+            return Ok(DeleteReport { deleted_count: 0, deleted_ids: vec![], committed: false });
+        }
+
+        let mut clauses = Vec::with_capacity(paths.len() + ids.len());
+        for path in paths {
+            clauses.push((Occur::Should, self.path_query(path)?));
+        }
+        for id in ids {
+            let id_query: Box<dyn TantivyQuery> = Box::new(TermQuery::new(Term::from_field_text(self.fields.id, id), IndexRecordOption::Basic));
+            clauses.push((Occur::Should, id_query));
+        }
+
+        self.delete_matching(Box::new(BooleanQuery::new(clauses)))
+    }
+
+    /// Matches the chunks of the file at `path`, see `delete_by_path`.
+    fn path_query(&self, path: &str) -> Result<Box<dyn TantivyQuery>> {
+        let path_query: Box<dyn TantivyQuery> = Box::new(TermQuery::new(
+            Term::from_field_text(self.fields.path, path),
+            IndexRecordOption::Basic,
+        ));
+        let mut clauses = vec![(Occur::Should, path_query)];
+
+        // Legacy chunks have no path, only the file name in their id. They could belong to
+        // any file of that name, so they are left alone when another one is indexed.
+        let file_name = path.rsplit('/').next().unwrap_or_default();
+        let same_name_query = BooleanQuery::new(vec![
+            (Occur::Must, Box::new(RegexQuery::from_pattern(&format!(".*/{}", escape_regex(file_name)), self.fields.path)?) as Box<dyn TantivyQuery>),
+            (Occur::MustNot, Box::new(TermQuery::new(Term::from_field_text(self.fields.path, path), IndexRecordOption::Basic))),
+        ]);
+        if !file_name.is_empty() && self.searcher(0)?.search(&same_name_query, &Count)? == 0 {
+            let legacy_query = BooleanQuery::new(vec![
+                (Occur::Must, Box::new(RegexQuery::from_pattern(&format!("{}-[0-9]+", escape_regex(file_name)), self.fields.id)?) as Box<dyn TantivyQuery>),
+                (Occur::MustNot, Box::new(RegexQuery::from_pattern(".+", self.fields.path)?)),
+            ]);
+            clauses.push((Occur::Should, Box::new(legacy_query)));
+        }
+        clauses.push((Occur::Should, Box::new(self.archive_entries_query(path)?)));
+
+        Ok(Box::new(BooleanQuery::new(clauses)))
+    }
+
+    /// Matches the chunks of the files inside the archive at `path`, if it is one.
+    fn archive_entries_query(&self, path: &str) -> Result<RegexQuery> {
+        let pattern = format!("{}.*", escape_regex(&format!("{}{}", path, ARCHIVE_SEPARATOR)));
+        Ok(RegexQuery::from_pattern(&pattern, self.fields.path)?)
+    }
+
+    /// See `delete_by_folder`.
+    fn delete_folder(&self, folder: &str) -> Result<DeleteReport> {
+        let facet = folder_facet(folder);
+        if facet.is_root() {
+            return Err(anyhow!("Refusing to delete the whole index, use a folder other than the root"));
+        }
+        let term = Term::from_facet(self.fields.folder, &facet);
+
+        self.delete_matching(Box::new(TermQuery::new(term, IndexRecordOption::Basic)))
+    }
+
+    /// See `delete_by_query`.
+    fn delete_text_query(&self, query: &str) -> Result<DeleteReport> {
+        let query_parser = QueryParser::for_index(&self.index, vec![self.fields.text]);
+        let parsed_query = query_parser.parse_query(query)?;
+
+        self.delete_matching(parsed_query)
+    }
+
+    /// Counts the distinct source files of the live documents, from their path or, for
+    /// documents indexed without metadata, from their legacy "<fileName>-<page>" id.
+    fn count_source_files(&self, searcher: &Searcher) -> Result<u64> {
+        let mut files = HashSet::new();
+        for segment_reader in searcher.segment_readers() {
+            let is_alive = |doc: DocId| segment_reader.alive_bitset().is_none_or(|alive_bitset| alive_bitset.is_alive(doc));
+
+            let mut has_path = vec![false; segment_reader.max_doc() as usize];
+            for_each_term_doc(segment_reader, self.fields.path, |path, doc| {
+                if is_alive(doc) {
+                    has_path[doc as usize] = true;
+                    files.insert(path.to_string());
+                }
+            })?;
+            for_each_term_doc(segment_reader, self.fields.id, |id, doc| {
+                if is_alive(doc) && !has_path[doc as usize] {
+                    files.insert(legacy_file_name(id).to_string());
+                }
+            })?;
+        }
+        Ok(files.len() as u64)
+    }
+
+    /// `count_source_files`, cached until the next commit as it reads every path and id term.
+    fn source_file_count(&self, searcher: &Searcher) -> Result<u64> {
+        let generation = self.commit_generation.load(Ordering::SeqCst);
+        let mut file_count = self.file_count.lock().unwrap();
+        if let Some((counted_generation, count)) = *file_count {
+            if counted_generation == generation {
+                return Ok(count);
+            }
+        }
+
+        let count = self.count_source_files(searcher)?;
+        *file_count = Some((generation, count));
+        Ok(count)
+    }
+
+    /// Index statistics. `index_bytes` and `embedding_store_bytes` are left at 0, `index_stats`
+    /// measures them without holding the state lock.
+    fn stats(&self) -> Result<IndexStats> {
+        let health = self.health()?;
+        let searcher = self.searcher(0)?;
+        let (extension_counts, language_counts) = searcher.search(
+            &AllQuery,
+            &(
+                self.facet_collector(self.fields.extension, &Facet::root()),
+                self.facet_collector(self.fields.language, &Facet::root()),
+            ),
+        )?;
+        let last_commit = self.index
+            .load_metas()?
+            .payload
+            .and_then(|payload| payload.parse().ok());
+
+        Ok(IndexStats {
+            chunk_count: health.num_docs,
+            file_count: self.source_file_count(&searcher)?,
+            extensions: facet_counts(&extension_counts, &Facet::root(), false),
+            languages: facet_counts(&language_counts, &Facet::root(), false),
+            segment_count: health.segment_count,
+            deleted_count: health.num_deleted_docs,
+            index_bytes: 0,
+            embedding_store_bytes: 0,
+            last_commit,
+        })
+    }
+
+    /// Segment and document counts. `disk_bytes` is left at 0, `index_health` measures it
+    /// without holding the state lock.
+    fn health(&self) -> Result<IndexHealth> {
+        let searcher = self.searcher(0)?;
+        let num_docs = searcher.num_docs();
+        let num_deleted_docs: u64 = searcher
+            .segment_readers()
+            .iter()
+            .map(|segment_reader| segment_reader.num_deleted_docs() as u64)
+            .sum();
+        let total_docs = num_docs + num_deleted_docs;
+
+        Ok(IndexHealth {
+            segment_count: searcher.segment_readers().len(),
+            num_docs,
+            num_deleted_docs,
+            deleted_ratio: if total_docs == 0 { 0.0 } else { num_deleted_docs as f64 / total_docs as f64 },
+            disk_bytes: 0,
+        })
+    }
+}
+
+
+fn for_each_term_doc(segment_reader: &SegmentReader, field: Field, mut f: impl FnMut(&str, DocId)) -> Result<()> {
+    let inverted_index = segment_reader.inverted_index(field)?;
+    let mut stream = inverted_index.terms().stream()?;
+    while stream.advance() {
+        let Ok(term) = std::str::from_utf8(stream.key()) else {
+            continue;
+        };
+        let mut postings = inverted_index.read_postings_from_terminfo(stream.value(), IndexRecordOption::Basic)?;
+        let mut doc = postings.doc();
+        while doc != TERMINATED {
+            f(term, doc);
+            doc = postings.advance();
+        }
+    }
+    Ok(())
+}
+
+
+fn legacy_file_name(id: &str) -> &str {
+    match id.rsplit_once('-') {
+        Some((file_name, page)) if !page.is_empty() && page.bytes().all(|byte| byte.is_ascii_digit()) => file_name,
+        _ => id,
+    }
+}
+
+
+pub(crate) fn dir_size(dir: &Path) -> Result<u64> {
+    let mut size = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        // Files removed by a merge meanwhile are not counted.
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        size += if metadata.is_dir() { dir_size(&entry.path())? } else { metadata.len() };
+    }
+    Ok(size)
+}
+
+
+const MAX_EDIT_DISTANCE: u8 = 2;
+const MAX_SUGGESTIONS: usize = 3;
+const MAX_SCANNED_PREFIX_TERMS: usize = 5_000;
+
+
+// Building a Levenshtein automaton builder is expensive, so one is kept per edit distance.
+static LEVENSHTEIN_BUILDERS: Lazy<Vec<LevenshteinAutomatonBuilder>> = Lazy::new(|| {
+    (1..=MAX_EDIT_DISTANCE)
+        .map(|distance| LevenshteinAutomatonBuilder::new(distance, true))
+        .collect()
+});
+
+
+/// Lets a Levenshtein DFA drive a term dictionary search.
+struct DfaWrapper<'a>(&'a DFA);
+
+
+impl Automaton for DfaWrapper<'_> {
+    type State = u32;
+
+    fn start(&self) -> u32 {
+        self.0.initial_state()
+    }
+
+    fn is_match(&self, state: &u32) -> bool {
+        matches!(self.0.distance(*state), Distance::Exact(_))
+    }
+
+    fn can_match(&self, state: &u32) -> bool {
+        *state != levenshtein_automata::SINK_STATE
+    }
+
+    fn accept(&self, state: &u32, byte: u8) -> u32 {
+        self.0.transition(*state, byte)
+    }
+}
+
+
+fn dfa_distance(dfa: &DFA, term: &str) -> u8 {
+    match dfa.eval(term) {
+        Distance::Exact(distance) => distance,
+        Distance::AtLeast(distance) => distance,
+    }
+}
+
+
+/// Version of the schema built by `build_schema`, to be bumped whenever fields change.
+/// Version 1 indexes only had the `id` and `text` fields, version 2 had no book fields,
+/// version 3 no headings, version 4 no chat fields, version 5 no spreadsheet fields,
+/// version 6 no transcript fields and version 7 no email fields.
+pub(crate) const SCHEMA_VERSION: u32 = 8;
+
+
+pub(crate) fn build_schema() -> Schema {
+    let mut schema_builder = Schema::builder();
+
+    schema_builder.add_text_field("id", STRING | STORED);
+
+    schema_builder.add_text_field("text", TEXT | STORED);
+
+    schema_builder.add_text_field("path", STRING | STORED);
+    schema_builder.add_facet_field("folder", FacetOptions::default());
+    schema_builder.add_facet_field("extension", FacetOptions::default());
+    schema_builder.add_facet_field("language", FacetOptions::default().set_stored());
+    schema_builder.add_date_field("modified", INDEXED | STORED | FAST);
+    schema_builder.add_facet_field("date", FacetOptions::default());
+
+    schema_builder.add_text_field("title", TEXT | STORED);
+    schema_builder.add_text_field("author", TEXT | STORED);
+    schema_builder.add_text_field("chapter", TEXT | STORED);
+    schema_builder.add_u64_field("chapter_index", INDEXED | STORED);
+    schema_builder.add_text_field("headings", TEXT | STORED);
+
+    schema_builder.add_text_field("chat", TEXT | STORED);
+    schema_builder.add_text_field("sender", TEXT | STORED);
+    schema_builder.add_date_field("timestamp", INDEXED | STORED | FAST);
+
+    schema_builder.add_text_field("sheet", TEXT | STORED);
+    schema_builder.add_u64_field("row", INDEXED | STORED);
+
+    schema_builder.add_u64_field("start_ms", INDEXED | STORED);
+    schema_builder.add_u64_field("end_ms", INDEXED | STORED);
+
+    schema_builder.add_text_field("subject", TEXT | STORED);
+    schema_builder.add_text_field("recipient", TEXT | STORED);
+
+    schema_builder.build()
+}
+
+
+fn escape_regex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+
+fn folder_facet(folder: &str) -> Facet {
+    Facet::from_path(folder.split('/').filter(|segment| !segment.is_empty()))
+}
+
+
+fn any_facet_query(field: Field, facets: impl Iterator<Item = Facet>) -> Box<dyn TantivyQuery> {
+    let clauses = facets
+        .map(|facet| {
+            let query: Box<dyn TantivyQuery> = Box::new(TermQuery::new(Term::from_facet(field, &facet), IndexRecordOption::Basic));
+            (Occur::Should, query)
+        })
+        .collect();
+    Box::new(BooleanQuery::new(clauses))
+}
+
+
+/// Counts the children of `parent`. Folders are reported as full paths so that they can be
+/// passed back as a `folder_prefix`, other facets by their last segment.
+fn facet_counts(counts: &FacetCounts, parent: &Facet, full_path: bool) -> Vec<FacetCount> {
+    let mut facet_counts: Vec<FacetCount> = counts
+        .get(parent.clone())
+        .map(|(facet, count)| FacetCount {
+            value: if full_path {
+                facet.to_path_string()
+            } else {
+                facet.to_path().last().copied().unwrap_or_default().to_string()
+            },
+            count,
+        })
+        .collect();
+    facet_counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    facet_counts
 }
 
 
@@ -46,52 +1172,29 @@ pub fn init_tantivy(dir_path: String) -> Result<()> {
 
     let index_dir = PathBuf::from(dir_path);
     std::fs::create_dir_all(&index_dir)?;
+    let index = open_index(&index_dir)?;
 
-    let (index, schema) = if index_dir.join("meta.json").exists() {
-        
-        let index = Index::open_in_dir(&index_dir)?;
-        let schema = index.schema();
-        (index, schema)
-    } else {
-        
-        let mut schema_builder = Schema::builder();
-        
-        schema_builder.add_text_field("id", STRING | STORED);
-        
-        schema_builder.add_text_field("text", TEXT | STORED);
-        let schema = schema_builder.build();
-        let index = Index::create_in_dir(&index_dir, schema.clone())?;
-        (index, schema)
-    };
+    let api = TantivyApi::new(index_dir, index)?;
 
-    let id_field = schema.get_field("id").map_err(|_| anyhow!("'id' field not found"))?;
-    let text_field = schema.get_field("text").map_err(|_| anyhow!("'text' field not found"))?;
+    *state_lock = Some(api);
 
-    let writer = index.writer(50_000_000)?; 
+    Ok(())
+}
 
-    
-    let reader = index
-        .reader_builder()
-        .reload_policy(ReloadPolicy::Manual)
-        .try_into()?;
-
-    let api = TantivyApi {
-        index,
-        writer: Mutex::new(writer),
-        reader,
-        schema,
-        id_field,
-        text_field,
-    };
 
-    *state_lock = Some(api);
+/// Closes the index, waiting for background merges to finish. Uncommitted changes are
+/// discarded. `init_tantivy` can be called again afterwards.
+pub fn close_tantivy() -> Result<()> {
+    let mut state_lock = STATE.lock().unwrap();
+    if let Some(api) = state_lock.take() {
+        api.writer.into_inner().unwrap().wait_merging_threads()?;
+    }
 
     Ok(())
 }
 
 
 
-
 pub fn add_document(doc: Document) -> Result<()> {
     let state_lock = STATE.lock().unwrap();
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
@@ -99,79 +1202,219 @@ pub fn add_document(doc: Document) -> Result<()> {
     let mut writer = api.writer.lock().unwrap();
 
     
-    let id_term = Term::from_field_text(api.id_field, &doc.id);
+    let id_term = Term::from_field_text(api.fields.id, &doc.id);
     writer.delete_term(id_term.clone());
 
-    let mut tantivy_doc = TantivyDocument::new();
-    tantivy_doc.add_text(api.id_field, &doc.id);
-    tantivy_doc.add_text(api.text_field, &doc.text);
+    let tantivy_doc = api.fields.build_document(&doc, &DocumentMetadata::default());
 
     writer.add_document(tantivy_doc)?;
-    writer.commit()?;
+    api.commit(&mut writer)?;
+
+    Ok(())
+}
+
+
+/// Same as `add_document`, but also indexes where the document comes from so that it can
+/// be filtered and faceted on.
+pub fn add_document_with_metadata(doc: Document, metadata: DocumentMetadata) -> Result<()> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    let mut writer = api.writer.lock().unwrap();
+
+    writer.delete_term(Term::from_field_text(api.fields.id, &doc.id));
+    writer.add_document(api.fields.build_document(&doc, &metadata))?;
+    api.commit(&mut writer)?;
+
+    Ok(())
+}
+
+
+/// Replaces every document indexed from `path` with `docs`, in a single commit, so that
+/// searches never see the file half indexed.
+pub fn replace_file_documents(path: String, docs: Vec<DocumentWithMetadata>) -> Result<()> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    let mut writer = api.writer.lock().unwrap();
+
+    writer.delete_term(Term::from_field_text(api.fields.path, &path));
+    writer.delete_query(Box::new(api.archive_entries_query(&path)?))?;
+    for DocumentWithMetadata { doc, metadata } in docs {
+        writer.add_document(api.fields.build_document(&doc, &metadata))?;
+    }
+    api.commit(&mut writer)?;
 
     Ok(())
 }
 
 
 pub fn search_documents(query: String, top_k: usize) -> Result<Vec<SearchResult>> {
+    let options = SearchOptions { top_k, max_snippets: 0, suggest_below_hits: 0, ..SearchOptions::default() };
+    let response = search(query, options)?;
+
+    Ok(response.hits
+        .into_iter()
+        .map(|hit| SearchResult { score: hit.score, doc: hit.doc, metadata: hit.metadata })
+        .collect())
+}
+
+
+/// Searches with a query string. Syntax errors such as unbalanced quotes or parentheses
+/// do not fail the search, the offending parts are ignored and reported in
+/// `query_warnings`.
+pub fn search(query: String, options: SearchOptions) -> Result<SearchResponse> {
     let state_lock = STATE.lock().unwrap();
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
-    
-    api.reader.reload()?;
+    // Queries are matched in NFC, so that precomposed and combining diacritics are the same term.
+    let query: String = query.nfc().collect();
+    api.search_text(&query, &options)
+}
 
-    
-    let searcher = api.reader.searcher();
 
-    let query_parser = QueryParser::for_index(&api.index, vec![api.text_field]);
-    let query = query_parser.parse_query(&query)?;
+pub fn search_structured(query: Query, options: SearchOptions) -> Result<SearchResponse> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
-    let top_docs = searcher.search(&query, &TopDocs::with_limit(top_k))?;
+    // In NFC, see `search`.
+    let query = query.nfc();
+    let fuzzy_distance = options.fuzzy_distance.min(MAX_EDIT_DISTANCE);
+    let parsed_query = to_tantivy_query(&query, &api.index, api.fields.text, fuzzy_distance)?;
+    let highlight_query = to_tantivy_query(&query, &api.index, api.fields.text, 0)?;
 
-    let mut results = Vec::new();
-    for (score, doc_address) in top_docs {
-        let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)?;
-        let id = retrieved_doc.get_first(api.id_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
-        let text = retrieved_doc.get_first(api.text_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
+    api.execute_search(parsed_query, &*highlight_query, &query.text(), Vec::new(), &options)
+}
 
-        results.push(SearchResult {
-            score,
-            doc: Document { id, text },
+
+fn strip_query_syntax(query: &str) -> String {
+    query
+        .chars()
+        .map(|c| if "\"():^[]{}~+-!*\\".contains(c) { ' ' } else { c })
+        .collect()
+}
+
+
+/// Picks up to `max_snippets` non-overlapping snippets from `text`. The best scoring
+/// fragment comes first, the others follow in document order.
+fn generate_snippets(generator: &SnippetGenerator, text: &str, max_snippets: usize) -> Vec<Snippet> {
+    let mut snippets = Vec::new();
+    let mut segments = vec![(0, text.len())];
+
+    while let Some((segment_start, segment_end)) = segments.pop() {
+        if snippets.len() >= max_snippets {
+            break;
+        }
+
+        let segment_text = &text[segment_start..segment_end];
+        let snippet = generator.snippet(segment_text);
+        if snippet.is_empty() {
+            continue;
+        }
+
+        let fragment = snippet.fragment();
+        let start = segment_start + segment_text.find(fragment).unwrap_or(0);
+        let end = start + fragment.len();
+
+        let highlights = snippet.highlighted()
+            .iter()
+            .map(|range| HighlightRange {
+                start: utf16_len(&fragment[..range.start]),
+                end: utf16_len(&fragment[..range.end]),
+            })
+            .collect();
+
+        snippets.push(Snippet {
+            fragment: fragment.to_string(),
+            offset: utf16_len(&text[..start]),
+            highlights,
         });
+
+        segments.push((end, segment_end));
+        segments.push((segment_start, start));
+    }
+
+    if let Some(others) = snippets.get_mut(1..) {
+        others.sort_by_key(|snippet| snippet.offset);
+    }
+    snippets
+}
+
+
+fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+
+
+/// Index terms starting with `prefix`, most frequent first. Used for autocompletion, so
+/// only the first `MAX_SCANNED_PREFIX_TERMS` terms of each segment are looked at.
+/// Returns no terms while the index is not open, so that the search history still
+/// gives suggestions then.
+pub(crate) fn index_terms_with_prefix(prefix: &str, limit: usize) -> Result<Vec<(String, u64)>> {
+    let state_lock = STATE.lock().unwrap();
+    let Some(api) = state_lock.as_ref() else {
+        return Ok(Vec::new());
+    };
+
+    let searcher = api.searcher(0)?;
+
+    let mut doc_freqs: HashMap<String, u64> = HashMap::new();
+    for segment_reader in searcher.segment_readers() {
+        let inverted_index = segment_reader.inverted_index(api.fields.text)?;
+        let mut stream = inverted_index.terms().range().ge(prefix.as_bytes()).into_stream()?;
+        let mut scanned = 0;
+        while scanned < MAX_SCANNED_PREFIX_TERMS && stream.advance() {
+            if !stream.key().starts_with(prefix.as_bytes()) {
+                break;
+            }
+            if let Ok(term) = std::str::from_utf8(stream.key()) {
+                *doc_freqs.entry(term.to_string()).or_default() += stream.value().doc_freq as u64;
+            }
+            scanned += 1;
+        }
     }
 
-    Ok(results)
+    let mut terms: Vec<(String, u64)> = doc_freqs.into_iter().collect();
+    terms.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    terms.truncate(limit);
+
+    Ok(terms)
+}
+
+
+pub(crate) fn index_dir() -> Result<PathBuf> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    Ok(api.dir.clone())
 }
 
 
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn get_document_by_id(id: String) -> Result<Option<Document>> {
+pub fn get_document_by_id(id: String) -> Result<Option<DocumentWithMetadata>> {
     let state_lock = STATE.lock().unwrap();
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
-    
-    let searcher = api.reader.searcher();
+    let searcher = api.searcher(0)?;
 
-    let id_term = Term::from_field_text(api.id_field, &id);
+    let id_term = Term::from_field_text(api.fields.id, &id);
     let query = tantivy::query::TermQuery::new(id_term, IndexRecordOption::Basic);
 
     let top_docs = searcher.search(&query, &TopDocs::with_limit(1))?;
 
     if let Some((_, doc_address)) = top_docs.first() {
         let retrieved_doc = searcher.doc::<TantivyDocument>(*doc_address)?;
-        let text = retrieved_doc.get_first(api.text_field)
+        let text = retrieved_doc.get_first(api.fields.text)
             .and_then(|v| v.as_str())
             .unwrap_or_default()
             .to_string();
 
-        return Ok(Some(Document { id, text }));
+        return Ok(Some(DocumentWithMetadata {
+            doc: Document { id, text },
+            metadata: api.fields.read_metadata(&retrieved_doc),
+        }));
     }
 
     Ok(None)
@@ -190,15 +1433,58 @@ pub fn delete_document(id: String) -> Result<()> {
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
     let mut writer = api.writer.lock().unwrap();
-    let id_term = Term::from_field_text(api.id_field, &id);
+    let id_term = Term::from_field_text(api.fields.id, &id);
 
     writer.delete_term(id_term);
-    writer.commit()?;
+    api.commit(&mut writer)?;
 
     Ok(())
 }
 
 
+/// Deletes every chunk of the file at `path`, and of the files inside it when it is an
+/// archive. Chunks indexed without metadata are recognised by their legacy
+/// "<fileName>-<page>" id, as long as no other indexed file has the same name. The deleted
+/// ids are returned so that the app can delete the embeddings of the same chunks.
+pub fn delete_by_path(path: String) -> Result<DeleteReport> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    api.delete_path(&path)
+}
+
+
+/// Deletes the chunks of the files in `paths` and the chunks with an id in `ids` in a
+/// single commit, see `delete_by_path`.
+pub(crate) fn delete_files(paths: &[String], ids: &[String]) -> Result<DeleteReport> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    api.delete_files(paths, ids)
+}
+
+
+/// Deletes every chunk of the files in `folder` and its subfolders.
+pub fn delete_by_folder(folder: String) -> Result<DeleteReport> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    api.delete_folder(&folder)
+}
+
+
+/// Deletes every chunk matching `query`. Unlike `search`, the query must parse without
+/// errors, so that a typo cannot delete more than intended. Like the other deletes, it is
+/// left uncommitted while writes made by the `_no_commit` functions are pending, and takes
+/// effect with their `commit`.
+pub fn delete_by_query(query: String) -> Result<DeleteReport> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    api.delete_text_query(&query)
+}
+
+
 pub fn add_documents_batch(docs: Vec<Document>) -> Result<()> {
     let state_lock = STATE.lock().unwrap();
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
@@ -207,18 +1493,16 @@ pub fn add_documents_batch(docs: Vec<Document>) -> Result<()> {
 
     for doc in docs {
         
-        let id_term = Term::from_field_text(api.id_field, &doc.id);
+        let id_term = Term::from_field_text(api.fields.id, &doc.id);
         writer.delete_term(id_term);
 
-        let mut tantivy_doc = TantivyDocument::new();
-        tantivy_doc.add_text(api.id_field, &doc.id);
-        tantivy_doc.add_text(api.text_field, &doc.text);
+        let tantivy_doc = api.fields.build_document(&doc, &DocumentMetadata::default());
 
         writer.add_document(tantivy_doc)?;
     }
 
     
-    writer.commit()?;
+    api.commit(&mut writer)?;
 
     Ok(())
 }
@@ -231,12 +1515,12 @@ pub fn delete_documents_batch(ids: Vec<String>) -> Result<()> {
     let mut writer = api.writer.lock().unwrap();
 
     for id in ids {
-        let id_term = Term::from_field_text(api.id_field, &id);
+        let id_term = Term::from_field_text(api.fields.id, &id);
         writer.delete_term(id_term);
     }
 
     
-    writer.commit()?;
+    api.commit(&mut writer)?;
 
     Ok(())
 }
@@ -249,27 +1533,37 @@ pub fn commit() -> Result<()> {
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
     let mut writer = api.writer.lock().unwrap();
-    writer.commit()?;
+    api.commit(&mut writer)?;
 
     Ok(())
 }
 
 
+/// Number of commits made since the index was opened. Pass it as
+/// `SearchOptions::min_generation` to read your own writes.
+#[flutter_rust_bridge::frb(sync)]
+pub fn commit_generation() -> Result<u64> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    Ok(api.commit_generation.load(Ordering::SeqCst))
+}
+
+
 
 pub fn add_document_no_commit(doc: Document) -> Result<()> {
     let state_lock = STATE.lock().unwrap();
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
-    let mut writer = api.writer.lock().unwrap();
+    let writer = api.writer.lock().unwrap();
 
-    let id_term = Term::from_field_text(api.id_field, &doc.id);
+    let id_term = Term::from_field_text(api.fields.id, &doc.id);
     writer.delete_term(id_term);
 
-    let mut tantivy_doc = TantivyDocument::new();
-    tantivy_doc.add_text(api.id_field, &doc.id);
-    tantivy_doc.add_text(api.text_field, &doc.text);
+    let tantivy_doc = api.fields.build_document(&doc, &DocumentMetadata::default());
 
     writer.add_document(tantivy_doc)?;
+    api.pending_writes.store(true, Ordering::SeqCst);
 
     Ok(())
 }
@@ -279,10 +1573,489 @@ pub fn delete_document_no_commit(id: String) -> Result<()> {
     let state_lock = STATE.lock().unwrap();
     let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
 
-    let mut writer = api.writer.lock().unwrap();
-    let id_term = Term::from_field_text(api.id_field, &id);
+    let writer = api.writer.lock().unwrap();
+    let id_term = Term::from_field_text(api.fields.id, &id);
 
     writer.delete_term(id_term);
+    api.pending_writes.store(true, Ordering::SeqCst);
 
     Ok(())
 }
+
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_merge_policy(options: MergePolicyOptions) -> Result<()> {
+    let state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    let writer = api.writer.lock().unwrap();
+    if options.enabled {
+        let mut merge_policy = LogMergePolicy::default();
+        merge_policy.set_min_num_segments(options.min_num_segments);
+        merge_policy.set_max_docs_before_merge(options.max_docs_before_merge);
+        merge_policy.set_del_docs_ratio_before_merge(options.deleted_ratio_before_merge.clamp(f32::EPSILON, 1.0));
+        writer.set_merge_policy(Box::new(merge_policy));
+    } else {
+        writer.set_merge_policy(Box::new(NoMergePolicy));
+    }
+
+    Ok(())
+}
+
+
+pub fn index_health() -> Result<IndexHealth> {
+    let (mut health, dir) = {
+        let state_lock = STATE.lock().unwrap();
+        let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+        (api.health()?, api.dir.clone())
+    };
+
+    // Walking the index directory is slow on large indexes, searches need not wait for it.
+    health.disk_bytes = dir_size(&dir)?;
+    Ok(health)
+}
+
+
+/// Merges all committed segments into one, dropping deleted documents, then removes the
+/// files no longer used. Meant to run while the device is idle and charging, as it can
+/// take a while on large indexes. Searching and indexing go on during the merge, documents
+/// committed meanwhile stay in their own segments. Uncommitted changes are left as they are.
+pub fn optimize_index() -> Result<IndexHealth> {
+    let merge = {
+        let state_lock = STATE.lock().unwrap();
+        let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+        let before = api.health()?;
+        if before.segment_count > 1 || before.num_deleted_docs > 0 {
+            let segment_ids = api.index.searchable_segment_ids()?;
+            let mut writer = api.writer.lock().unwrap();
+            Some(writer.merge(&segment_ids))
+        } else {
+            None
+        }
+    };
+    // The merge runs on tantivy's merge threads, it is waited for without holding the locks.
+    if let Some(merge) = merge {
+        merge.wait()?;
+    }
+
+    garbage_collect_index()?;
+
+    {
+        let state_lock = STATE.lock().unwrap();
+        let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+        api.reader.reload()?;
+    }
+    index_health()
+}
+
+
+/// Removes index files left behind by merges and deletes. Returns the number of files
+/// removed.
+pub fn garbage_collect_index() -> Result<u64> {
+    let garbage_collection = {
+        let state_lock = STATE.lock().unwrap();
+        let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+        let writer = api.writer.lock().unwrap();
+        writer.garbage_collect_files()
+    };
+    let result = garbage_collection.wait()?;
+
+    Ok(result.deleted_files.len() as u64)
+}
+
+
+/// Reads every file of the index to check it against its checksums, which is too slow to do
+/// on every startup. A damaged index is quarantined and replaced by an empty one, as on
+/// startup, and the returned report tells what happened. Uncommitted changes are discarded
+/// in that case.
+pub fn verify_index() -> Result<Option<RecoveryReport>> {
+    let mut state_lock = STATE.lock().unwrap();
+    let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+
+    let mut damaged_files: Vec<PathBuf> = api.index.validate_checksum()?.into_iter().collect();
+    if damaged_files.is_empty() {
+        return Ok(None);
+    }
+    damaged_files.sort();
+    let problems = damaged_files
+        .iter()
+        .map(|file| format!("Checksum mismatch in {}", file.display()))
+        .collect();
+
+    let api = state_lock.take().unwrap();
+    let dir = api.dir.clone();
+    drop(api);
+    let index = quarantine(&dir, RecoveryReason::Corrupted, problems)?;
+    *state_lock = Some(TantivyApi::new(dir, index)?);
+
+    Ok(take_recovery_report())
+}
+
+
+/// `embedding_store_dir` is only used to report the size of the embedding store.
+pub fn index_stats(embedding_store_dir: Option<String>) -> Result<IndexStats> {
+    let (mut stats, dir) = {
+        let state_lock = STATE.lock().unwrap();
+        let api = state_lock.as_ref().ok_or_else(|| anyhow!("Tantivy not initialized"))?;
+        (api.stats()?, api.dir.clone())
+    };
+
+    stats.index_bytes = dir_size(&dir)?;
+    stats.embedding_store_bytes = match embedding_store_dir.map(PathBuf::from) {
+        Some(dir) if dir.exists() => dir_size(&dir)?,
+        _ => 0,
+    };
+    Ok(stats)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(path: &str, modified: i64, language: &str) -> DocumentMetadata {
+        DocumentMetadata { path: path.to_string(), modified: Some(modified), language: Some(language.to_string()), ..DocumentMetadata::default() }
+    }
+
+    fn api_with_metadata(docs: &[(&str, DocumentMetadata)]) -> TantivyApi {
+        let api = TantivyApi::new(PathBuf::new(), Index::create_in_ram(build_schema())).unwrap();
+        {
+            let mut writer = api.writer.lock().unwrap();
+            for (index, (text, metadata)) in docs.iter().enumerate() {
+                let doc = Document { id: format!("doc-{}", index), text: text.to_string() };
+                writer.add_document(api.fields.build_document(&doc, metadata)).unwrap();
+            }
+            api.commit(&mut writer).unwrap();
+        }
+        api
+    }
+
+    fn api_with(texts: &[&str]) -> TantivyApi {
+        let docs: Vec<_> = texts.iter().map(|text| (*text, DocumentMetadata::default())).collect();
+        api_with_metadata(&docs)
+    }
+
+    fn files_api() -> TantivyApi {
+        api_with_metadata(&[
+            ("Quarterly report", metadata("/storage/Documents/report.pdf", 1_709_251_200, "yo")),
+            ("Old report", metadata("/storage/Documents/2019/old.PDF", 1_577_836_800, "en")),
+            ("Meeting notes", metadata("/storage/WhatsApp/notes.txt", 1_714_521_600, "yo")),
+        ])
+    }
+
+    fn count(api: &TantivyApi, filter: SearchFilter) -> usize {
+        let query = api.filtered_query(Box::new(AllQuery), &filter);
+        api.searcher(0).unwrap().search(&query, &Count).unwrap()
+    }
+
+    fn facets(api: &TantivyApi, field: Field, parent: &Facet, full_path: bool) -> Vec<(String, u64)> {
+        let mut collector = FacetCollector::for_field(api.index.schema().get_field_name(field));
+        collector.add_facet(parent.clone());
+        let counts = api.searcher(0).unwrap().search(&AllQuery, &collector).unwrap();
+        facet_counts(&counts, parent, full_path)
+            .into_iter()
+            .map(|facet_count| (facet_count.value, facet_count.count))
+            .collect()
+    }
+
+    fn add(api: &TantivyApi, id: &str, path: &str) {
+        let writer = api.writer.lock().unwrap();
+        let doc = Document { id: id.to_string(), text: format!("chunk {}", id) };
+        let metadata = DocumentMetadata { path: path.to_string(), ..DocumentMetadata::default() };
+        writer.add_document(api.fields.build_document(&doc, &metadata)).unwrap();
+    }
+
+    fn ids(api: &TantivyApi) -> Vec<String> {
+        let searcher = api.searcher(0).unwrap();
+        let mut ids: Vec<String> = searcher
+            .search(&AllQuery, &DocSetCollector)
+            .unwrap()
+            .into_iter()
+            .map(|doc_address| {
+                let doc: TantivyDocument = searcher.doc(doc_address).unwrap();
+                doc.get_first(api.fields.id).and_then(|value| value.as_str()).unwrap().to_string()
+            })
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    fn chunks_api(chunks: &[(&str, &str)]) -> TantivyApi {
+        let api = TantivyApi::new(PathBuf::new(), Index::create_in_ram(build_schema())).unwrap();
+        for (id, path) in chunks {
+            add(&api, id, path);
+        }
+        api.commit(&mut api.writer.lock().unwrap()).unwrap();
+        api
+    }
+
+    #[test]
+    fn searches_read_their_writes() {
+        let api = TantivyApi::new(PathBuf::new(), Index::create_in_ram(build_schema())).unwrap();
+
+        let generation = {
+            let mut writer = api.writer.lock().unwrap();
+            let doc = Document { id: "report-1".to_string(), text: "Quarterly report".to_string() };
+            writer.add_document(api.fields.build_document(&doc, &DocumentMetadata::default())).unwrap();
+            api.commit(&mut writer).unwrap()
+        };
+
+        assert_eq!(api.searcher(generation).unwrap().num_docs(), 1);
+        assert!(api.searcher(generation + 1).is_err());
+    }
+
+    #[test]
+    fn filters_restrict_matches() {
+        let api = files_api();
+
+        assert_eq!(count(&api, SearchFilter::default()), 3);
+        assert_eq!(count(&api, SearchFilter { extensions: vec![".pdf".to_string()], ..SearchFilter::default() }), 2);
+        assert_eq!(count(&api, SearchFilter { folder_prefix: Some("/storage/Documents/".to_string()), ..SearchFilter::default() }), 2);
+        assert_eq!(count(&api, SearchFilter { languages: vec!["YO".to_string()], ..SearchFilter::default() }), 2);
+        assert_eq!(count(&api, SearchFilter { modified_after: Some(1_704_067_200), ..SearchFilter::default() }), 2);
+        assert_eq!(count(&api, SearchFilter { modified_before: Some(1_709_251_200), ..SearchFilter::default() }), 1);
+
+        let filter = SearchFilter {
+            extensions: vec!["pdf".to_string(), "txt".to_string()],
+            languages: vec!["yo".to_string()],
+            modified_after: Some(1_710_000_000),
+            ..SearchFilter::default()
+        };
+        assert_eq!(count(&api, filter), 1);
+    }
+
+    #[test]
+    fn facets_count_children_of_the_filtered_folder() {
+        let api = files_api();
+
+        assert_eq!(facets(&api, api.fields.extension, &Facet::root(), false), vec![("pdf".to_string(), 2), ("txt".to_string(), 1)]);
+        assert_eq!(facets(&api, api.fields.date, &Facet::root(), false), vec![("2024".to_string(), 2), ("2020".to_string(), 1)]);
+        assert_eq!(facets(&api, api.fields.language, &Facet::root(), false), vec![("yo".to_string(), 2), ("en".to_string(), 1)]);
+        assert_eq!(
+            facets(&api, api.fields.folder, &folder_facet("/storage"), true),
+            vec![("/storage/Documents".to_string(), 2), ("/storage/WhatsApp".to_string(), 1)],
+        );
+    }
+
+    #[test]
+    fn fuzzy_hits_have_snippets() {
+        let api = api_with(&["The quarterly report is due on Friday."]);
+        let options = SearchOptions { fuzzy_distance: 1, ..SearchOptions::default() };
+
+        let response = api.search_text("reprot", &options).unwrap();
+
+        assert_eq!(response.hits.len(), 1);
+        let snippets = &response.hits[0].snippets;
+        assert_eq!(snippets.len(), 1);
+        let highlight = &snippets[0].highlights[0];
+        assert_eq!(&snippets[0].fragment[highlight.start..highlight.end], "report");
+    }
+
+    #[test]
+    fn offset_pages_through_hits() {
+        let api = api_with(&["report one", "report two", "report three", "report four", "report five"]);
+
+        let mut ids = BTreeSet::new();
+        for offset in [0, 2, 4] {
+            let options = SearchOptions { top_k: 2, offset, ..SearchOptions::default() };
+            let response = api.search_text("report", &options).unwrap();
+
+            assert_eq!(response.total_hits, 5);
+            assert_eq!(response.hits.len(), if offset == 4 { 1 } else { 2 });
+            ids.extend(response.hits.into_iter().map(|hit| hit.doc.id));
+        }
+        assert_eq!(ids.len(), 5);
+
+        let options = SearchOptions { top_k: 2, offset: 5, ..SearchOptions::default() };
+        let response = api.search_text("report", &options).unwrap();
+        assert_eq!(response.total_hits, 5);
+        assert!(response.hits.is_empty());
+    }
+
+    #[test]
+    fn zero_top_k_counts_without_hits() {
+        let api = api_with(&["first report", "second report"]);
+        let options = SearchOptions { top_k: 0, ..SearchOptions::default() };
+
+        let response = api.search_text("report", &options).unwrap();
+
+        assert_eq!(response.total_hits, 2);
+        assert!(response.hits.is_empty());
+    }
+
+    #[test]
+    fn delete_by_path_removes_the_file_and_its_legacy_chunks() {
+        let api = chunks_api(&[
+            ("report-0", "/storage/Documents/report.pdf"),
+            ("report-1", "/storage/Documents/report.pdf"),
+            ("report.pdf-0", ""),
+            ("other.pdf-0", ""),
+            ("notes", "/storage/Documents/notes.txt"),
+        ]);
+
+        let report = api.delete_path("/storage/Documents/report.pdf").unwrap();
+
+        assert!(report.committed);
+        assert_eq!(report.deleted_ids, vec!["report-0", "report-1", "report.pdf-0"]);
+        assert_eq!(ids(&api), vec!["notes", "other.pdf-0"]);
+    }
+
+    #[test]
+    fn delete_by_path_keeps_legacy_chunks_another_file_could_own() {
+        let api = chunks_api(&[
+            ("a", "/storage/Documents/report.pdf"),
+            ("b", "/storage/Download/report.pdf"),
+            ("report.pdf-0", ""),
+        ]);
+
+        let report = api.delete_path("/storage/Documents/report.pdf").unwrap();
+
+        assert_eq!(report.deleted_ids, vec!["a"]);
+        assert_eq!(ids(&api), vec!["b", "report.pdf-0"]);
+    }
+
+    #[test]
+    fn delete_by_folder_includes_subfolders() {
+        let api = chunks_api(&[
+            ("a", "/storage/Documents/a.pdf"),
+            ("b", "/storage/Documents/2019/b.pdf"),
+            ("c", "/storage/DocumentsOld/c.pdf"),
+        ]);
+
+        assert!(api.delete_folder("/").is_err());
+        let report = api.delete_folder("/storage/Documents/").unwrap();
+
+        assert_eq!(report.deleted_count, 2);
+        assert_eq!(ids(&api), vec!["c"]);
+    }
+
+    #[test]
+    fn delete_by_query_requires_a_valid_query() {
+        let api = chunks_api(&[("a", ""), ("b", ""), ("c", "")]);
+
+        assert!(api.delete_text_query("chunk AND (a").is_err());
+        let report = api.delete_text_query("a OR b").unwrap();
+
+        assert_eq!(report.deleted_ids, vec!["a", "b"]);
+        assert_eq!(ids(&api), vec!["c"]);
+    }
+
+    #[test]
+    fn deletes_wait_for_pending_writes_to_be_committed() {
+        let api = chunks_api(&[("a", ""), ("b", "")]);
+        add(&api, "c", "");
+        api.pending_writes.store(true, Ordering::SeqCst);
+
+        let report = api.delete_text_query("a OR c").unwrap();
+
+        assert!(!report.committed);
+        assert_eq!(report.deleted_ids, vec!["a"]);
+        assert_eq!(ids(&api), vec!["a", "b"]);
+
+        api.commit(&mut api.writer.lock().unwrap()).unwrap();
+        assert_eq!(ids(&api), vec!["b"]);
+    }
+
+    #[test]
+    fn stats_count_files_from_paths_and_legacy_ids() {
+        let api = chunks_api(&[
+            ("report.pdf-0", ""),
+            ("report.pdf-1", ""),
+            ("notes.txt", ""),
+            ("a", "/storage/Documents/a.pdf"),
+            ("b", "/storage/Documents/a.pdf"),
+            ("c", "/storage/Documents/b.txt"),
+        ]);
+
+        let stats = api.stats().unwrap();
+
+        assert_eq!(stats.chunk_count, 6);
+        assert_eq!(stats.file_count, 4);
+        assert!(stats.last_commit.is_some());
+    }
+
+    #[test]
+    fn stats_count_extensions_and_languages() {
+        let api = files_api();
+
+        let stats = api.stats().unwrap();
+        let counts = |facet_counts: Vec<FacetCount>| -> Vec<(String, u64)> {
+            facet_counts.into_iter().map(|facet_count| (facet_count.value, facet_count.count)).collect()
+        };
+
+        assert_eq!(counts(stats.extensions), vec![("pdf".to_string(), 2), ("txt".to_string(), 1)]);
+        assert_eq!(counts(stats.languages), vec![("yo".to_string(), 2), ("en".to_string(), 1)]);
+    }
+
+    #[test]
+    fn file_count_is_recounted_after_a_commit() {
+        let api = chunks_api(&[("a", "/storage/a.pdf"), ("b", "/storage/b.pdf")]);
+        assert_eq!(api.stats().unwrap().file_count, 2);
+
+        api.delete_path("/storage/a.pdf").unwrap();
+        let stats = api.stats().unwrap();
+
+        assert_eq!(stats.file_count, 1);
+        assert_eq!(stats.deleted_count + stats.chunk_count, 2);
+    }
+
+    #[test]
+    fn hits_carry_the_stored_metadata() {
+        let metadata = DocumentMetadata {
+            title: Some("Things Fall Apart".to_string()),
+            author: Some("Chinua Achebe".to_string()),
+            chapter: Some("Chapter One".to_string()),
+            chapter_index: Some(1),
+            ..metadata("/storage/Books/things.epub", 1_709_251_200, "EN")
+        };
+        let api = api_with_metadata(&[("Okonkwo was well known", metadata)]);
+
+        let response = api.search_text("okonkwo", &SearchOptions::default()).unwrap();
+        let hit_metadata = &response.hits[0].metadata;
+
+        assert_eq!(hit_metadata.path, "/storage/Books/things.epub");
+        assert_eq!(hit_metadata.modified, Some(1_709_251_200));
+        assert_eq!(hit_metadata.language.as_deref(), Some("en"));
+        assert_eq!(hit_metadata.title.as_deref(), Some("Things Fall Apart"));
+        assert_eq!(hit_metadata.author.as_deref(), Some("Chinua Achebe"));
+        assert_eq!(hit_metadata.chapter.as_deref(), Some("Chapter One"));
+        assert_eq!(hit_metadata.chapter_index, Some(1));
+    }
+
+    #[test]
+    fn transcript_segment_times_round_trip() {
+        let metadata = DocumentMetadata {
+            start_ms: Some(61_500),
+            end_ms: Some(64_250),
+            ..metadata("/storage/WhatsApp/Voice Notes/PTT-20240301.opus", 1_709_251_200, "yo")
+        };
+        let api = api_with_metadata(&[("E kaaro, se daadaa ni", metadata)]);
+
+        let response = api.search_text("kaaro", &SearchOptions::default()).unwrap();
+        let hit_metadata = &response.hits[0].metadata;
+
+        assert_eq!(hit_metadata.start_ms, Some(61_500));
+        assert_eq!(hit_metadata.end_ms, Some(64_250));
+    }
+
+    #[test]
+    fn email_headers_come_back_with_hits() {
+        let metadata = DocumentMetadata {
+            subject: Some("Harmattan break".to_string()),
+            senders: vec!["Ngozi <ngozi@example.com>".to_string()],
+            recipients: vec!["Tunde <tunde@example.com>".to_string(), "Aisha <aisha@example.com>".to_string()],
+            timestamp: Some(1_709_251_200),
+            ..metadata("/storage/Mail/inbox.mbox", 1_709_251_200, "en")
+        };
+        let api = api_with_metadata(&[("The school closes for the harmattan break", metadata)]);
+
+        let response = api.search_text("harmattan", &SearchOptions::default()).unwrap();
+        let hit_metadata = &response.hits[0].metadata;
+
+        assert_eq!(hit_metadata.subject.as_deref(), Some("Harmattan break"));
+        assert_eq!(hit_metadata.senders, vec!["Ngozi <ngozi@example.com>"]);
+        assert_eq!(hit_metadata.recipients, vec!["Tunde <tunde@example.com>", "Aisha <aisha@example.com>"]);
+        assert_eq!(hit_metadata.timestamp, Some(1_709_251_200));
+    }
+}
@@ -0,0 +1,542 @@
+use anyhow::{anyhow, Result};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252};
+use quick_xml::escape::resolve_html5_entity;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::api::chat::{extract_whatsapp, is_whatsapp_export};
+use crate::api::epub::BLOCK_ELEMENTS;
+use crate::api::extract::{chunk_id, file_metadata, split_paragraphs, ExtractedFile, CHUNK_CHARS};
+use crate::api::tantivy::{Document, DocumentMetadata, DocumentWithMetadata};
+
+
+// Larger files are most likely logs or data dumps rather than something to read.
+const MAX_TEXT_FILE_SIZE: u64 = 64 * 1024 * 1024;
+// Number of bytes looked at to guess the encoding.
+const SNIFF_BYTES: usize = 4096;
+// Elements whose content is not text of the page.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "template", "noscript"];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TextFormat {
+    Plain,
+    Markdown,
+    Html,
+}
+
+
+/// A line of text, or a heading.
+struct Block {
+    text: String,
+    heading: bool,
+}
+
+
+/// Guesses the UTF-16 byte order of text without a BOM from where the zero bytes are:
+/// mostly ASCII text has one in every other byte.
+fn sniff_utf16(bytes: &[u8]) -> Option<&'static Encoding> {
+    let sample = &bytes[..bytes.len().min(SNIFF_BYTES) & !1];
+    if sample.len() < 2 {
+        return None;
+    }
+    let pairs = sample.len() / 2;
+    let even_zeros = sample.iter().step_by(2).filter(|&&byte| byte == 0).count();
+    let odd_zeros = sample.iter().skip(1).step_by(2).filter(|&&byte| byte == 0).count();
+
+    if odd_zeros * 10 > pairs * 3 && even_zeros * 10 < pairs {
+        Some(UTF_16LE)
+    } else if even_zeros * 10 > pairs * 3 && odd_zeros * 10 < pairs {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+
+/// Reads the charset declared by an HTML `<meta>` tag near the start of the page.
+fn sniff_html_charset(bytes: &[u8]) -> Option<&'static Encoding> {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(SNIFF_BYTES)]).to_ascii_lowercase();
+    let start = head.find("charset=")? + "charset=".len();
+    let label: String = head[start..]
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_' | ':' | '.'))
+        .collect();
+    Encoding::for_label(label.as_bytes())
+}
+
+
+/// Decodes `bytes` using, in order: a byte order mark, the zero bytes of UTF-16, UTF-8 if
+/// the bytes are valid UTF-8, the charset declared by an HTML page, and Windows-1252,
+/// which also covers Latin-1. Returns the text and the encoding used.
+pub(crate) fn decode_text(bytes: &[u8], format: TextFormat) -> (String, &'static Encoding) {
+    if let Some((encoding, bom_length)) = Encoding::for_bom(bytes) {
+        return (encoding.decode_without_bom_handling(&bytes[bom_length..]).0.into_owned(), encoding);
+    }
+    if let Some(encoding) = sniff_utf16(bytes) {
+        return (encoding.decode_without_bom_handling(bytes).0.into_owned(), encoding);
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return (text.to_string(), UTF_8);
+    }
+
+    // A page claiming UTF-8 but failing to decode is more likely Windows-1252.
+    let encoding = match format {
+        TextFormat::Html => sniff_html_charset(bytes).filter(|&encoding| encoding != UTF_8),
+        _ => None,
+    }
+    .unwrap_or(WINDOWS_1252);
+    (encoding.decode_without_bom_handling(bytes).0.into_owned(), encoding)
+}
+
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+
+/// Decodes the character reference at the start of `text`, e.g. "&amp;" or "&#233;".
+/// Returns the character and the length of the reference.
+fn html_entity(text: &str) -> Option<(String, usize)> {
+    // The longest named reference, "&CounterClockwiseContourIntegral;", has 33 characters.
+    let end = text.char_indices().take(40).find(|&(_, character)| character == ';')?.0;
+    let name = &text[1..end];
+    let decoded = if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        char::from_u32(code)?.to_string()
+    } else {
+        resolve_html5_entity(name)?.to_string()
+    };
+    Some((decoded, end + 1))
+}
+
+
+/// Finds the `>` closing the tag that starts `text`, skipping quoted attribute values.
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, character) in text.char_indices() {
+        match (quote, character) {
+            (None, '"' | '\'') => quote = Some(character),
+            (Some(open), _) if open == character => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+
+/// Finds the `</name` starting the closing tag of a `name` element, ignoring ASCII case.
+fn find_closing_tag(text: &str, name: &str) -> Option<usize> {
+    let mut from = 0;
+    while let Some(offset) = text[from..].find("</") {
+        let start = from + offset;
+        let candidate = &text.as_bytes()[start + 2..];
+        if candidate.len() >= name.len() && candidate[..name.len()].eq_ignore_ascii_case(name.as_bytes()) {
+            return Some(start);
+        }
+        from = start + 2;
+    }
+    None
+}
+
+
+/// Strips HTML to lines of text. Unlike e-book chapters, web pages saved on a phone are
+/// rarely well-formed XML, so tags are skipped by hand rather than parsed.
+fn html_blocks(html: &str) -> (Vec<Block>, Option<String>) {
+    let mut blocks = Vec::new();
+    let mut title = None;
+    let mut line = String::new();
+    let mut heading: Option<String> = None;
+    let mut in_title = false;
+    let mut title_text = String::new();
+
+    let flush = |line: &mut String, blocks: &mut Vec<Block>, is_heading: bool| {
+        let text = collapse_whitespace(line);
+        if !text.is_empty() {
+            blocks.push(Block { text, heading: is_heading });
+        }
+        line.clear();
+    };
+
+    let mut rest = html;
+    while let Some(character) = rest.chars().next() {
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let is_tag = character == '<'
+            && rest[1..].starts_with(|next: char| next.is_ascii_alphabetic() || matches!(next, '/' | '!' | '?'));
+        if is_tag {
+            let Some(end) = tag_end(rest) else { break };
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            let closing = tag.starts_with('/');
+            let name: String = tag
+                .trim_start_matches('/')
+                .chars()
+                .take_while(|character| character.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase();
+
+            if !closing && RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                rest = find_closing_tag(rest, &name)
+                    .and_then(|start| tag_end(&rest[start..]).map(|end| &rest[start + end + 1..]))
+                    .unwrap_or("");
+            } else if name == "title" {
+                in_title = !closing;
+                if closing && title.is_none() {
+                    title = Some(collapse_whitespace(&title_text)).filter(|title| !title.is_empty());
+                }
+            } else if matches!(name.as_str(), "h1" | "h2" | "h3" | "h4" | "h5" | "h6") {
+                match (closing, heading.take()) {
+                    (false, _) => {
+                        flush(&mut line, &mut blocks, false);
+                        heading = Some(String::new());
+                    }
+                    (true, Some(mut text)) => flush(&mut text, &mut blocks, true),
+                    (true, None) => {}
+                }
+            } else if BLOCK_ELEMENTS.contains(&name.as_str()) {
+                match heading.as_mut() {
+                    Some(text) => text.push(' '),
+                    None => flush(&mut line, &mut blocks, false),
+                }
+            }
+            continue;
+        }
+
+        let (text, length) = match character {
+            '&' => html_entity(rest).unwrap_or_else(|| ("&".to_string(), 1)),
+            _ => (character.to_string(), character.len_utf8()),
+        };
+        rest = &rest[length..];
+        if in_title {
+            title_text.push_str(&text);
+        } else if let Some(heading) = heading.as_mut() {
+            heading.push_str(&text);
+        } else {
+            line.push_str(&text);
+        }
+    }
+
+    if let Some(mut text) = heading.take() {
+        flush(&mut text, &mut blocks, true);
+    }
+    flush(&mut line, &mut blocks, false);
+    (blocks, title)
+}
+
+
+/// Removes Markdown inline syntax: emphasis, code spans, links and images keep their text,
+/// HTML tags are dropped.
+fn strip_markdown_inline(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(character) = rest.chars().next() {
+        match character {
+            '!' if rest.starts_with("![") => rest = &rest[1..],
+            '[' => {
+                // "[text](url)" and "[text][reference]" keep their text.
+                if let Some(close) = rest.find(']') {
+                    let after = &rest[close + 1..];
+                    let skip = match after.chars().next() {
+                        Some('(') => after.find(')').map(|end| end + 1),
+                        Some('[') => after.find(']').map(|end| end + 1),
+                        _ => Some(0),
+                    };
+                    if let Some(skip) = skip {
+                        text.push_str(&strip_markdown_inline(&rest[1..close]));
+                        rest = &after[skip..];
+                        continue;
+                    }
+                }
+                text.push('[');
+                rest = &rest[1..];
+            }
+            '<' => match tag_end(rest) {
+                // Autolinks keep their address.
+                Some(end) if rest[1..end].contains("://") || rest[1..end].contains('@') => {
+                    text.push_str(&rest[1..end]);
+                    rest = &rest[end + 1..];
+                }
+                Some(end) if rest[1..].starts_with(|next: char| next.is_ascii_alphabetic() || next == '/') => {
+                    rest = &rest[end + 1..];
+                }
+                _ => {
+                    text.push('<');
+                    rest = &rest[1..];
+                }
+            },
+            '*' | '`' | '~' => rest = &rest[1..],
+            '_' => {
+                // Underscores inside words, as in snake_case, are kept.
+                let previous = text.chars().last();
+                let next = rest[1..].chars().next();
+                if previous.is_some_and(char::is_alphanumeric) && next.is_some_and(char::is_alphanumeric) {
+                    text.push('_');
+                }
+                rest = &rest[1..];
+            }
+            '\\' if rest[1..].starts_with(|next: char| next.is_ascii_punctuation()) => {
+                let next = rest[1..].chars().next().unwrap_or_default();
+                text.push(next);
+                rest = &rest[1 + next.len_utf8()..];
+            }
+            '&' => {
+                let (decoded, length) = html_entity(rest).unwrap_or_else(|| ("&".to_string(), 1));
+                text.push_str(&decoded);
+                rest = &rest[length..];
+            }
+            _ => {
+                text.push(character);
+                rest = &rest[character.len_utf8()..];
+            }
+        }
+    }
+
+    collapse_whitespace(&text)
+}
+
+
+fn is_thematic_break(line: &str) -> bool {
+    let compact: String = line.chars().filter(|character| !character.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_'].iter().any(|&marker| compact.chars().all(|character| character == marker))
+}
+
+
+/// Removes list, quote and task markers from the start of a Markdown line.
+fn strip_block_markers(line: &str) -> &str {
+    let mut line = line.trim_start();
+    loop {
+        let before = line;
+        line = line.strip_prefix('>').unwrap_or(line).trim_start();
+        for marker in ["- ", "* ", "+ "] {
+            if let Some(rest) = line.strip_prefix(marker) {
+                line = rest.trim_start();
+            }
+        }
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        if digits > 0 && digits < 10 && line[digits..].starts_with([')', '.']) && line[digits + 1..].starts_with(' ') {
+            line = line[digits + 1..].trim_start();
+        }
+        for task in ["[ ] ", "[x] ", "[X] "] {
+            if let Some(rest) = line.strip_prefix(task) {
+                line = rest;
+            }
+        }
+        if line == before {
+            return line;
+        }
+    }
+}
+
+
+/// Strips Markdown to lines of text and headings. The title is the front matter title, or
+/// else the first level 1 heading.
+fn markdown_blocks(markdown: &str) -> (Vec<Block>, Option<String>) {
+    let mut blocks = Vec::new();
+    let mut title = None;
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut index = 0;
+
+    // YAML front matter.
+    if lines.first().is_some_and(|line| line.trim_end() == "---") {
+        if let Some(end) = lines.iter().skip(1).position(|line| matches!(line.trim_end(), "---" | "...")) {
+            for line in &lines[1..end + 1] {
+                if let Some(value) = line.strip_prefix("title:") {
+                    title = Some(value.trim().trim_matches(['"', '\'']).to_string()).filter(|title| !title.is_empty());
+                }
+            }
+            index = end + 2;
+        }
+    }
+
+    let mut fence: Option<&str> = None;
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+        index += 1;
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            } else if !trimmed.is_empty() {
+                blocks.push(Block { text: trimmed.to_string(), heading: false });
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fence = Some(&trimmed[..3]);
+            continue;
+        }
+
+        let hashes = trimmed.chars().take_while(|&character| character == '#').count();
+        if (1..=6).contains(&hashes) && trimmed[hashes..].chars().next().is_none_or(char::is_whitespace) {
+            let text = strip_markdown_inline(trimmed[hashes..].trim().trim_end_matches('#'));
+            if !text.is_empty() {
+                if hashes == 1 && title.is_none() {
+                    title = Some(text.clone());
+                }
+                blocks.push(Block { text, heading: true });
+            }
+            continue;
+        }
+
+        // Setext headings are underlined with "=" or "-".
+        let underline = lines.get(index).map(|next| next.trim()).unwrap_or_default();
+        let is_underline = !underline.is_empty()
+            && (underline.chars().all(|character| character == '=') || underline.chars().all(|character| character == '-'));
+        if !trimmed.is_empty() && is_underline && !is_thematic_break(trimmed) {
+            let text = strip_markdown_inline(strip_block_markers(trimmed));
+            if !text.is_empty() {
+                if underline.starts_with('=') && title.is_none() {
+                    title = Some(text.clone());
+                }
+                blocks.push(Block { text, heading: true });
+            }
+            index += 1;
+            continue;
+        }
+
+        if trimmed.is_empty() || is_thematic_break(trimmed) {
+            continue;
+        }
+        // Link reference definitions, e.g. "[1]: https://example.com".
+        if trimmed.starts_with('[') && trimmed.contains("]:") {
+            continue;
+        }
+        // Table separator rows.
+        if trimmed.contains('|') && trimmed.chars().all(|character| matches!(character, '|' | '-' | ':' | ' ')) {
+            continue;
+        }
+
+        let text = strip_markdown_inline(&strip_block_markers(trimmed).replace('|', " "));
+        if !text.is_empty() {
+            blocks.push(Block { text, heading: false });
+        }
+    }
+
+    (blocks, title)
+}
+
+
+/// Groups blocks into documents of at most `CHUNK_CHARS` characters. Each document keeps
+/// the heading of the section it starts in and the headings it contains.
+fn chunk_blocks(blocks: Vec<Block>) -> Vec<(String, Vec<String>)> {
+    let mut chunks = Vec::new();
+    let mut text = String::new();
+    let mut headings: Vec<String> = Vec::new();
+    let mut section: Option<String> = None;
+
+    for block in blocks {
+        if block.heading {
+            section = Some(block.text.clone());
+        }
+        for piece in split_paragraphs(&block.text, CHUNK_CHARS) {
+            let length = text.chars().count();
+            if length > 0 && length + 1 + piece.chars().count() > CHUNK_CHARS {
+                chunks.push((std::mem::take(&mut text), std::mem::take(&mut headings)));
+            }
+            if text.is_empty() && !block.heading {
+                headings.extend(section.clone());
+            }
+            if block.heading && !headings.contains(&block.text) {
+                headings.push(block.text.clone());
+            }
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&piece);
+        }
+    }
+
+    if !text.is_empty() {
+        chunks.push((text, headings));
+    }
+    chunks
+}
+
+
+/// Reads the file at `path` and decodes it to text.
+pub(crate) fn read_text_file(path: &Path, format: TextFormat) -> Result<String> {
+    let file = File::open(path).map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?;
+    let mut bytes = Vec::new();
+    file.take(MAX_TEXT_FILE_SIZE + 1).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > MAX_TEXT_FILE_SIZE {
+        return Err(anyhow!("{} is larger than {} bytes", path.display(), MAX_TEXT_FILE_SIZE));
+    }
+
+    Ok(decode_text(&bytes, format).0)
+}
+
+
+/// Reads a plain text, Markdown or HTML file into documents of a few paragraphs each,
+/// with their headings in a separate field.
+pub(crate) fn extract_text_file(path: &Path, format: TextFormat) -> Result<ExtractedFile> {
+    let text = read_text_file(path, format)?;
+    let (blocks, title) = match format {
+        // WhatsApp exports are plain text files too.
+        TextFormat::Plain if is_whatsapp_export(&text) => return Ok(extract_whatsapp(path, &text)),
+        TextFormat::Plain => {
+            let blocks = text.lines().map(|line| Block { text: line.trim().to_string(), heading: false });
+            (blocks.filter(|block| !block.text.is_empty()).collect(), None)
+        }
+        TextFormat::Markdown => markdown_blocks(&text),
+        TextFormat::Html => html_blocks(&text),
+    };
+
+    let metadata = file_metadata(path, &title, &None);
+    let documents = chunk_blocks(blocks)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (text, headings))| DocumentWithMetadata {
+            doc: Document { id: chunk_id(path, index), text },
+            metadata: DocumentMetadata { headings, ..metadata.clone() },
+        })
+        .collect();
+
+    Ok(ExtractedFile { path: metadata.path, title, author: None, documents })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_character_references() {
+        assert_eq!(html_entity("&amp; more"), Some(("&".to_string(), 5)));
+        assert_eq!(html_entity("&#233;"), Some(("é".to_string(), 6)));
+        assert_eq!(html_entity("&#x1F600;"), Some(("😀".to_string(), 9)));
+        assert_eq!(html_entity("&nosuchentity;"), None);
+        assert_eq!(html_entity("& no reference"), None);
+    }
+
+    #[test]
+    fn looks_for_references_among_multibyte_text() {
+        let text = format!("&a{}", "ẹ".repeat(20));
+
+        assert_eq!(html_entity(&text), None);
+        assert_eq!(html_entity("&ẹ;"), None);
+        assert_eq!(strip_markdown_inline(&text), text);
+    }
+    #[test]
+    fn skips_raw_text_elements_whatever_the_case_of_their_closing_tag() {
+        assert_eq!(find_closing_tag("a < b </b></SCRIPT>", "script"), Some(10));
+        assert_eq!(find_closing_tag("ẹ</scrip", "script"), None);
+
+        let (blocks, _) = html_blocks("<p>Before</p><script>if (a </b) {}</SCRIPT><p>After</p>");
+        let texts: Vec<&str> = blocks.iter().map(|block| block.text.as_str()).collect();
+        assert_eq!(texts, vec!["Before", "After"]);
+    }
+}